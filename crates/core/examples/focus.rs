@@ -1,4 +1,6 @@
 //! An example showing a more advanced implementation with focus.
+//!
+//! Clicking the text input focuses it and places the cursor at the clicked character.
 
 use bevy::prelude::*;
 use bevy_ui_forms::{prelude::*, BevyUiFormsPlugins};
@@ -13,13 +15,16 @@ fn main() {
         .add_plugins(DefaultPlugins)
         .add_plugins(BevyUiFormsPlugins)
         .add_systems(Startup, setup)
-        .add_systems(Update, focus)
         .run();
 }
 
-fn setup(mut commands: Commands) {
+fn setup(mut commands: Commands, mut theme: ResMut<FormTheme>) {
     commands.spawn(Camera2dBundle::default());
 
+    // The border of inputs with a focus ring follows the theme.
+    theme.border_color.focused = BORDER_COLOR_ACTIVE;
+    theme.border_color.normal = BORDER_COLOR_INACTIVE;
+
     commands
         .spawn((
             NodeBundle {
@@ -55,19 +60,11 @@ fn setup(mut commands: Commands) {
                         color: TEXT_COLOR,
                         ..default()
                     })
-                    .with_value("Click Me"),
+                    .with_value("Click Me")
+                    .with_settings(TextInputSettings {
+                        focus_ring: true,
+                        ..default()
+                    }),
             ));
         });
 }
-
-fn focus(
-    mut text_input_query: Query<(&TextInputActive, &mut BorderColor), Changed<TextInputActive>>,
-) {
-    for (active, mut border_color) in &mut text_input_query {
-        if active.0 {
-            *border_color = BORDER_COLOR_ACTIVE.into();
-        } else {
-            *border_color = BORDER_COLOR_INACTIVE.into();
-        }
-    }
-}
@@ -4,6 +4,8 @@
 use arboard::Clipboard;
 use bevy::prelude::*;
 
+use crate::form_element::{FormKeyAction, FormKeyBindings};
+
 #[cfg(target_family = "wasm")]
 use async_channel::Receiver;
 #[cfg(target_family = "wasm")]
@@ -12,11 +14,15 @@ pub(crate) use wasm_bindgen_futures::spawn_local as spawn;
 use wasm_bindgen_futures::JsFuture;
 
 /// A Bevy plugin that provides clipboard functionality.
+///
+/// The keys copying and pasting are configured using [`FormKeyBindings`].
 pub struct ClipboardPlugin;
 
 impl Plugin for ClipboardPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<ClipboardEvent>()
+        app.init_resource::<FormKeyBindings>()
+            .init_resource::<SystemClipboard>()
+            .add_event::<ClipboardEvent>()
             .add_systems(Update, keyboard);
 
         #[cfg(target_family = "wasm")]
@@ -32,35 +38,33 @@ struct ClipboardContentReceiver(Receiver<String>);
 #[derive(Event, Debug, Clone)]
 pub enum ClipboardEvent {
     /// User requested to copy the current selection.
-    /// Currently this is only a placeholder and does not actually copy anything.
+    /// Text inputs copy their selected text, or their whole value if nothing is selected.
     Copy,
+    /// User requested to cut the current selection.
+    /// Text inputs copy their selected text and remove it from their value.
+    Cut,
     /// User requested to paste the current selection.
     Paste(String),
 }
 
 #[cfg(not(target_family = "wasm"))]
 #[allow(clippy::needless_pass_by_value)]
-fn keyboard(keys: Res<ButtonInput<KeyCode>>, mut submit_writer: EventWriter<ClipboardEvent>) {
-    if keys.just_pressed(KeyCode::Insert) {
-        request_clipboard_content(submit_writer);
-        return;
-    }
-
-    if keys.just_pressed(KeyCode::Copy) {
+fn keyboard(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<FormKeyBindings>,
+    mut submit_writer: EventWriter<ClipboardEvent>,
+) {
+    if key_bindings.just_pressed(FormKeyAction::Copy, &keys) {
         submit_writer.send(ClipboardEvent::Copy);
         return;
     }
 
-    if !keys.pressed(KeyCode::ControlLeft) && !keys.pressed(KeyCode::ControlRight) {
+    if key_bindings.just_pressed(FormKeyAction::Cut, &keys) {
+        submit_writer.send(ClipboardEvent::Cut);
         return;
     }
 
-    if keys.just_pressed(KeyCode::KeyC) {
-        submit_writer.send(ClipboardEvent::Copy);
-        return;
-    }
-
-    if keys.just_pressed(KeyCode::KeyV) {
+    if key_bindings.just_pressed(FormKeyAction::Paste, &keys) {
         request_clipboard_content(submit_writer);
     }
 }
@@ -70,28 +74,20 @@ fn keyboard(keys: Res<ButtonInput<KeyCode>>, mut submit_writer: EventWriter<Clip
 fn keyboard(
     commands: Commands,
     keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<FormKeyBindings>,
     mut submit_writer: EventWriter<ClipboardEvent>,
 ) {
-    if keys.just_pressed(KeyCode::Insert) {
-        request_clipboard_content(commands);
-        return;
-    }
-
-    if keys.just_pressed(KeyCode::Copy) {
+    if key_bindings.just_pressed(FormKeyAction::Copy, &keys) {
         submit_writer.send(ClipboardEvent::Copy);
         return;
     }
 
-    if !keys.pressed(KeyCode::ControlLeft) && !keys.pressed(KeyCode::ControlRight) {
-        return;
-    }
-
-    if keys.just_pressed(KeyCode::KeyC) {
-        submit_writer.send(ClipboardEvent::Copy);
+    if key_bindings.just_pressed(FormKeyAction::Cut, &keys) {
+        submit_writer.send(ClipboardEvent::Cut);
         return;
     }
 
-    if keys.just_pressed(KeyCode::KeyV) {
+    if key_bindings.just_pressed(FormKeyAction::Paste, &keys) {
         request_clipboard_content(commands);
     }
 }
@@ -113,6 +109,48 @@ fn async_clipboard(
     }
 }
 
+/// The system clipboard, kept open so copied text stays available while the app is running.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Resource, Default)]
+pub(crate) struct SystemClipboard(Option<Clipboard>);
+
+#[cfg(not(target_family = "wasm"))]
+impl SystemClipboard {
+    /// Puts the text on the system clipboard.
+    pub(crate) fn set_text(&mut self, text: String) {
+        if self.0.is_none() {
+            self.0 = Clipboard::new()
+                .map_err(|err| warn!("Failed to open the clipboard: {err}"))
+                .ok();
+        }
+
+        if let Some(clipboard) = &mut self.0 {
+            if let Err(err) = clipboard.set_text(text) {
+                warn!("Failed to copy to the clipboard: {err}");
+            }
+        }
+    }
+}
+
+/// The clipboard of the browser.
+#[cfg(target_family = "wasm")]
+#[derive(Resource, Default)]
+pub(crate) struct SystemClipboard;
+
+#[cfg(target_family = "wasm")]
+impl SystemClipboard {
+    /// Puts the text on the clipboard of the browser.
+    #[allow(clippy::unused_self)]
+    pub(crate) fn set_text(&mut self, text: String) {
+        spawn(async move {
+            let clipboard = web_sys::window().unwrap().navigator().clipboard().unwrap();
+            if let Err(err) = JsFuture::from(clipboard.write_text(&text)).await {
+                warn!("Failed to copy to the clipboard: {err:?}");
+            }
+        });
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 fn request_clipboard_content(mut ev_clipboard: EventWriter<ClipboardEvent>) {
     ev_clipboard.send(ClipboardEvent::Paste(
@@ -1,17 +1,69 @@
 #![allow(clippy::module_name_repetitions)]
 
-use bevy::prelude::*;
+use bevy::{
+    ecs::system::{Command, SystemParam},
+    prelude::*,
+};
 
-use crate::prelude::FormButtonBundle;
+use crate::form_element::{self, FocusOrder, FormKeyAction, FormKeyBindings};
+use crate::prelude::{
+    ButtonPressEvent, ButtonRole, FormButton, FormButtonBundle, FormElementDirty,
+    FormElementDisabled, FormElementFocus, FormElementOrder, FormTheme, PendingElements,
+};
+
+/// Keeping forms in sync with resources.
+pub mod binding;
+/// Forms shown as a dialog.
+pub mod modal;
+/// Forms embedded in other forms.
+pub mod nested;
+/// Forms generated from reflected structs.
+pub mod reflect;
+/// Fields consisting of a variable number of rows.
+pub mod repeated;
+/// Resetting forms to their initial values.
+pub mod reset;
+/// Forms described by assets, e.g. `.form.ron` files.
+#[cfg(feature = "schema")]
+pub mod schema;
+/// Sections grouping the fields of a form under a header.
+pub mod section;
+/// Editing the components of another entity.
+pub mod target;
+/// Built-in validators for text inputs.
+pub mod validation;
 
 /// Plugin for forms consisting of multiple input fields.
 pub struct FormPlugin;
 
 impl Plugin for FormPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<FormInputTextStyle>()
+        app.init_resource::<FormTheme>()
             .add_event::<GenericFormEvent>()
-            .add_systems(Update, form_keyboard);
+            .add_event::<FormValidationEvent>()
+            .add_event::<FormDirtyChanged>()
+            .add_event::<FormCancelRequested>()
+            .add_plugins((
+                validation::FormValidationPlugin,
+                section::FormSectionPlugin,
+                repeated::RepeatedFieldPlugin,
+                reflect::ReflectFormPlugin,
+                reset::FormResetPlugin,
+                modal::ModalFormPlugin,
+            ))
+            .add_systems(
+                Update,
+                (
+                    form_keyboard,
+                    cascade_disabled,
+                    form_dirty.after(form_element::form_element_dirty),
+                    form_validation_event
+                        .after(form_element::form_element_dirty)
+                        .after(form_element::form_element_invalid)
+                        .after(form_element::form_element_valid),
+                ),
+            )
+            .register_type::<FormDisabled>();
     }
 }
 
@@ -19,6 +71,16 @@ impl Plugin for FormPlugin {
 #[derive(Component, Reflect)]
 pub struct Form;
 
+/// Add this to a form to disable all of its elements and buttons, e.g. while a submission is in
+/// flight. Removing it enables them again, except for elements that were disabled on their own.
+/// See [`FormElementDisabled`].
+#[derive(Component, Reflect)]
+pub struct FormDisabled;
+
+/// Marker component for elements disabled by a [`FormDisabled`] ancestor.
+#[derive(Component)]
+struct DisabledByForm;
+
 /// Marker component indicating that the form is valid.
 #[derive(Component, Reflect)]
 pub struct FormValid;
@@ -27,18 +89,45 @@ pub struct FormValid;
 #[derive(Component, Reflect)]
 pub struct FormInvalid(pub Vec<FormValidationError>);
 
-/// Text style for form input fields.
-/// Default is `TextStyle` with `font_size` 20.0 and `color` `Color::BLACK`.
-#[derive(Resource, Debug)]
-pub struct FormInputTextStyle(pub TextStyle);
+/// Marker component indicating that an element of the form is dirty, e.g. to warn about unsaved
+/// changes. See [`FormElementDirty`].
+#[derive(Component, Reflect)]
+pub struct FormDirty;
+
+/// Add this to a form to confirm discarding changes before it is cancelled.
+///
+/// Cancelling a [`FormDirty`] form using the keyboard or a cancel button sends a
+/// [`FormCancelRequested`] event instead, e.g. to ask the player whether to discard the changes.
+/// To cancel the form anyway, send a [`GenericFormEvent`] with [`FormEvent::Cancel`].
+///
+/// Forms generated by the `form_struct` macro get it with `#[form_struct(confirm_cancel)]`.
+#[derive(Component, Reflect)]
+pub struct FormConfirmCancel;
+
+/// Event that is sent instead of cancelling a dirty form marked with [`FormConfirmCancel`].
+#[derive(Event, Debug)]
+pub struct FormCancelRequested {
+    /// The form entity.
+    pub form: Entity,
+}
+
+/// Intercepts cancelling forms that need a confirmation. See [`FormConfirmCancel`].
+#[derive(SystemParam)]
+pub struct FormCancelGuard<'w, 's> {
+    q_confirm: Query<'w, 's, (), (With<FormConfirmCancel>, With<FormDirty>)>,
+    ev_requested: EventWriter<'w, FormCancelRequested>,
+}
+
+impl FormCancelGuard<'_, '_> {
+    /// Returns true if cancelling the form needs a confirmation, in which case a
+    /// [`FormCancelRequested`] event is sent and the form must not be cancelled.
+    pub fn intercept(&mut self, form: Entity) -> bool {
+        if !self.q_confirm.contains(form) {
+            return false;
+        }
 
-impl Default for FormInputTextStyle {
-    fn default() -> Self {
-        FormInputTextStyle(TextStyle {
-            font_size: 20.0,
-            color: Color::BLACK,
-            ..default()
-        })
+        self.ev_requested.send(FormCancelRequested { form });
+        true
     }
 }
 
@@ -58,13 +147,94 @@ pub enum FormEvent<T> {
     Apply(T),
     /// Cancel event.
     Cancel(Entity),
+    /// Reset event. Send it as a [`GenericFormEvent`] to reset the elements of the form to their
+    /// initial values.
+    Reset(Entity),
     /// Custom event with a message.
     Custom(Entity, String, Option<T>),
 }
 
-/// Event that is sent when a form is validated.
+impl<T> FormEvent<T> {
+    /// Returns the form data carried by the event, if any.
+    #[must_use]
+    pub fn data(&self) -> Option<&T> {
+        match self {
+            FormEvent::Submit(data) | FormEvent::Apply(data) => Some(data),
+            FormEvent::Custom(_, _, data) => data.as_ref(),
+            FormEvent::Cancel(_) | FormEvent::Reset(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> FormEvent<T> {
+    /// Serializes the form data carried by the event to a JSON string.
+    /// Returns `None` if the event does not carry form data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bevy_ui_forms::prelude::*;
+    ///
+    /// let event = FormEvent::Submit(vec!["foo", "bar"]);
+    /// assert_eq!(event.to_json().unwrap(), Some(r#"["foo","bar"]"#.to_string()));
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the form data can not be serialized.
+    pub fn to_json(&self) -> serde_json::Result<Option<String>> {
+        self.data().map(serde_json::to_string).transpose()
+    }
+}
+
+/// Deserializes form data from JSON, e.g. to pre-fill a form with saved state.
+/// Keys missing in the JSON are taken from `T::default()`.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_ui_forms::prelude::*;
+/// use std::collections::BTreeMap;
+///
+/// let data: BTreeMap<String, String> = form_data_from_json(r#"{"name":"foo"}"#).unwrap();
+/// assert_eq!(data["name"], "foo");
+/// ```
+///
+/// # Errors
+/// Returns an error if the JSON is malformed or does not match the form data.
+#[cfg(feature = "serde")]
+pub fn form_data_from_json<T>(json: &str) -> serde_json::Result<T>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + Default,
+{
+    let serde_json::Value::Object(provided) = serde_json::from_str(json)? else {
+        return serde_json::from_str(json);
+    };
+
+    let mut data = serde_json::to_value(T::default())?;
+    if let serde_json::Value::Object(defaults) = &mut data {
+        defaults.extend(provided);
+    } else {
+        data = serde_json::Value::Object(provided);
+    }
+
+    serde_json::from_value(data)
+}
+
+/// Event that is sent when a form becomes dirty or is no longer dirty, e.g. after it was reset.
+#[derive(Event, Debug)]
+pub struct FormDirtyChanged {
+    /// The form entity.
+    pub form: Entity,
+    /// Whether the form is dirty.
+    pub dirty: bool,
+}
+
+/// Event that is sent when the validity or dirtiness of a form changes, and once for every new form.
 #[derive(Event, Debug)]
 pub struct FormValidationEvent {
+    /// The form entity.
+    pub form: Entity,
     /// Whether the form is valid.
     pub valid: bool,
     /// Whether the form is dirty.
@@ -82,6 +252,42 @@ pub enum FormValidationError {
     Invalid(Entity),
     /// Custom error with a message.
     Custom(Entity, String),
+    /// Error with a machine-readable code and a human-readable message.
+    Code(Entity, &'static str, String),
+}
+
+impl FormValidationError {
+    /// Returns the entity of the form element the error belongs to.
+    #[must_use]
+    pub fn entity(&self) -> Entity {
+        match self {
+            FormValidationError::Required(entity)
+            | FormValidationError::Invalid(entity)
+            | FormValidationError::Custom(entity, _)
+            | FormValidationError::Code(entity, _, _) => *entity,
+        }
+    }
+
+    /// Returns the machine-readable code of the error, if it has one.
+    #[must_use]
+    pub fn code(&self) -> Option<&'static str> {
+        match self {
+            FormValidationError::Code(_, code, _) => Some(code),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for FormValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormValidationError::Required(_) => write!(f, "This field is required"),
+            FormValidationError::Invalid(_) => write!(f, "Invalid value"),
+            FormValidationError::Custom(_, message) | FormValidationError::Code(_, _, message) => {
+                write!(f, "{message}")
+            }
+        }
+    }
 }
 
 /// Actions that can be performed on a form.
@@ -118,21 +324,312 @@ pub trait FormActions: Sized {
     fn from_id_and_data(id: usize, entity: Option<Self::FormEntity>) -> Result<Self, String>;
 }
 
+/// A form generated by the `form_struct` macro.
+pub trait GeneratedForm: Sized {
+    /// The marker component of the form entity.
+    type Marker: Component;
+    /// The component on the form entity holding the entities of its inputs.
+    type Fields: Component;
+    /// The event sent when the form is submitted or cancelled.
+    type Event: Event;
+
+    /// Inserts the marker component of the form, which spawns its inputs into the entity.
+    fn insert_marker(commands: &mut Commands, entity: Entity);
+
+    /// Fills the inputs with the given data.
+    fn set_form_data(fields: &Self::Fields, commands: &mut Commands, data: &Self);
+
+    /// Returns the form event carried by the event.
+    fn form_event(event: &Self::Event) -> &FormEvent<Self>;
+
+    /// Returns the entity of the form that sent the event.
+    fn form(event: &Self::Event) -> Entity;
+}
+
+/// The data a form generated by the `form_struct` macro is filled with when it is spawned, e.g. the
+/// current values in an edit dialog.
+///
+/// Insert it next to the marker component of the form or use [`SpawnFormExt::spawn_form_with`].
+/// Unlike the `default_value` of a field it can be set at runtime.
+#[derive(Component, Debug)]
+pub struct FormInitialValue<T: Send + Sync + 'static>(pub T);
+
+/// Extension trait for `Commands` to spawn forms generated by the `form_struct` macro.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+///
+/// #[form_struct(submit = "Login")]
+/// #[derive(Debug, Clone)]
+/// pub struct LoginData {
+///     #[text_box(placeholder = "Username")]
+///     pub username: String,
+/// }
+///
+/// fn open_login(mut commands: Commands) {
+///     let form = commands.spawn_form::<LoginData>();
+/// }
+///
+/// fn close_login(mut commands: Commands, q_form: Query<Entity, With<LoginDataForm>>) {
+///     for form in &q_form {
+///         commands.despawn_form(form);
+///     }
+/// }
+/// ```
+pub trait SpawnFormExt {
+    /// Spawns the root node of the form `T`, laying out its fields in a column.
+    /// Returns the form entity.
+    fn spawn_form<T: GeneratedForm>(&mut self) -> Entity;
+
+    /// Spawns the form `T` like [`SpawnFormExt::spawn_form`], filled with the given data.
+    fn spawn_form_with<T: GeneratedForm + Send + Sync + 'static>(&mut self, data: T) -> Entity;
+
+    /// Despawns the form and all its elements. See [`DespawnForm`].
+    fn despawn_form(&mut self, form: Entity);
+}
+
+impl SpawnFormExt for Commands<'_, '_> {
+    fn despawn_form(&mut self, form: Entity) {
+        self.add(DespawnForm(form));
+    }
+
+    fn spawn_form_with<T: GeneratedForm + Send + Sync + 'static>(&mut self, data: T) -> Entity {
+        let form = self.spawn_form::<T>();
+        self.entity(form).insert(FormInitialValue(data));
+        form
+    }
+
+    fn spawn_form<T: GeneratedForm>(&mut self) -> Entity {
+        let form = self
+            .spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(8.0),
+                    align_self: AlignSelf::Stretch,
+                    align_items: AlignItems::Stretch,
+                    ..default()
+                },
+                ..default()
+            })
+            .id();
+        T::insert_marker(self, form);
+        form
+    }
+}
+
+/// A `Command` despawning a form and all its elements, including popups like the options of
+/// selects, and detaching it from its parent.
+///
+/// The entities of the inputs are stored on the form entity, so nothing refers to the despawned
+/// elements afterwards. Events already sent for the form are ignored. Does nothing if the form
+/// does not exist.
+#[derive(Debug, Clone, Copy)]
+pub struct DespawnForm(pub Entity);
+
+impl Command for DespawnForm {
+    fn apply(self, world: &mut World) {
+        if let Some(form) = world.get_entity_mut(self.0) {
+            form.despawn_recursive();
+        }
+    }
+}
+
+/// Returns the closest ancestor of the entity that is accepted by `is_form`.
+pub(crate) fn find_form_ancestor(
+    entity: Entity,
+    q_parent: &Query<&Parent>,
+    is_form: impl Fn(Entity) -> bool,
+) -> Option<Entity> {
+    q_parent
+        .iter_ancestors(entity)
+        .find(|ancestor| is_form(*ancestor))
+}
+
+/// The validity and dirtiness of a form sent last.
+#[derive(Component, PartialEq, Eq)]
+struct FormValidationState {
+    valid: bool,
+    dirty: bool,
+}
+
+/// Disables the elements and buttons of forms marked with [`FormDisabled`], including ones spawned
+/// later, and enables them again once the marker is removed.
+#[allow(clippy::needless_pass_by_value)]
+fn cascade_disabled(
+    mut commands: Commands,
+    mut enabled: RemovedComponents<FormDisabled>,
+    q_disabled: Query<Entity, With<FormDisabled>>,
+    q_children: Query<&Children>,
+    q_element: Query<
+        (Has<FormElementDisabled>, Has<DisabledByForm>),
+        Or<(With<FormElementOrder>, With<FormButton>)>,
+    >,
+) {
+    let mut enabled_elements = Vec::new();
+    for form in enabled.read() {
+        for entity in q_children.iter_descendants(form) {
+            if let Ok((_, true)) = q_element.get(entity) {
+                commands
+                    .entity(entity)
+                    .remove::<(FormElementDisabled, DisabledByForm)>();
+                enabled_elements.push(entity);
+            }
+        }
+    }
+
+    // Elements enabled above stay disabled if another disabled form contains them.
+    for form in &q_disabled {
+        for entity in q_children.iter_descendants(form) {
+            let Ok((disabled, _)) = q_element.get(entity) else {
+                continue;
+            };
+            if !disabled || enabled_elements.contains(&entity) {
+                commands
+                    .entity(entity)
+                    .insert((FormElementDisabled, DisabledByForm));
+            }
+        }
+    }
+}
+
+/// Keeps the [`FormDirty`] marker of forms in sync with the dirty markers of their elements.
 #[allow(clippy::needless_pass_by_value)]
-fn form_keyboard(
+fn form_dirty(
+    mut commands: Commands,
+    q_form: Query<(Entity, Has<FormDirty>), With<Form>>,
+    q_children: Query<&Children>,
+    q_dirty: Query<(), With<FormElementDirty>>,
+    mut ev_dirty: EventWriter<FormDirtyChanged>,
+) {
+    for (form, was_dirty) in &q_form {
+        let dirty = q_children
+            .iter_descendants(form)
+            .any(|child| q_dirty.contains(child));
+        if dirty == was_dirty {
+            continue;
+        }
+
+        if dirty {
+            commands.entity(form).insert(FormDirty);
+        } else {
+            commands.entity(form).remove::<FormDirty>();
+        }
+        ev_dirty.send(FormDirtyChanged { form, dirty });
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn form_validation_event(
+    mut commands: Commands,
+    q_form: Query<(Entity, Option<&FormInvalid>, Option<&FormValidationState>), With<Form>>,
+    q_children: Query<&Children>,
+    q_dirty: Query<(), With<FormElementDirty>>,
+    mut ev_validation: EventWriter<FormValidationEvent>,
+) {
+    for (form, invalid, previous) in &q_form {
+        let state = FormValidationState {
+            valid: invalid.is_none(),
+            dirty: q_children
+                .iter_descendants(form)
+                .any(|child| q_dirty.contains(child)),
+        };
+
+        if previous == Some(&state) {
+            continue;
+        }
+
+        ev_validation.send(FormValidationEvent {
+            form,
+            valid: state.valid,
+            dirty: state.dirty,
+            fields: invalid.map(|invalid| invalid.0.clone()).unwrap_or_default(),
+        });
+        commands.entity(form).insert(state);
+    }
+}
+
+/// Submits the form containing the focused element on `Enter` and cancels it on `Escape`, or the
+/// chords configured in [`FormKeyBindings`]. Other forms are left alone, so several forms can be
+/// shown at the same time. See [`EnterKeyBehavior`](form_element::EnterKeyBehavior) for moving the
+/// focus to the next element on `Enter` instead.
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn form_keyboard(
+    mut commands: Commands,
     keyboard_input: Res<ButtonInput<KeyCode>>,
-    q_form: Query<(Entity, Option<&FormInvalid>), With<Form>>,
+    key_bindings: Res<FormKeyBindings>,
+    q_form: Query<Option<&FormInvalid>, With<Form>>,
+    q_focused: Query<Entity, With<FormElementFocus>>,
+    q_parent: Query<&Parent>,
+    pending: PendingElements,
+    focus_order: FocusOrder,
+    mut cancel_guard: FormCancelGuard,
     mut form_events: EventWriter<GenericFormEvent>,
 ) {
-    if let Ok((entity, invalid)) = q_form.get_single() {
-        if keyboard_input.just_released(KeyCode::Enter) && invalid.is_none() {
-            form_events.send(GenericFormEvent {
-                form: FormEvent::Submit(entity),
-            });
-        } else if keyboard_input.just_released(KeyCode::Escape) {
-            form_events.send(GenericFormEvent {
-                form: FormEvent::Cancel(entity),
-            });
+    let submit = key_bindings.just_released(FormKeyAction::Submit, &keyboard_input);
+    let cancel = key_bindings.just_released(FormKeyAction::Cancel, &keyboard_input);
+    if !submit && !cancel {
+        return;
+    }
+
+    // Only the form containing the focused element reacts to the keyboard.
+    let Some((focused, entity)) = q_focused.iter().find_map(|focused| {
+        find_form_ancestor(focused, &q_parent, |entity| q_form.contains(entity))
+            .map(|form| (focused, form))
+    }) else {
+        return;
+    };
+
+    let Ok(invalid) = q_form.get(entity) else {
+        return;
+    };
+
+    if submit {
+        if let Some(next) = focus_order.enter_advances_to(entity, focused) {
+            commands.entity(next).insert(FormElementFocus);
+            return;
         }
     }
+
+    if submit && invalid.is_none() && !pending.is_pending(entity) {
+        form_events.send(GenericFormEvent {
+            form: FormEvent::Submit(entity),
+        });
+    } else if cancel && !cancel_guard.intercept(entity) {
+        form_events.send(GenericFormEvent {
+            form: FormEvent::Cancel(entity),
+        });
+    }
+}
+
+/// Sends the form events of the buttons of forms marked with `T`, for forms that are not generated
+/// by the `form_struct` macro.
+#[allow(clippy::needless_pass_by_value)]
+pub(crate) fn button_form_events<T: Component>(
+    mut ev_button: EventReader<ButtonPressEvent>,
+    q_form: Query<Has<FormInvalid>, With<T>>,
+    pending: PendingElements,
+    mut cancel_guard: FormCancelGuard,
+    mut ev_form: EventWriter<GenericFormEvent>,
+) {
+    for ev in ev_button.read() {
+        let Some((form, invalid)) = ev
+            .button
+            .form
+            .and_then(|form| Some((form, q_form.get(form).ok()?)))
+        else {
+            continue;
+        };
+
+        let event = match ev.role {
+            ButtonRole::Submit if !invalid && !pending.is_pending(form) => FormEvent::Submit(form),
+            ButtonRole::Apply if !invalid && !pending.is_pending(form) => FormEvent::Apply(form),
+            ButtonRole::Cancel if !cancel_guard.intercept(form) => FormEvent::Cancel(form),
+            _ => continue,
+        };
+        ev_form.send(GenericFormEvent { form: event });
+    }
 }
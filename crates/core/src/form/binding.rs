@@ -0,0 +1,73 @@
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+
+use crate::prelude::{FormEvent, GeneratedForm};
+
+/// A Bevy `Plugin` keeping a form generated by the `form_struct` macro in sync with a resource of
+/// the same type, e.g. a settings menu bound to a `GameSettings` resource.
+///
+/// Submitting or applying the form writes the data to the resource. Whenever the resource is
+/// changed elsewhere, and when the form is spawned, the inputs are filled with its value.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+///
+/// #[form_struct(submit = "Save")]
+/// #[derive(Resource, Debug, Clone)]
+/// pub struct GameSettings {
+///     #[slider(min = 0.0, max = 1.0, step = 0.1)]
+///     pub volume: f32,
+/// }
+///
+/// fn build(app: &mut App) {
+///     app.insert_resource(GameSettings { volume: 0.5 })
+///         .add_plugins((GameSettingsFormPlugin, FormBinding::<GameSettings>::default()));
+/// }
+/// ```
+pub struct FormBinding<R>(PhantomData<R>);
+
+impl<R> Default for FormBinding<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: GeneratedForm + Resource + Clone> Plugin for FormBinding<R> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, sync::<R>);
+    }
+}
+
+/// Fills the inputs of all forms of the type when the resource was changed since the last run,
+/// then writes the data of submitted forms. Changes made by this system are not seen as changed in
+/// its next run.
+#[allow(clippy::needless_pass_by_value)]
+fn sync<R: GeneratedForm + Resource + Clone>(
+    mut commands: Commands,
+    mut ev_form: EventReader<R::Event>,
+    mut resource: Option<ResMut<R>>,
+    q_fields: Query<Ref<R::Fields>>,
+) {
+    if let Some(resource) = &resource {
+        for fields in &q_fields {
+            if resource.is_changed() || fields.is_added() {
+                R::set_form_data(&fields, &mut commands, resource);
+            }
+        }
+    }
+
+    for ev in ev_form.read() {
+        let (FormEvent::Submit(data) | FormEvent::Apply(data)) = R::form_event(ev) else {
+            continue;
+        };
+
+        match resource.as_mut() {
+            Some(resource) => **resource = data.clone(),
+            None => commands.insert_resource(data.clone()),
+        }
+    }
+}
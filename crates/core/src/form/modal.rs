@@ -0,0 +1,203 @@
+use bevy::{prelude::*, ui::FocusPolicy};
+
+use crate::form::{find_form_ancestor, form_keyboard, FormCancelGuard};
+use crate::form_element;
+use crate::prelude::{
+    Form, FormElementFocus, FormEvent, FormKeyAction, FormKeyBindings, GenericFormEvent,
+};
+
+/// A Bevy `Plugin` providing the systems required to make a [`ModalFormBundle`] work.
+pub struct ModalFormPlugin;
+
+impl Plugin for ModalFormPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ModalFormStyle>()
+            .add_systems(
+                Update,
+                (
+                    style_backdrop,
+                    style_card,
+                    trap_focus.before(form_element::form_element_focus),
+                    cancel.after(form_keyboard),
+                ),
+            )
+            .register_type::<ModalForm>();
+    }
+}
+
+/// Style of [`ModalFormBundle`]s. Default is a half transparent black backdrop and a white card
+/// with a padding of 16px.
+#[derive(Resource, Debug)]
+pub struct ModalFormStyle {
+    /// The color of the backdrop covering the screen.
+    pub backdrop_color: Color,
+    /// The background color of the form.
+    pub card_color: Color,
+    /// The padding of the form.
+    pub card_padding: UiRect,
+}
+
+impl Default for ModalFormStyle {
+    fn default() -> Self {
+        ModalFormStyle {
+            backdrop_color: Color::rgba(0.0, 0.0, 0.0, 0.5),
+            card_color: Color::WHITE,
+            card_padding: UiRect::all(Val::Px(16.0)),
+        }
+    }
+}
+
+/// A bundle showing forms as a dialog on top of everything else.
+///
+/// Spawn the form as a child of the bundle. The bundle covers the screen with a backdrop blocking
+/// the interaction with the UI below and centers the form, which is styled as a card using the
+/// [`ModalFormStyle`]. While a modal is shown, elements outside of it can not be focused, and
+/// `Escape` cancels the form even if none of its elements is focused.
+///
+/// The modal is not closed automatically. Despawn it once the form is submitted or cancelled.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+///
+/// #[form_struct(submit = "Delete", cancel = "Keep")]
+/// #[derive(Debug, Clone)]
+/// pub struct DeleteSave {
+///     #[text_box(placeholder = "Type the name of the save to confirm")]
+///     pub name: String,
+/// }
+///
+/// fn open(mut commands: Commands) {
+///     let form = commands.spawn_form::<DeleteSave>();
+///     commands.spawn(ModalFormBundle::default()).add_child(form);
+/// }
+///
+/// fn close(
+///     mut commands: Commands,
+///     mut ev_form: EventReader<DeleteSaveFormEvent>,
+///     q_modal: Query<Entity, With<ModalForm>>,
+/// ) {
+///     for _ in ev_form.read() {
+///         for modal in &q_modal {
+///             commands.entity(modal).despawn_recursive();
+///         }
+///     }
+/// }
+/// ```
+#[derive(Bundle)]
+pub struct ModalFormBundle {
+    /// The backdrop covering the screen.
+    pub node: NodeBundle,
+    /// Marker component of the modal.
+    pub modal: ModalForm,
+}
+
+impl Default for ModalFormBundle {
+    fn default() -> Self {
+        Self {
+            node: NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    top: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                focus_policy: FocusPolicy::Block,
+                z_index: ZIndex::Global(10),
+                ..default()
+            },
+            modal: ModalForm,
+        }
+    }
+}
+
+/// Marker component for the backdrop of a [`ModalFormBundle`].
+#[derive(Component, Debug, Default, Reflect)]
+pub struct ModalForm;
+
+#[allow(clippy::needless_pass_by_value)]
+fn style_backdrop(
+    style: Res<ModalFormStyle>,
+    mut q_modal: Query<&mut BackgroundColor, Added<ModalForm>>,
+) {
+    for mut background in &mut q_modal {
+        *background = style.backdrop_color.into();
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn style_card(
+    style: Res<ModalFormStyle>,
+    mut q_form: Query<
+        (&Parent, &mut Style, &mut BackgroundColor),
+        (With<Form>, Or<(Added<Form>, Changed<Parent>)>),
+    >,
+    q_modal: Query<(), With<ModalForm>>,
+) {
+    for (parent, mut node_style, mut background) in &mut q_form {
+        if !q_modal.contains(parent.get()) {
+            continue;
+        }
+
+        node_style.padding = style.card_padding;
+        *background = style.card_color.into();
+    }
+}
+
+/// Removes the focus from elements outside of the modals while a modal is shown.
+#[allow(clippy::needless_pass_by_value)]
+fn trap_focus(
+    mut commands: Commands,
+    q_modal: Query<(), With<ModalForm>>,
+    q_focused: Query<Entity, With<FormElementFocus>>,
+    q_parent: Query<&Parent>,
+) {
+    if q_modal.is_empty() {
+        return;
+    }
+
+    for entity in &q_focused {
+        if find_form_ancestor(entity, &q_parent, |e| q_modal.contains(e)).is_none() {
+            commands.entity(entity).remove::<FormElementFocus>();
+        }
+    }
+}
+
+/// Cancels the forms of modals on `Escape` if none of their elements is focused. Otherwise the
+/// form is already cancelled by the keyboard handling of forms.
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+fn cancel(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<FormKeyBindings>,
+    q_modal: Query<&Children, With<ModalForm>>,
+    q_form: Query<(), With<Form>>,
+    q_focused: Query<Entity, With<FormElementFocus>>,
+    q_parent: Query<&Parent>,
+    mut cancel_guard: FormCancelGuard,
+    mut ev_form: EventWriter<GenericFormEvent>,
+) {
+    if !key_bindings.just_released(FormKeyAction::Cancel, &keyboard_input) {
+        return;
+    }
+
+    for children in &q_modal {
+        for form in children.iter().copied().filter(|e| q_form.contains(*e)) {
+            let focused = q_focused
+                .iter()
+                .any(|focused| find_form_ancestor(focused, &q_parent, |e| e == form).is_some());
+            if focused || cancel_guard.intercept(form) {
+                continue;
+            }
+
+            ev_form.send(GenericFormEvent {
+                form: FormEvent::Cancel(form),
+            });
+        }
+    }
+}
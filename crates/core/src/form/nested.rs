@@ -0,0 +1,98 @@
+use std::fmt::Debug;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::prelude::{
+    CheckboxValue, ColorPickerValue, FormSectionStyle, FormTheme, RepeatedRow, SelectValue,
+    SliderValue, TagInputValue, TextInputFormatMask, TextInputTransform, TextInputValue,
+};
+
+/// A form that can be embedded in other forms.
+///
+/// Implemented by the `form_struct` macro. A field of such a type is embedded in another form using
+/// `#[form_field(nested)]`, which spawns the inputs of the nested form in a group and composes its
+/// data when the outer form is submitted.
+pub trait NestedForm: Sized {
+    /// The entities of the inputs of the form.
+    type Fields: Debug + Send + Sync + 'static;
+
+    /// The number of focusable elements of the form, including nested forms.
+    const ELEMENT_COUNT: usize;
+
+    /// Spawns the inputs of the form. The elements are ordered starting at `order`.
+    ///
+    /// Returns the entities of the inputs and the nodes to add to the container of the form.
+    fn spawn_fields(
+        commands: &mut Commands,
+        styles: &FormStyles,
+        order: usize,
+    ) -> (Self::Fields, Vec<Entity>);
+
+    /// Reads the data from the inputs. Returns `None` and marks the input as invalid if a value can
+    /// not be parsed. Also returns `None` if an input was despawned.
+    fn read_fields(
+        commands: &mut Commands,
+        values: &FormValues,
+        fields: &Self::Fields,
+    ) -> Option<Self>;
+}
+
+/// The styles used to spawn the inputs of generated forms.
+#[derive(SystemParam)]
+pub struct FormStyles<'w> {
+    /// The look of the inputs, labels and help texts.
+    pub theme: Res<'w, FormTheme>,
+    /// Style of the sections.
+    pub section: Res<'w, FormSectionStyle>,
+}
+
+/// The values of the form elements, read when a generated form is submitted.
+#[derive(SystemParam)]
+pub struct FormValues<'w, 's> {
+    /// Values of text inputs.
+    pub text_input: Query<'w, 's, &'static TextInputValue>,
+    /// Format masks of text inputs.
+    pub format_mask: Query<'w, 's, &'static TextInputFormatMask>,
+    /// Transforms of text inputs.
+    pub transform: Query<'w, 's, &'static TextInputTransform>,
+    /// Values of color pickers.
+    pub color_picker: Query<'w, 's, &'static ColorPickerValue>,
+    /// Values of selects.
+    pub select: Query<'w, 's, &'static SelectValue>,
+    /// Values of sliders.
+    pub slider: Query<'w, 's, &'static SliderValue>,
+    /// Values of tag inputs.
+    pub tag_input: Query<'w, 's, &'static TagInputValue>,
+    /// Values of checkboxes.
+    pub checkbox: Query<'w, 's, &'static CheckboxValue>,
+    /// Children of repeated fields.
+    pub children: Query<'w, 's, &'static Children>,
+    /// Rows of repeated fields.
+    pub repeated_row: Query<'w, 's, &'static RepeatedRow>,
+}
+
+impl FormValues<'_, '_> {
+    /// Returns the value of the text input without the separators of its [`TextInputFormatMask`]
+    /// and with its [`TextInputTransform`] applied.
+    #[must_use]
+    pub fn text(&self, input: Entity) -> Option<String> {
+        let value = self.text_input.get(input).ok()?;
+        let value = self
+            .format_mask
+            .get(input)
+            .map_or_else(|_| value.0.clone(), |mask| mask.strip(&value.0));
+        match self.transform.get(input) {
+            Ok(transform) => Some(transform.apply(&value)),
+            Err(_) => Some(value),
+        }
+    }
+
+    /// Returns the inputs of the rows of the [`RepeatedField`](crate::prelude::RepeatedField) in order.
+    pub fn repeated_rows<T: 'static>(&self, field: Entity) -> impl Iterator<Item = &T> {
+        self.children
+            .get(field)
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| self.repeated_row.get(*child).ok()?.inputs())
+    }
+}
@@ -0,0 +1,371 @@
+use bevy::{
+    prelude::*,
+    reflect::{ReflectMut, ReflectRef},
+};
+
+use crate::form::{button_form_events, form_keyboard};
+use crate::prelude::{
+    ButtonRole, CheckboxBundle, Form, FormButtonBundle, FormElementInvalid, FormElementOptional,
+    FormElementOrder, FormElementValid, FormEvent, FormStyles, FormThemed, FormValid,
+    FormValidationError, FormValues, GenericFormEvent, NumberInputSettings, TextInputBundle,
+    TextInputSettings,
+};
+
+/// A Bevy `Plugin` spawning the inputs of [`ReflectForm`]s and sending [`ReflectFormEvent`]s.
+pub struct ReflectFormPlugin;
+
+impl Plugin for ReflectFormPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ReflectFormEvent>().add_systems(
+            Update,
+            (
+                setup,
+                button_form_events::<ReflectForm>,
+                submit
+                    .after(button_form_events::<ReflectForm>)
+                    .after(form_keyboard),
+            ),
+        );
+    }
+}
+
+/// A form editing a copy of a reflected struct, e.g. a component in a dev tool.
+///
+/// An input is spawned for every field the form can edit: a text input for `String`, a checkbox
+/// for `bool` and a number input for numeric types. Other fields are left unchanged. Text inputs
+/// may be left empty, as an empty `String` is a valid value. When the form is submitted the edits
+/// are applied to the copy, which is sent in a [`ReflectFormEvent`].
+///
+/// Use [`spawn_form_for_reflect`] to spawn the form.
+#[derive(Component)]
+pub struct ReflectForm {
+    value: Box<dyn Reflect>,
+    /// The text of the submit button. No button is shown if it is not set.
+    pub submit: Option<String>,
+    /// The text of the cancel button. No button is shown if it is not set.
+    pub cancel: Option<String>,
+}
+
+impl ReflectForm {
+    /// Creates a form editing a copy of the value, with an "Apply" button.
+    #[must_use]
+    pub fn new(value: &dyn Reflect) -> Self {
+        Self {
+            value: value.clone_value(),
+            submit: Some("Apply".to_string()),
+            cancel: None,
+        }
+    }
+
+    /// Sets the text of the submit button.
+    #[must_use]
+    pub fn with_submit(mut self, submit: impl Into<String>) -> Self {
+        self.submit = Some(submit.into());
+        self
+    }
+
+    /// Sets the text of the cancel button.
+    #[must_use]
+    pub fn with_cancel(mut self, cancel: impl Into<String>) -> Self {
+        self.cancel = Some(cancel.into());
+        self
+    }
+
+    /// Returns the value as it was last submitted.
+    #[must_use]
+    pub fn value(&self) -> &dyn Reflect {
+        self.value.as_ref()
+    }
+}
+
+/// Event that is sent when a [`ReflectForm`] is submitted or applied.
+#[derive(Event)]
+pub struct ReflectFormEvent {
+    /// The form entity.
+    pub form: Entity,
+    /// The edited value. Apply it to the original, e.g. using `Reflect::apply`.
+    pub value: Box<dyn Reflect>,
+}
+
+/// Spawns a form editing a copy of the reflected struct and returns its entity.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// #[derive(Component, Reflect)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+///     invincible: bool,
+/// }
+///
+/// fn edit(mut commands: Commands, q_player: Query<&Player>) {
+///     for player in &q_player {
+///         spawn_form_for_reflect(&mut commands, player);
+///     }
+/// }
+///
+/// fn apply(mut ev_form: EventReader<ReflectFormEvent>, mut q_player: Query<&mut Player>) {
+///     for ev in ev_form.read() {
+///         for mut player in &mut q_player {
+///             player.apply(ev.value.as_ref());
+///         }
+///     }
+/// }
+/// ```
+pub fn spawn_form_for_reflect(commands: &mut Commands, value: &dyn Reflect) -> Entity {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                ..default()
+            },
+            ReflectForm::new(value),
+        ))
+        .id()
+}
+
+/// The inputs of the fields of a [`ReflectForm`] by the name of their field.
+#[derive(Component)]
+struct ReflectFormInputs(Vec<(String, Entity)>);
+
+/// The kinds of fields a [`ReflectForm`] can edit.
+enum ReflectFieldKind {
+    Text,
+    Bool,
+    Integer { unsigned: bool },
+    Float,
+}
+
+impl ReflectFieldKind {
+    fn of(value: &dyn Reflect) -> Option<Self> {
+        if value.is::<String>() {
+            Some(ReflectFieldKind::Text)
+        } else if value.is::<bool>() {
+            Some(ReflectFieldKind::Bool)
+        } else if value.is::<f32>() || value.is::<f64>() {
+            Some(ReflectFieldKind::Float)
+        } else if value.is::<u8>()
+            || value.is::<u16>()
+            || value.is::<u32>()
+            || value.is::<u64>()
+            || value.is::<u128>()
+            || value.is::<usize>()
+        {
+            Some(ReflectFieldKind::Integer { unsigned: true })
+        } else if value.is::<i8>()
+            || value.is::<i16>()
+            || value.is::<i32>()
+            || value.is::<i64>()
+            || value.is::<i128>()
+            || value.is::<isize>()
+        {
+            Some(ReflectFieldKind::Integer { unsigned: false })
+        } else {
+            None
+        }
+    }
+}
+
+/// Settings of the text inputs, which keep their value when pressing enter so the form can read it.
+fn retained() -> TextInputSettings {
+    TextInputSettings {
+        retain_on_submit: true,
+        ..default()
+    }
+}
+
+/// Parses the text into the numeric field. Returns false if it is not a valid value of the field.
+fn set_number(field: &mut dyn Reflect, text: &str) -> bool {
+    macro_rules! parse_into {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(field) = field.downcast_mut::<$ty>() {
+                    return text.trim().parse().map(|value| *field = value).is_ok();
+                }
+            )*
+        };
+    }
+
+    parse_into!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    false
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::too_many_lines)]
+fn setup(
+    mut commands: Commands,
+    styles: FormStyles,
+    q_added: Query<(Entity, &ReflectForm), Added<ReflectForm>>,
+) {
+    for (entity, form) in &q_added {
+        let text_style = styles.theme.text_style.clone();
+        let mut inputs = Vec::new();
+        let mut children = Vec::new();
+
+        if let ReflectRef::Struct(value) = form.value.reflect_ref() {
+            for (index, field) in value.iter_fields().enumerate() {
+                let (Some(name), Some(kind)) = (value.name_at(index), ReflectFieldKind::of(field))
+                else {
+                    continue;
+                };
+
+                children.push(
+                    commands
+                        .spawn((
+                            TextBundle::from_section(name, text_style.clone()),
+                            Name::new("label"),
+                        ))
+                        .id(),
+                );
+
+                let mut input = commands.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(100.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    FormElementOrder(inputs.len()),
+                ));
+                match kind {
+                    ReflectFieldKind::Text => {
+                        let value = field.downcast_ref::<String>().cloned().unwrap_or_default();
+                        input.insert((
+                            TextInputBundle::default()
+                                .with_text_style(text_style.clone())
+                                .with_settings(retained())
+                                .with_value(value),
+                            FormElementOptional,
+                            FormThemed,
+                        ));
+                    }
+                    ReflectFieldKind::Bool => {
+                        let checked = field.downcast_ref::<bool>().copied().unwrap_or_default();
+                        input.insert(
+                            CheckboxBundle::new(checked).with_text_style(text_style.clone()),
+                        );
+                    }
+                    ReflectFieldKind::Integer { unsigned } => {
+                        input.insert((
+                            TextInputBundle::default()
+                                .with_text_style(text_style.clone())
+                                .with_settings(retained())
+                                .with_value(format!("{field:?}")),
+                            NumberInputSettings {
+                                min: unsigned.then_some(0.0),
+                                decimals: Some(0),
+                                ..default()
+                            },
+                            FormThemed,
+                        ));
+                    }
+                    ReflectFieldKind::Float => {
+                        input.insert((
+                            TextInputBundle::default()
+                                .with_text_style(text_style.clone())
+                                .with_settings(retained())
+                                .with_value(format!("{field:?}")),
+                            NumberInputSettings::default(),
+                            FormThemed,
+                        ));
+                    }
+                }
+
+                let input = input.id();
+                children.push(input);
+                inputs.push((name.to_string(), input));
+            }
+        }
+
+        let actions = commands
+            .spawn((NodeBundle::default(), Name::new("action-row")))
+            .id();
+        let buttons = [
+            (&form.cancel, ButtonRole::Cancel),
+            (&form.submit, ButtonRole::Submit),
+        ];
+        for (text, role) in buttons {
+            let Some(text) = text else {
+                continue;
+            };
+            let button = commands
+                .spawn(
+                    FormButtonBundle::new(text.clone())
+                        .with_form(entity)
+                        .with_role(role),
+                )
+                .id();
+            commands.entity(actions).add_child(button);
+        }
+        children.push(actions);
+
+        commands
+            .entity(entity)
+            .insert((
+                Form,
+                FormValid,
+                ReflectFormInputs(inputs),
+                Name::new("form"),
+            ))
+            .push_children(&children);
+    }
+}
+
+/// Applies the values of the inputs to a copy of the value when the form is submitted.
+/// Inputs that can not be parsed are marked as invalid and no event is sent.
+#[allow(clippy::needless_pass_by_value)]
+fn submit(
+    mut commands: Commands,
+    mut ev_form: EventReader<GenericFormEvent>,
+    mut q_form: Query<(&mut ReflectForm, &ReflectFormInputs)>,
+    values: FormValues,
+    mut ev_reflect: EventWriter<ReflectFormEvent>,
+) {
+    for ev in ev_form.read() {
+        let (FormEvent::Submit(form) | FormEvent::Apply(form)) = ev.form else {
+            continue;
+        };
+        let Ok((mut reflect_form, inputs)) = q_form.get_mut(form) else {
+            continue;
+        };
+
+        let mut value = reflect_form.value.clone_value();
+        let ReflectMut::Struct(fields) = value.reflect_mut() else {
+            continue;
+        };
+
+        let mut valid = true;
+        for (name, input) in &inputs.0 {
+            let Some(field) = fields.field_mut(name) else {
+                continue;
+            };
+
+            if let Ok(checkbox) = values.checkbox.get(*input) {
+                if let Some(field) = field.downcast_mut::<bool>() {
+                    *field = checkbox.0;
+                }
+            } else if let Ok(text) = values.text_input.get(*input) {
+                if let Some(field) = field.downcast_mut::<String>() {
+                    field.clone_from(&text.0);
+                } else if !set_number(field, &text.0) {
+                    commands
+                        .entity(*input)
+                        .insert(FormElementInvalid(FormValidationError::Invalid(*input)))
+                        .remove::<FormElementValid>();
+                    valid = false;
+                }
+            }
+        }
+
+        if valid {
+            reflect_form.value = value.clone_value();
+            ev_reflect.send(ReflectFormEvent { form, value });
+        }
+    }
+}
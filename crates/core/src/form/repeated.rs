@@ -0,0 +1,359 @@
+use std::any::Any;
+
+use bevy::{ecs::system::SystemState, prelude::*};
+
+use crate::form::nested::FormStyles;
+use crate::prelude::{ButtonPressEvent, ButtonRole, FormButtonBundle, FormElementOrder};
+
+/// A Bevy `Plugin` providing the systems required to make a [`RepeatedField`] work.
+pub struct RepeatedFieldPlugin;
+
+impl Plugin for RepeatedFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (setup, press.after(setup), update_buttons.after(press)),
+        );
+    }
+}
+
+/// Spawns the inputs of a row, with the focusable elements ordered starting at the given order.
+/// Returns the node of the row and the entities of its inputs.
+pub type SpawnRow = fn(&mut Commands, &FormStyles, usize) -> (Entity, Box<dyn Any + Send + Sync>);
+
+/// A field consisting of a variable number of rows, e.g. a list of servers.
+///
+/// Each row is spawned by the [`SpawnRow`] function and has a button to remove it. A button below the
+/// rows adds another one. Used by the `form_struct` macro for `Vec<T>` fields marked with
+/// `#[repeated]`. The rows are read in order using [`FormValues::repeated_rows`](crate::prelude::FormValues::repeated_rows).
+///
+/// # Maximal number of rows
+///
+/// The field reserves a fixed range of the focus order for its rows, so the number of rows is
+/// capped by [`RepeatedField::max`], which defaults to [`RepeatedField::DEFAULT_MAX`]. Raise it
+/// with [`RepeatedField::with_max`], or `#[repeated(max = ...)]` with the `form_struct` macro, for
+/// fields that may hold more rows. Filling in more rows than that using [`set_repeated_rows`]
+/// drops the remaining ones with a warning.
+#[derive(Component, Clone)]
+pub struct RepeatedField {
+    /// Spawns a row.
+    pub spawn_row: SpawnRow,
+    /// The order of the first element of the first row.
+    pub order: usize,
+    /// The number of focusable elements of a row.
+    pub elements: usize,
+    /// The minimal number of rows. Rows can not be removed below it.
+    pub min: usize,
+    /// The maximal number of rows. Rows can not be added above it.
+    /// The field takes up `max * elements` positions in the focus order.
+    pub max: usize,
+    /// The text of the button adding a row.
+    pub add_text: String,
+    /// The text of the buttons removing a row.
+    pub remove_text: String,
+}
+
+impl RepeatedField {
+    /// The maximal number of rows if not set otherwise. See [the section on the maximal number of
+    /// rows](RepeatedField#maximal-number-of-rows).
+    pub const DEFAULT_MAX: usize = 10;
+
+    /// Creates a field with up to [`RepeatedField::DEFAULT_MAX`] rows, spawned by `spawn_row`.
+    pub fn new(spawn_row: SpawnRow, order: usize, elements: usize) -> Self {
+        Self {
+            spawn_row,
+            order,
+            elements,
+            min: 0,
+            max: Self::DEFAULT_MAX,
+            add_text: "+ Add".to_string(),
+            remove_text: "Remove".to_string(),
+        }
+    }
+
+    /// Sets the minimal number of rows.
+    #[must_use]
+    pub fn with_min(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximal number of rows.
+    #[must_use]
+    pub fn with_max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the text of the button adding a row.
+    #[must_use]
+    pub fn with_add_text(mut self, add_text: impl Into<String>) -> Self {
+        self.add_text = add_text.into();
+        self
+    }
+
+    /// Sets the text of the buttons removing a row.
+    #[must_use]
+    pub fn with_remove_text(mut self, remove_text: impl Into<String>) -> Self {
+        self.remove_text = remove_text.into();
+        self
+    }
+}
+
+/// A row of a [`RepeatedField`], holding the entities of its inputs.
+#[derive(Component)]
+pub struct RepeatedRow {
+    inputs: Box<dyn Any + Send + Sync>,
+}
+
+impl RepeatedRow {
+    /// Returns the entities of the inputs of the row, if they are of type `T`.
+    #[must_use]
+    pub fn inputs<T: 'static>(&self) -> Option<&T> {
+        self.inputs.downcast_ref()
+    }
+}
+
+/// Marker component for the button adding a row.
+#[derive(Component)]
+struct AddRowButton;
+
+/// Marker component for the button removing its row.
+#[derive(Component)]
+struct RemoveRowButton;
+
+/// Replaces the rows of the repeated field with `count` new rows, e.g. to fill in form data.
+///
+/// `fill` is called with the index and the entities of the inputs of each row once it is spawned.
+/// At most [`RepeatedField::max`] rows are spawned.
+pub fn set_repeated_rows(
+    commands: &mut Commands,
+    field: Entity,
+    count: usize,
+    fill: impl Fn(&mut Commands, usize, &(dyn Any + Send + Sync)) + Send + Sync + 'static,
+) {
+    commands.add(move |world: &mut World| {
+        let mut state = SystemState::<(
+            Commands,
+            FormStyles,
+            Query<(&RepeatedField, Option<&Children>)>,
+            Query<(), With<RepeatedRow>>,
+        )>::new(world);
+        let (mut commands, styles, q_field, q_row) = state.get_mut(world);
+        let Ok((repeated, children)) = q_field.get(field) else {
+            return;
+        };
+
+        for row in children
+            .into_iter()
+            .flatten()
+            .filter(|child| q_row.contains(**child))
+        {
+            commands.entity(*row).despawn_recursive();
+        }
+        if count > repeated.max {
+            warn!(
+                "Dropping {} rows of a repeated field exceeding its maximum of {} rows",
+                count - repeated.max,
+                repeated.max
+            );
+        }
+        for index in 0..count.min(repeated.max) {
+            spawn_row(
+                &mut commands,
+                &styles,
+                field,
+                repeated,
+                index,
+                |commands, inputs| {
+                    fill(commands, index, inputs);
+                },
+            );
+        }
+
+        state.apply(world);
+    });
+}
+
+/// Spawns a row at the given index, in front of the add button.
+fn spawn_row(
+    commands: &mut Commands,
+    styles: &FormStyles,
+    field: Entity,
+    repeated: &RepeatedField,
+    index: usize,
+    fill: impl FnOnce(&mut Commands, &(dyn Any + Send + Sync)),
+) {
+    let (node, inputs) =
+        (repeated.spawn_row)(commands, styles, repeated.order + index * repeated.elements);
+    fill(commands, inputs.as_ref());
+
+    commands.entity(node).insert(Style {
+        flex_grow: 1.0,
+        ..default()
+    });
+    let remove = commands
+        .spawn((
+            FormButtonBundle::new(repeated.remove_text.clone())
+                .with_role(ButtonRole::Custom("remove-row".to_string())),
+            RemoveRowButton,
+            Name::new("remove-row"),
+        ))
+        .id();
+    let row = commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            RepeatedRow { inputs },
+            Name::new("row"),
+        ))
+        .push_children(&[node, remove])
+        .id();
+    commands.entity(field).insert_children(index, &[row]);
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn setup(
+    mut commands: Commands,
+    styles: FormStyles,
+    q_added: Query<(Entity, &RepeatedField), Added<RepeatedField>>,
+    q_children: Query<&Children>,
+    q_row: Query<(), With<RepeatedRow>>,
+) {
+    for (entity, repeated) in &q_added {
+        let add = commands
+            .spawn((
+                FormButtonBundle::new(repeated.add_text.clone())
+                    .with_role(ButtonRole::Custom("add-row".to_string())),
+                AddRowButton,
+                Name::new("add-row"),
+            ))
+            .id();
+        commands.entity(entity).add_child(add);
+
+        // Rows filled in before the field was set up count towards the minimum.
+        for index in rows(entity, &q_children, &q_row).len()..repeated.min {
+            spawn_row(&mut commands, &styles, entity, repeated, index, |_, _| {});
+        }
+    }
+}
+
+/// Adds or removes rows when their buttons are pressed.
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+fn press(
+    mut commands: Commands,
+    styles: FormStyles,
+    mut ev_button: EventReader<ButtonPressEvent>,
+    q_button: Query<(&Parent, Has<AddRowButton>, Has<RemoveRowButton>)>,
+    q_parent: Query<&Parent>,
+    q_children: Query<&Children>,
+    q_field: Query<&RepeatedField>,
+    q_row: Query<(), With<RepeatedRow>>,
+    mut q_order: Query<&mut FormElementOrder>,
+) {
+    for ev in ev_button.read() {
+        let Ok((parent, add, remove)) = q_button.get(ev.entity) else {
+            continue;
+        };
+
+        if add {
+            let Ok(repeated) = q_field.get(parent.get()) else {
+                continue;
+            };
+            let rows = rows(parent.get(), &q_children, &q_row);
+            if rows.len() < repeated.max {
+                spawn_row(
+                    &mut commands,
+                    &styles,
+                    parent.get(),
+                    repeated,
+                    rows.len(),
+                    |_, _| {},
+                );
+            }
+        } else if remove {
+            let row = parent.get();
+            let Some((field, repeated)) = q_parent
+                .get(row)
+                .ok()
+                .and_then(|field| Some((field.get(), q_field.get(field.get()).ok()?)))
+            else {
+                continue;
+            };
+            let rows = rows(field, &q_children, &q_row);
+            if rows.len() <= repeated.min {
+                continue;
+            }
+
+            commands.entity(row).despawn_recursive();
+            // The following rows move up, keeping the focus order of the field compact.
+            for following in rows.iter().skip_while(|other| **other != row).skip(1) {
+                for entity in q_children.iter_descendants(*following) {
+                    if let Ok(mut order) = q_order.get_mut(entity) {
+                        order.0 -= repeated.elements;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn rows(
+    field: Entity,
+    q_children: &Query<&Children>,
+    q_row: &Query<(), With<RepeatedRow>>,
+) -> Vec<Entity> {
+    q_children
+        .get(field)
+        .map(|children| {
+            children
+                .iter()
+                .copied()
+                .filter(|child| q_row.contains(*child))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Hides the add button at the maximal and the remove buttons at the minimal number of rows.
+#[allow(clippy::needless_pass_by_value)]
+fn update_buttons(
+    q_field: Query<(&RepeatedField, &Children), Changed<Children>>,
+    q_row: Query<&Children, With<RepeatedRow>>,
+    mut q_button: Query<
+        (&mut Style, Has<AddRowButton>),
+        Or<(With<AddRowButton>, With<RemoveRowButton>)>,
+    >,
+) {
+    for (repeated, children) in &q_field {
+        let rows = children
+            .iter()
+            .filter_map(|child| q_row.get(*child).ok())
+            .collect::<Vec<_>>();
+
+        let buttons = children
+            .iter()
+            .chain(rows.iter().flat_map(|row| row.iter()));
+        for button in buttons {
+            let Ok((mut style, add)) = q_button.get_mut(*button) else {
+                continue;
+            };
+            let visible = if add {
+                rows.len() < repeated.max
+            } else {
+                rows.len() > repeated.min
+            };
+            style.display = if visible {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+use crate::prelude::{
+    ButtonPressEvent, ButtonRole, CheckboxValue, ColorPickerValue, FormElementDirty,
+    FormElementTouched, FormEvent, GenericFormEvent, SelectValue, SliderValue, TagInputValue,
+    TextInputValue,
+};
+
+/// A Bevy `Plugin` resetting forms when a [`FormEvent::Reset`] is sent or a button with the
+/// [`ButtonRole::Reset`] is pressed.
+///
+/// The elements of the form get back the value they had when they were spawned, including values
+/// the form was filled with on spawn. The dirty and touched markers are removed and the elements are
+/// validated again, so the validity of the form matches the restored values.
+pub struct FormResetPlugin;
+
+impl Plugin for FormResetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                (
+                    capture::<TextInputValue>,
+                    capture::<ColorPickerValue>,
+                    capture::<SelectValue>,
+                    capture::<SliderValue>,
+                    capture::<TagInputValue>,
+                    capture::<CheckboxValue>,
+                ),
+                reset_button,
+                reset.after(reset_button),
+            ),
+        );
+    }
+}
+
+/// The value an element is reset to. Captured when the value of the element is added.
+///
+/// Replace it to change what a reset restores, e.g. after the form was applied.
+#[derive(Component, Clone, Debug)]
+pub struct FormElementResetValue<T: Component + Clone>(pub T);
+
+/// Marker component indicating that the value of the element was restored, so the change does not
+/// make the element dirty.
+#[derive(Component)]
+pub(crate) struct FormElementReset;
+
+#[allow(clippy::needless_pass_by_value)]
+fn capture<T: Component + Clone>(mut commands: Commands, q_added: Query<(Entity, &T), Added<T>>) {
+    for (entity, value) in &q_added {
+        commands
+            .entity(entity)
+            .insert(FormElementResetValue(value.clone()));
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn reset_button(
+    mut ev_button: EventReader<ButtonPressEvent>,
+    mut ev_form: EventWriter<GenericFormEvent>,
+) {
+    for ev in ev_button.read() {
+        if let (ButtonRole::Reset, Some(form)) = (&ev.role, ev.button.form) {
+            ev_form.send(GenericFormEvent {
+                form: FormEvent::Reset(form),
+            });
+        }
+    }
+}
+
+/// Restores the value of the element. Returns false if the element has no value of the type.
+fn restore<T: Component + Clone>(
+    q_value: &mut Query<(&mut T, &FormElementResetValue<T>)>,
+    entity: Entity,
+) -> bool {
+    let Ok((mut value, reset_value)) = q_value.get_mut(entity) else {
+        return false;
+    };
+    *value = reset_value.0.clone();
+    true
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub(crate) fn reset(
+    mut commands: Commands,
+    mut ev_form: EventReader<GenericFormEvent>,
+    q_children: Query<&Children>,
+    mut q_values: ParamSet<(
+        Query<(&mut TextInputValue, &FormElementResetValue<TextInputValue>)>,
+        Query<(
+            &mut ColorPickerValue,
+            &FormElementResetValue<ColorPickerValue>,
+        )>,
+        Query<(&mut SelectValue, &FormElementResetValue<SelectValue>)>,
+        Query<(&mut SliderValue, &FormElementResetValue<SliderValue>)>,
+        Query<(&mut TagInputValue, &FormElementResetValue<TagInputValue>)>,
+        Query<(&mut CheckboxValue, &FormElementResetValue<CheckboxValue>)>,
+    )>,
+) {
+    for ev in ev_form.read() {
+        let FormEvent::Reset(form) = ev.form else {
+            continue;
+        };
+
+        for entity in q_children.iter_descendants(form) {
+            let restored = restore(&mut q_values.p0(), entity)
+                || restore(&mut q_values.p1(), entity)
+                || restore(&mut q_values.p2(), entity)
+                || restore(&mut q_values.p3(), entity)
+                || restore(&mut q_values.p4(), entity)
+                || restore(&mut q_values.p5(), entity);
+
+            if restored {
+                commands
+                    .entity(entity)
+                    .remove::<(FormElementDirty, FormElementTouched)>()
+                    .insert(FormElementReset);
+            }
+        }
+    }
+}
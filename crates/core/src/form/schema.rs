@@ -0,0 +1,616 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{self, Display};
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+    utils::BoxedFuture,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::form::button_form_events;
+use crate::prelude::{
+    ButtonRole, ColorPickerBundle, Form, FormButtonBundle, FormElementOptional, FormElementOrder,
+    FormInvalid, FormStyles, FormThemed, FormTooltip, FormValid, FormValidator, FormValidators,
+    FormValues, NumberInputSettings, SelectBundle, SliderBundle, SliderSettings, TextAreaSettings,
+    TextInputBundle, TextInputSettings,
+};
+
+/// A Bevy `Plugin` loading [`FormSchema`] assets and spawning the forms of [`FormSchemaHandle`]s.
+pub struct FormSchemaPlugin;
+
+impl Plugin for FormSchemaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<FormSchema>()
+            .register_asset_loader(FormSchemaLoader)
+            .add_systems(Update, button_form_events::<FormSchemaHandle>)
+            // Rebuilding despawns the inputs, so it runs after the systems setting them up.
+            .add_systems(PostUpdate, build);
+    }
+}
+
+/// A form described by an asset, e.g. a `.form.ron` or `.form.json` file.
+///
+/// Spawn a [`FormSchemaHandle`] to show the form. The form is rebuilt whenever the asset changes,
+/// so with Bevy's `file_watcher` feature edits to the file show up while the app is running.
+///
+/// # Example
+///
+/// ```ron
+/// #![enable(implicit_some)]
+/// (
+///     submit: "Save",
+///     fields: [
+///         (name: "name", label: "Name", validators: [MinLen(3)]),
+///         (name: "email", label: "Email", optional: true, validators: [Email]),
+///         (name: "bio", label: "About you", kind: TextArea(rows: 5)),
+///         (name: "role", label: "Role", kind: Select(options: ["Player", "Admin"])),
+///     ],
+/// )
+/// ```
+#[derive(Asset, TypePath, Clone, Default, Debug, Serialize, Deserialize)]
+pub struct FormSchema {
+    /// The fields of the form in order.
+    pub fields: Vec<FormSchemaField>,
+    /// The text of the submit button. No button is shown if it is not set.
+    #[serde(default)]
+    pub submit: Option<String>,
+    /// The text of the cancel button. No button is shown if it is not set.
+    #[serde(default)]
+    pub cancel: Option<String>,
+}
+
+/// A field of a [`FormSchema`].
+#[derive(Clone, Default, Debug, Serialize, Deserialize)]
+pub struct FormSchemaField {
+    /// The name the value of the field is reported under.
+    pub name: String,
+    /// The label shown above the input.
+    #[serde(default)]
+    pub label: Option<String>,
+    /// The help text shown below the input.
+    #[serde(default)]
+    pub help: Option<String>,
+    /// The tooltip shown while the input is hovered.
+    #[serde(default)]
+    pub tooltip: Option<String>,
+    /// Whether the field may be left empty.
+    #[serde(default)]
+    pub optional: bool,
+    /// The input of the field. Defaults to a text box.
+    #[serde(default)]
+    pub kind: FormSchemaFieldKind,
+    /// Validators checking the value of text inputs.
+    #[serde(default)]
+    pub validators: Vec<FormSchemaValidator>,
+}
+
+/// The input of a [`FormSchemaField`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum FormSchemaFieldKind {
+    /// A single-line text input.
+    TextBox {
+        /// The text shown while the input is empty.
+        #[serde(default)]
+        placeholder: Option<String>,
+        /// The initial value.
+        #[serde(default)]
+        default: Option<String>,
+        /// The character shown instead of the entered ones, e.g. for passwords.
+        #[serde(default)]
+        mask: Option<char>,
+    },
+    /// A multi-line text input.
+    TextArea {
+        /// The text shown while the input is empty.
+        #[serde(default)]
+        placeholder: Option<String>,
+        /// The number of visible lines. Defaults to `3`.
+        #[serde(default)]
+        rows: Option<usize>,
+        /// The maximum number of lines that can be entered.
+        #[serde(default)]
+        max_lines: Option<usize>,
+    },
+    /// A numeric text input.
+    Number {
+        /// The minimum value.
+        #[serde(default)]
+        min: Option<f64>,
+        /// The maximum value.
+        #[serde(default)]
+        max: Option<f64>,
+        /// The initial value.
+        #[serde(default)]
+        default: Option<f64>,
+    },
+    /// A select offering the given options.
+    Select {
+        /// The options to choose from.
+        options: Vec<String>,
+        /// The text shown while no option is selected.
+        #[serde(default)]
+        placeholder: Option<String>,
+        /// The index of the initially selected option.
+        #[serde(default)]
+        default: Option<usize>,
+    },
+    /// A slider.
+    Slider {
+        /// The minimum value.
+        min: f64,
+        /// The maximum value.
+        max: f64,
+        /// The amount the value changes by with each step. Defaults to a tenth of the range.
+        #[serde(default)]
+        step: Option<f64>,
+        /// The initial value.
+        #[serde(default)]
+        default: Option<f64>,
+    },
+    /// A color picker, initially white.
+    ColorPicker,
+}
+
+impl Default for FormSchemaFieldKind {
+    fn default() -> Self {
+        FormSchemaFieldKind::TextBox {
+            placeholder: None,
+            default: None,
+            mask: None,
+        }
+    }
+}
+
+/// A validator of a [`FormSchemaField`]. See [`FormValidator`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum FormSchemaValidator {
+    /// The value needs to have at least the given number of characters.
+    MinLen(usize),
+    /// The value needs to have at most the given number of characters.
+    MaxLen(usize),
+    /// The value needs to match the regular expression. Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    Regex(String),
+    /// The value needs to be an email address.
+    Email,
+    /// The value needs to be a URL with a scheme.
+    Url,
+}
+
+impl FormSchemaValidator {
+    /// Converts the validator into a [`FormValidator`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormSchemaLoaderError::Regex`] for an invalid regular expression.
+    pub fn validator(&self) -> Result<FormValidator, FormSchemaLoaderError> {
+        match self {
+            FormSchemaValidator::MinLen(len) => Ok(FormValidator::MinLen(*len)),
+            FormSchemaValidator::MaxLen(len) => Ok(FormValidator::MaxLen(*len)),
+            #[cfg(feature = "regex")]
+            FormSchemaValidator::Regex(pattern) => {
+                FormValidator::regex(pattern).map_err(FormSchemaLoaderError::Regex)
+            }
+            FormSchemaValidator::Email => Ok(FormValidator::Email),
+            FormSchemaValidator::Url => Ok(FormValidator::Url),
+        }
+    }
+}
+
+/// Spawns the form described by the [`FormSchema`] as children of the entity.
+///
+/// The entity becomes a [`Form`], sending [`GenericFormEvent`](crate::prelude::GenericFormEvent)s
+/// when its buttons are pressed. The values are read using [`FormSchemaInputs`].
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         FormSchemaHandle(asset_server.load("settings.form.ron")),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Default, Debug)]
+pub struct FormSchemaHandle(pub Handle<FormSchema>);
+
+/// The inputs of a form spawned from a [`FormSchema`] by the name of their field.
+#[derive(Component, Clone, Default, Debug)]
+pub struct FormSchemaInputs(pub Vec<(String, Entity)>);
+
+impl FormSchemaInputs {
+    /// Returns the input of the field with the given name.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Entity> {
+        self.0
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, input)| *input)
+    }
+
+    /// Reads the values of the inputs by the name of their field.
+    #[must_use]
+    pub fn values(&self, values: &FormValues) -> BTreeMap<String, FormSchemaValue> {
+        self.0
+            .iter()
+            .filter_map(|(field, input)| {
+                Some((field.clone(), FormSchemaValue::read(values, *input)?))
+            })
+            .collect()
+    }
+}
+
+/// The value of an input of a form spawned from a [`FormSchema`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormSchemaValue {
+    /// The text of text boxes, text areas and number inputs.
+    Text(String),
+    /// The value of a slider.
+    Number(f64),
+    /// The index of the selected option of a select.
+    Select(Option<usize>),
+    /// The color of a color picker.
+    Color(Color),
+}
+
+impl FormSchemaValue {
+    fn read(values: &FormValues, input: Entity) -> Option<Self> {
+        if let Ok(value) = values.text_input.get(input) {
+            Some(FormSchemaValue::Text(value.0.clone()))
+        } else if let Ok(value) = values.slider.get(input) {
+            Some(FormSchemaValue::Number(value.0))
+        } else if let Ok(value) = values.select.get(input) {
+            Some(FormSchemaValue::Select(value.0))
+        } else {
+            values
+                .color_picker
+                .get(input)
+                .ok()
+                .map(|value| FormSchemaValue::Color(value.0))
+        }
+    }
+}
+
+/// Loads [`FormSchema`]s from `.form.ron` and `.form.json` files.
+#[derive(Default)]
+pub struct FormSchemaLoader;
+
+/// Error loading a [`FormSchema`].
+#[derive(Debug)]
+pub enum FormSchemaLoaderError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file is not a valid RON form schema.
+    Ron(ron::error::SpannedError),
+    /// The file is not a valid JSON form schema.
+    Json(serde_json::Error),
+    /// A [`FormSchemaValidator::Regex`] of the schema is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    Regex(regex::Error),
+}
+
+impl Display for FormSchemaLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormSchemaLoaderError::Io(error) => write!(f, "could not read form schema: {error}"),
+            FormSchemaLoaderError::Ron(error) => write!(f, "invalid form schema: {error}"),
+            FormSchemaLoaderError::Json(error) => write!(f, "invalid form schema: {error}"),
+            #[cfg(feature = "regex")]
+            FormSchemaLoaderError::Regex(error) => write!(f, "invalid form validator: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for FormSchemaLoaderError {}
+
+impl AssetLoader for FormSchemaLoader {
+    type Asset = FormSchema;
+    type Settings = ();
+    type Error = FormSchemaLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<FormSchema, FormSchemaLoaderError>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(FormSchemaLoaderError::Io)?;
+
+            let json = load_context
+                .path()
+                .extension()
+                .is_some_and(|extension| extension == "json");
+            let schema: FormSchema = if json {
+                serde_json::from_slice(&bytes).map_err(FormSchemaLoaderError::Json)?
+            } else {
+                ron::de::from_bytes(&bytes).map_err(FormSchemaLoaderError::Ron)?
+            };
+
+            // Invalid validators fail the load instead of being skipped when the form is spawned.
+            for field in &schema.fields {
+                for validator in &field.validators {
+                    validator.validator()?;
+                }
+            }
+
+            Ok(schema)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["form.ron", "form.json"]
+    }
+}
+
+/// Spawns the form of new or changed handles and rebuilds it when the asset is modified.
+#[allow(clippy::needless_pass_by_value)]
+fn build(
+    mut commands: Commands,
+    styles: FormStyles,
+    schemas: Res<Assets<FormSchema>>,
+    mut ev_asset: EventReader<AssetEvent<FormSchema>>,
+    q_form: Query<(Entity, Ref<FormSchemaHandle>)>,
+) {
+    let changed = ev_asset
+        .read()
+        .filter_map(|ev| match ev {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::LoadedWithDependencies { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    for (entity, handle) in &q_form {
+        if !handle.is_changed() && !changed.contains(&handle.0.id()) {
+            continue;
+        }
+        // Handles of schemas that are still loading are built once the asset is loaded.
+        let Some(schema) = schemas.get(&handle.0) else {
+            continue;
+        };
+
+        spawn_form(&mut commands, &styles, entity, schema);
+    }
+}
+
+fn spawn_form(commands: &mut Commands, styles: &FormStyles, form: Entity, schema: &FormSchema) {
+    let text_style = styles.theme.text_style.clone();
+    let mut inputs = Vec::new();
+    let mut children = Vec::new();
+
+    for (order, field) in schema.fields.iter().enumerate() {
+        if let Some(label) = &field.label {
+            children.push(
+                commands
+                    .spawn((
+                        TextBundle::from_section(label.clone(), text_style.clone()),
+                        Name::new("label"),
+                    ))
+                    .id(),
+            );
+        }
+
+        let input = spawn_input(commands, &field.kind, text_style.clone());
+        let mut input_commands = commands.entity(input);
+        input_commands.insert(FormElementOrder(order));
+        if field.optional {
+            input_commands.insert(FormElementOptional);
+        }
+        if !field.validators.is_empty() {
+            input_commands.insert(FormValidators(
+                field
+                    .validators
+                    .iter()
+                    .filter_map(|validator| {
+                        // Only schemas added without the loader can get here.
+                        validator
+                            .validator()
+                            .map_err(|err| warn!("Skipping validator of {}: {err}", field.name))
+                            .ok()
+                    })
+                    .collect(),
+            ));
+        }
+        if let Some(tooltip) = &field.tooltip {
+            input_commands.insert(FormTooltip(tooltip.clone()));
+        }
+        children.push(input);
+        inputs.push((field.name.clone(), input));
+
+        if let Some(help) = &field.help {
+            children.push(
+                commands
+                    .spawn((
+                        TextBundle::from_section(
+                            help.clone(),
+                            styles.theme.help_text_style.clone(),
+                        ),
+                        Name::new("help"),
+                    ))
+                    .id(),
+            );
+        }
+    }
+
+    let actions = commands
+        .spawn((NodeBundle::default(), Name::new("action-row")))
+        .id();
+    let buttons = [
+        (&schema.cancel, ButtonRole::Cancel),
+        (&schema.submit, ButtonRole::Submit),
+    ];
+    for (text, role) in buttons {
+        let Some(text) = text else {
+            continue;
+        };
+        let button = commands
+            .spawn(
+                FormButtonBundle::new(text.clone())
+                    .with_form(form)
+                    .with_role(role),
+            )
+            .id();
+        commands.entity(actions).add_child(button);
+    }
+    children.push(actions);
+
+    commands
+        .entity(form)
+        .despawn_descendants()
+        .remove::<FormInvalid>()
+        .insert((Form, FormValid, FormSchemaInputs(inputs), Name::new("form")))
+        .push_children(&children);
+}
+
+/// The style of the node of the input, matching the inputs generated by the `form_struct` macro.
+fn node_style(kind: &FormSchemaFieldKind) -> Style {
+    match kind {
+        FormSchemaFieldKind::Select { .. } => Style {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            ..default()
+        },
+        FormSchemaFieldKind::Slider { .. } => Style {
+            width: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        },
+        FormSchemaFieldKind::ColorPicker => Style {
+            flex_direction: FlexDirection::Column,
+            ..default()
+        },
+        _ => Style {
+            width: Val::Percent(100.0),
+            ..default()
+        },
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn spawn_input(
+    commands: &mut Commands,
+    kind: &FormSchemaFieldKind,
+    text_style: TextStyle,
+) -> Entity {
+    let mut input = commands.spawn(NodeBundle {
+        style: node_style(kind),
+        ..default()
+    });
+
+    match kind {
+        FormSchemaFieldKind::TextBox {
+            placeholder,
+            default: value,
+            mask,
+        } => {
+            let mut text_input = TextInputBundle::default()
+                .with_text_style(text_style)
+                .with_settings(TextInputSettings {
+                    mask_character: *mask,
+                    retain_on_submit: true,
+                    ..default()
+                });
+            if let Some(placeholder) = placeholder {
+                text_input = text_input.with_placeholder(placeholder.clone(), None);
+            }
+            if let Some(value) = value {
+                text_input = text_input.with_value(value.clone());
+            }
+            input.insert((text_input, FormThemed));
+        }
+        FormSchemaFieldKind::TextArea {
+            placeholder,
+            rows,
+            max_lines,
+        } => {
+            let mut text_input = TextInputBundle::default()
+                .with_text_style(text_style)
+                .with_settings(TextInputSettings {
+                    retain_on_submit: true,
+                    ..default()
+                });
+            if let Some(placeholder) = placeholder {
+                text_input = text_input.with_placeholder(placeholder.clone(), None);
+            }
+            input.insert((
+                text_input,
+                TextAreaSettings {
+                    rows: rows.unwrap_or(TextAreaSettings::default().rows),
+                    max_lines: *max_lines,
+                },
+                FormThemed,
+            ));
+        }
+        FormSchemaFieldKind::Number {
+            min,
+            max,
+            default: value,
+        } => {
+            let mut text_input = TextInputBundle::default()
+                .with_text_style(text_style)
+                .with_settings(TextInputSettings {
+                    retain_on_submit: true,
+                    ..default()
+                });
+            if let Some(value) = value {
+                text_input = text_input.with_value(value.to_string());
+            }
+            input.insert((
+                text_input,
+                NumberInputSettings {
+                    min: *min,
+                    max: *max,
+                    ..default()
+                },
+                FormThemed,
+            ));
+        }
+        FormSchemaFieldKind::Select {
+            options,
+            placeholder,
+            default: value,
+        } => {
+            let mut select = SelectBundle::new(options.clone()).with_text_style(text_style);
+            if let Some(placeholder) = placeholder {
+                select = select.with_placeholder(placeholder.clone());
+            }
+            if let Some(value) = value {
+                select = select.with_value(*value);
+            }
+            input.insert((select, FormThemed));
+        }
+        FormSchemaFieldKind::Slider {
+            min,
+            max,
+            step,
+            default: value,
+        } => {
+            let settings = SliderSettings {
+                min: *min,
+                max: *max,
+                step: step.unwrap_or((max - min) / 10.0),
+            };
+            let mut slider = SliderBundle::new(settings).with_text_style(text_style);
+            if let Some(value) = value {
+                slider = slider.with_value(*value);
+            }
+            input.insert(slider);
+        }
+        FormSchemaFieldKind::ColorPicker => {
+            input.insert(ColorPickerBundle::new(Color::WHITE));
+        }
+    }
+
+    input.id()
+}
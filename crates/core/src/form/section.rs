@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+
+/// A Bevy `Plugin` providing the systems required to collapse [`FormSection`]s.
+pub struct FormSectionPlugin;
+
+impl Plugin for FormSectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FormSectionStyle>()
+            .add_systems(Update, (toggle, collapse.after(toggle)))
+            .register_type::<FormSection>()
+            .register_type::<FormSectionHeader>()
+            .register_type::<CollapsibleSection>()
+            .register_type::<SectionCollapsed>();
+    }
+}
+
+/// Style of the sections of forms generated by the `form_struct` macro.
+/// Default is a header with `font_size` 24.0 and `color` `Color::BLACK`, sections are separated by
+/// 24px.
+#[derive(Resource, Debug)]
+pub struct FormSectionStyle {
+    /// The text style of the section header.
+    pub header_text_style: TextStyle,
+    /// The margin around the section header.
+    pub header_margin: UiRect,
+    /// The margin around the section.
+    pub margin: UiRect,
+}
+
+impl Default for FormSectionStyle {
+    fn default() -> Self {
+        FormSectionStyle {
+            header_text_style: TextStyle {
+                font_size: 24.0,
+                color: Color::BLACK,
+                ..default()
+            },
+            header_margin: UiRect::bottom(Val::Px(8.0)),
+            margin: UiRect::vertical(Val::Px(12.0)),
+        }
+    }
+}
+
+/// Marker component for a section of a form, containing a [`FormSectionHeader`] and the fields of
+/// the section.
+#[derive(Component, Debug, Default, Reflect)]
+pub struct FormSection;
+
+/// Marker component for the header of a [`FormSection`]. Needs to be a direct child of the section.
+#[derive(Component, Debug, Default, Reflect)]
+pub struct FormSectionHeader;
+
+/// Marker component for a [`FormSection`] that is collapsed and expanded by clicking its header.
+/// The header needs an `Interaction` to be clickable.
+#[derive(Component, Debug, Default, Reflect)]
+pub struct CollapsibleSection;
+
+/// Marker component for a collapsed [`FormSection`]. All children except the header are hidden.
+///
+/// Insert or remove it to collapse or expand a section.
+#[derive(Component, Debug, Default, Reflect)]
+pub struct SectionCollapsed;
+
+#[allow(clippy::needless_pass_by_value)]
+fn toggle(
+    mut commands: Commands,
+    q_header: Query<(&Interaction, &Parent), (Changed<Interaction>, With<FormSectionHeader>)>,
+    q_section: Query<Has<SectionCollapsed>, With<CollapsibleSection>>,
+) {
+    for (interaction, parent) in &q_header {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Ok(collapsed) = q_section.get(parent.get()) else {
+            continue;
+        };
+
+        if collapsed {
+            commands.entity(parent.get()).remove::<SectionCollapsed>();
+        } else {
+            commands.entity(parent.get()).insert(SectionCollapsed);
+        }
+    }
+}
+
+/// Hides the children of collapsed sections, including children added while collapsed.
+#[allow(clippy::needless_pass_by_value)]
+fn collapse(
+    q_collapsed: Query<
+        &Children,
+        (
+            With<FormSection>,
+            Or<(Added<SectionCollapsed>, Changed<Children>)>,
+            With<SectionCollapsed>,
+        ),
+    >,
+    mut ev_expanded: RemovedComponents<SectionCollapsed>,
+    q_expanded: Query<&Children, (With<FormSection>, Without<SectionCollapsed>)>,
+    mut q_style: Query<&mut Style, Without<FormSectionHeader>>,
+) {
+    for children in &q_collapsed {
+        set_display(children, Display::None, &mut q_style);
+    }
+
+    for children in q_expanded.iter_many(ev_expanded.read()) {
+        set_display(children, Display::Flex, &mut q_style);
+    }
+}
+
+fn set_display(
+    children: &Children,
+    display: Display,
+    q_style: &mut Query<&mut Style, Without<FormSectionHeader>>,
+) {
+    let mut iter = q_style.iter_many_mut(children);
+    while let Some(mut style) = iter.fetch_next() {
+        if style.display != display {
+            style.display = display;
+        }
+    }
+}
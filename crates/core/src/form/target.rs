@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use bevy::{
+    ecs::{system::SystemState, world::EntityWorldMut},
+    prelude::*,
+};
+
+use crate::prelude::{FormEvent, GeneratedForm};
+
+/// A Bevy `Plugin` letting a form generated by the `form_struct` macro edit the components of its
+/// [`FormTarget`], e.g. the name and stats of the selected NPC in a level editor.
+///
+/// Each binding maps a component of the target to fields of the form. The inputs are filled from
+/// the target when the form is spawned or its target changes, starting from `F::default()`.
+/// Submitting or applying the form writes the values back to the components of the target.
+/// Components the target does not have are skipped.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// #[form_struct(submit = "Save")]
+/// #[derive(Debug, Clone, Default)]
+/// pub struct NpcData {
+///     #[text_box(placeholder = "Name")]
+///     pub name: String,
+///     #[text_box(placeholder = "Health")]
+///     pub health: u32,
+/// }
+///
+/// fn build(app: &mut App) {
+///     app.add_plugins((
+///         NpcDataFormPlugin,
+///         FormTargetPlugin::<NpcData>::default()
+///             .bind(
+///                 |data: &mut NpcData, name: &Name| data.name = name.to_string(),
+///                 |data, name: &mut Name| *name = Name::new(data.name.clone()),
+///             )
+///             .bind(
+///                 |data: &mut NpcData, health: &Health| data.health = health.0,
+///                 |data, health: &mut Health| health.0 = data.health,
+///             ),
+///     ));
+/// }
+///
+/// fn edit(mut commands: Commands, npc: Entity) {
+///     let form = commands.spawn_form::<NpcData>();
+///     commands.entity(form).insert(FormTarget(npc));
+/// }
+/// ```
+pub struct FormTargetPlugin<F> {
+    bindings: Vec<Arc<TargetBinding<F>>>,
+}
+
+impl<F> Default for FormTargetPlugin<F> {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+        }
+    }
+}
+
+impl<F: 'static> FormTargetPlugin<F> {
+    /// Binds the component `C` of the target. `read` copies the component into the form data and
+    /// `write` copies the form data into the component.
+    #[must_use]
+    pub fn bind<C: Component>(
+        mut self,
+        read: impl Fn(&mut F, &C) + Send + Sync + 'static,
+        write: impl Fn(&F, &mut C) + Send + Sync + 'static,
+    ) -> Self {
+        self.bindings.push(Arc::new(TargetBinding {
+            read: Box::new(move |data, entity| {
+                if let Some(component) = entity.get::<C>() {
+                    read(data, component);
+                }
+            }),
+            write: Box::new(move |data, entity| {
+                if let Some(mut component) = entity.get_mut::<C>() {
+                    write(data, &mut component);
+                }
+            }),
+        }));
+        self
+    }
+}
+
+impl<F: GeneratedForm + Clone + Default + Send + Sync + 'static> Plugin for FormTargetPlugin<F> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TargetBindings(self.bindings.clone()))
+            .add_systems(Update, (fill::<F>, write::<F>));
+    }
+}
+
+/// The entity whose components the form edits. See [`FormTargetPlugin`].
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub struct FormTarget(pub Entity);
+
+/// A component of the target bound to fields of the form.
+struct TargetBinding<F> {
+    read: Box<dyn Fn(&mut F, &EntityRef) + Send + Sync>,
+    write: Box<dyn Fn(&F, &mut EntityWorldMut) + Send + Sync>,
+}
+
+#[derive(Resource)]
+struct TargetBindings<F>(Vec<Arc<TargetBinding<F>>>);
+
+/// Fills the inputs from the target when the form is spawned or its target changes.
+#[allow(clippy::needless_pass_by_value)]
+fn fill<F: GeneratedForm + Default + Send + Sync + 'static>(
+    mut commands: Commands,
+    q_form: Query<(Entity, Ref<FormTarget>, Ref<F::Fields>), With<F::Marker>>,
+    bindings: Res<TargetBindings<F>>,
+) {
+    for (form, target, fields) in &q_form {
+        if !target.is_changed() && !fields.is_added() {
+            continue;
+        }
+
+        let target = target.0;
+        let bindings = bindings.0.clone();
+        commands.add(move |world: &mut World| {
+            let Some(entity) = world.get_entity(target) else {
+                return;
+            };
+            let mut data = F::default();
+            for binding in &bindings {
+                (binding.read)(&mut data, &entity);
+            }
+
+            let mut state = SystemState::<(Commands, Query<&F::Fields>)>::new(world);
+            let (mut commands, q_fields) = state.get_mut(world);
+            if let Ok(fields) = q_fields.get(form) {
+                F::set_form_data(fields, &mut commands, &data);
+            }
+            state.apply(world);
+        });
+    }
+}
+
+/// Writes the data of submitted forms to the components of their target.
+#[allow(clippy::needless_pass_by_value)]
+fn write<F: GeneratedForm + Clone + Send + Sync + 'static>(
+    mut commands: Commands,
+    mut ev_form: EventReader<F::Event>,
+    q_form: Query<&FormTarget, With<F::Marker>>,
+    bindings: Res<TargetBindings<F>>,
+) {
+    for ev in ev_form.read() {
+        let (FormEvent::Submit(data) | FormEvent::Apply(data)) = F::form_event(ev) else {
+            continue;
+        };
+        let Ok(target) = q_form.get(F::form(ev)) else {
+            continue;
+        };
+
+        let target = target.0;
+        let data = data.clone();
+        let bindings = bindings.0.clone();
+        commands.add(move |world: &mut World| {
+            let Some(mut entity) = world.get_entity_mut(target) else {
+                return;
+            };
+            for binding in &bindings {
+                (binding.write)(&data, &mut entity);
+            }
+        });
+    }
+}
@@ -0,0 +1,367 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use bevy::{prelude::*, tasks::AsyncComputeTaskPool};
+
+use crate::form_elements::text_input;
+use crate::prelude::{
+    FormElementDisabled, FormElementInvalid, FormElementPending, FormElementValid,
+    FormValidationError, TextInputTextStyle, TextInputValue,
+};
+
+/// A Bevy `Plugin` running the [`FormValidators`] of text inputs.
+pub struct FormValidationPlugin;
+
+impl Plugin for FormValidationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                validate.after(text_input::validate),
+                create_async,
+                start_async.after(validate).after(create_async),
+                finish_async,
+            ),
+        );
+    }
+}
+
+/// A validator checking the value of a text input.
+#[derive(Debug, Clone)]
+pub enum FormValidator {
+    /// The value needs to have at least the given number of characters.
+    MinLen(usize),
+    /// The value needs to have at most the given number of characters.
+    MaxLen(usize),
+    /// The value needs to match the regular expression. Requires the `regex` feature.
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+    /// The value needs to be an email address.
+    Email,
+    /// The value needs to be a URL with a scheme, e.g. `https://example.com`.
+    Url,
+    /// The function is called with the value and returns the error message if it is invalid.
+    Custom(fn(&str) -> Result<(), String>),
+}
+
+impl FormValidator {
+    /// Creates a validator matching the value against the pattern.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    pub fn regex(pattern: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(pattern).map(FormValidator::Regex)
+    }
+
+    /// Checks the value of the form element.
+    ///
+    /// # Errors
+    ///
+    /// Returns the validation error if the value does not pass the validator. Custom validators
+    /// report a [`FormValidationError::Custom`], all others a [`FormValidationError::Code`].
+    pub fn validate(&self, entity: Entity, value: &str) -> Result<(), FormValidationError> {
+        let (code, message) = match self {
+            FormValidator::MinLen(len) if value.chars().count() < *len => {
+                ("min_len", format!("Must be at least {len} characters long"))
+            }
+            FormValidator::MaxLen(len) if value.chars().count() > *len => {
+                ("max_len", format!("Must be at most {len} characters long"))
+            }
+            #[cfg(feature = "regex")]
+            FormValidator::Regex(regex) if !regex.is_match(value) => {
+                ("regex", "Invalid format".to_string())
+            }
+            FormValidator::Email if !is_email(value) => {
+                ("email", "Invalid email address".to_string())
+            }
+            FormValidator::Url if !is_url(value) => ("url", "Invalid URL".to_string()),
+            FormValidator::Custom(validator) => {
+                return validator(value)
+                    .map_err(|message| FormValidationError::Custom(entity, message));
+            }
+            _ => return Ok(()),
+        };
+
+        Err(FormValidationError::Code(entity, code, message))
+    }
+}
+
+/// Validators checking the value of a text input.
+///
+/// Add this next to a [`TextInputBundle`](crate::prelude::TextInputBundle). Whenever the value
+/// changes the validators are run in order, the first failing one marks the input as invalid.
+/// Empty values are left to the required check.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default(),
+///         FormValidators(vec![FormValidator::MinLen(3), FormValidator::Email]),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Default, Debug)]
+pub struct FormValidators(pub Vec<FormValidator>);
+
+impl FormValidators {
+    /// Runs the validators in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first validator the value does not pass.
+    pub fn validate(&self, entity: Entity, value: &str) -> Result<(), FormValidationError> {
+        self.0
+            .iter()
+            .try_for_each(|validator| validator.validate(entity, value))
+    }
+}
+
+/// The future returned by an asynchronous validator.
+pub type AsyncValidation = Pin<Box<dyn Future<Output = Result<(), String>> + Send>>;
+
+/// An asynchronous validator of a text input, e.g. checking whether a username is available.
+///
+/// Add this next to a [`TextInputBundle`](crate::prelude::TextInputBundle). Whenever the value
+/// changes and passes the [`FormValidators`] of the input, the validator is run on the
+/// `AsyncComputeTaskPool`. Until it resolves the input is marked as [`FormElementPending`], shows
+/// "validating…" and the form can not be submitted. An error marks the input as invalid using a
+/// [`FormValidationError::Custom`]. Results for outdated values are discarded.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// async fn available(username: String) -> Result<(), String> {
+///     if username == "admin" {
+///         Err("Username is taken".to_string())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default(),
+///         FormAsyncValidator::new(available),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone)]
+pub struct FormAsyncValidator(Arc<dyn Fn(String) -> AsyncValidation + Send + Sync>);
+
+impl FormAsyncValidator {
+    /// Creates a validator calling the function with the value.
+    pub fn new<F, Fut>(validator: F) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        Self(Arc::new(move |value| Box::pin(validator(value))))
+    }
+}
+
+/// The result of the running validation, written by the task.
+type AsyncValidationResult = Arc<Mutex<Option<Result<(), String>>>>;
+
+/// The "validating…" indicator and the result of the running validation.
+#[derive(Component)]
+struct AsyncValidationState {
+    indicator: Entity,
+    result: Option<AsyncValidationResult>,
+}
+
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !value.chars().any(char::is_whitespace)
+        && !domain.contains('@')
+        && domain.split('.').count() > 1
+        && domain.split('.').all(|part| !part.is_empty())
+}
+
+fn is_url(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return false;
+    };
+
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    scheme
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !host.is_empty()
+        && !value.chars().any(char::is_whitespace)
+}
+
+/// Runs after the required check, so a failing validator replaces its result.
+#[allow(clippy::needless_pass_by_value)]
+fn validate(
+    mut commands: Commands,
+    q_validators: Query<
+        (Entity, &FormValidators, &TextInputValue),
+        (
+            Without<FormElementDisabled>,
+            Or<(Changed<TextInputValue>, Changed<FormValidators>)>,
+        ),
+    >,
+) {
+    for (entity, validators, value) in &q_validators {
+        let value = value.0.trim();
+        if value.is_empty() {
+            continue;
+        }
+
+        if let Err(error) = validators.validate(entity, value) {
+            commands
+                .entity(entity)
+                .insert(FormElementInvalid(error))
+                .remove::<FormElementValid>();
+        } else {
+            commands
+                .entity(entity)
+                .remove::<FormElementInvalid>()
+                .insert(FormElementValid);
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn create_async(
+    mut commands: Commands,
+    q_added: Query<(Entity, &TextInputTextStyle), Added<FormAsyncValidator>>,
+) {
+    for (entity, text_style) in &q_added {
+        let mut style = text_style.0.clone();
+        style.color = style.color.with_a(style.color.a() * 0.5);
+
+        let indicator = commands
+            .spawn((
+                TextBundle {
+                    style: Style {
+                        display: Display::None,
+                        margin: UiRect::left(Val::Auto),
+                        ..default()
+                    },
+                    ..TextBundle::from_section("validating…", style)
+                },
+                Name::new("validating"),
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(AsyncValidationState {
+                indicator,
+                result: None,
+            })
+            .add_child(indicator);
+    }
+}
+
+/// Starts the validation of changed values that pass the synchronous validators.
+fn start_async(
+    mut commands: Commands,
+    mut q_validator: Query<
+        (
+            Entity,
+            &FormAsyncValidator,
+            &TextInputValue,
+            Option<&FormValidators>,
+            &mut AsyncValidationState,
+            Has<FormElementDisabled>,
+        ),
+        Changed<TextInputValue>,
+    >,
+    mut q_style: Query<&mut Style>,
+) {
+    for (entity, validator, value, validators, mut state, disabled) in &mut q_validator {
+        let value = value.0.trim().to_string();
+        let skip = disabled
+            || value.is_empty()
+            || validators.is_some_and(|validators| validators.validate(entity, &value).is_err());
+
+        // Replacing the result discards the result of a validation that is still running.
+        state.result = if skip {
+            commands.entity(entity).remove::<FormElementPending>();
+            None
+        } else {
+            let result = AsyncValidationResult::default();
+            let task_result = result.clone();
+            let validation = (validator.0)(value);
+            AsyncComputeTaskPool::get()
+                .spawn(async move {
+                    let validation = validation.await;
+                    if let Ok(mut result) = task_result.lock() {
+                        *result = Some(validation);
+                    }
+                })
+                .detach();
+
+            commands.entity(entity).insert(FormElementPending);
+            Some(result)
+        };
+
+        if let Ok(mut style) = q_style.get_mut(state.indicator) {
+            style.display = if state.result.is_some() {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+    }
+}
+
+/// Applies the results of finished validations.
+fn finish_async(
+    mut commands: Commands,
+    mut q_validator: Query<(Entity, &mut AsyncValidationState)>,
+    mut q_style: Query<&mut Style>,
+) {
+    for (entity, mut state) in &mut q_validator {
+        let Some(result) = state
+            .result
+            .as_ref()
+            .and_then(|result| result.lock().ok()?.take())
+        else {
+            continue;
+        };
+
+        state.result = None;
+        if let Ok(mut style) = q_style.get_mut(state.indicator) {
+            style.display = Display::None;
+        }
+
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.remove::<FormElementPending>();
+        match result {
+            Ok(()) => {
+                entity_commands
+                    .remove::<FormElementInvalid>()
+                    .insert(FormElementValid);
+            }
+            Err(message) => {
+                entity_commands
+                    .insert(FormElementInvalid(FormValidationError::Custom(
+                        entity, message,
+                    )))
+                    .remove::<FormElementValid>();
+            }
+        }
+    }
+}
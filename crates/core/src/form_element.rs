@@ -1,27 +1,69 @@
 #![allow(clippy::module_name_repetitions)]
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*, utils::HashMap};
 
-use crate::form::{Form, FormInvalid, FormValid, FormValidationError};
+use crate::form::{
+    find_form_ancestor, form_keyboard,
+    reset::{self, FormElementReset},
+    Form, FormInvalid, FormValid, FormValidationError,
+};
+use crate::form_elements::text_input;
+use crate::prelude::{
+    ButtonPressEvent, ButtonRole, CheckboxValue, ColorPickerValue, SelectValue, SliderValue,
+    TagInputValue, TextInputValue,
+};
 
 /// Plugin for form elements.
 pub struct FormElementPlugin;
 
 impl Plugin for FormElementPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                form_element_touched,
-                form_element_invalid,
-                form_element_valid,
-                form_element_keyboard,
-            ),
-        )
-        .register_type::<FormElementDirty>()
-        .register_type::<FormElementValid>()
-        .register_type::<FormElementInvalid>()
-        .register_type::<FormElementTouched>()
-        .register_type::<FormElementOptional>();
+        app.init_resource::<FocusRingStyle>()
+            .init_resource::<ErrorTextStyle>()
+            .init_resource::<FormFocus>()
+            .init_resource::<FormKeyBindings>()
+            .add_event::<FieldValidityChanged>()
+            .add_event::<FormSubmitInvalid>()
+            .add_event::<FormElementFocused>()
+            .add_event::<FormElementBlurred>()
+            .add_systems(
+                Update,
+                (
+                    form_element_touched,
+                    form_element_dirty.after(reset::reset),
+                    form_element_invalid.after(text_input::validate),
+                    form_element_valid.after(text_input::validate),
+                    form_element_keyboard,
+                    form_element_disabled,
+                    dim_disabled,
+                    form_element_focus,
+                    focus_ring,
+                    sync_form_focus,
+                    report_on_blur
+                        .after(form_element_invalid)
+                        .after(form_element_valid),
+                    report_on_submit
+                        .after(form_element_invalid)
+                        .after(form_element_valid)
+                        .after(form_keyboard),
+                    error_text.after(report_on_blur).after(report_on_submit),
+                ),
+            )
+            .register_type::<FormElementDirty>()
+            .register_type::<FormElementValid>()
+            .register_type::<FormElementInvalid>()
+            .register_type::<FormElementTouched>()
+            .register_type::<FormElementOptional>()
+            .register_type::<FormElementPending>()
+            .register_type::<FormElementDisabled>()
+            .register_type::<ValidationMode>()
+            .register_type::<EnterKeyBehavior>()
+            .register_type::<FocusRingStyle>()
+            .register_type::<ErrorTextStyle>()
+            .register_type::<FormFocus>()
+            .register_type::<FormKeyBindings>()
+            .register_type::<FormKeyAction>()
+            .register_type::<KeyChord>()
+            .register_type::<TabFocusPolicy>();
     }
 }
 
@@ -33,7 +75,264 @@ pub struct FromElement;
 #[derive(Component, Reflect)]
 pub struct FormElementFocus;
 
-/// Marker component indicating that a value was changed.
+/// The currently focused form element.
+///
+/// Kept in sync with the [`FormElementFocus`] component, which remains the source of truth.
+#[derive(Resource, Default, Debug, Reflect)]
+pub struct FormFocus(pub Option<Entity>);
+
+/// Keyboard navigation behavior of forms.
+///
+/// Maps each [`FormKeyAction`] to the [`KeyChord`]s triggering it. The defaults are `Tab` and
+/// `Shift+Tab` to move the focus, `Enter` and `Ctrl+Enter` to submit, `Escape` to cancel,
+/// `Ctrl+C` and `Copy` to copy, `Ctrl+X` and `Cut` to cut, `Ctrl+V`, `Insert` and `Shift+Insert`
+/// to paste, `Ctrl+A` to select everything, `Ctrl+Z` to undo, and `Ctrl+Shift+Z` and `Ctrl+Y` to
+/// redo.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+///
+/// fn setup(mut key_bindings: ResMut<FormKeyBindings>) {
+///     // Move between fields using the arrow keys instead of `Tab`.
+///     key_bindings.set(FormKeyAction::NextField, [KeyChord::new(KeyCode::ArrowDown)]);
+///     key_bindings.set(FormKeyAction::PrevField, [KeyChord::new(KeyCode::ArrowUp)]);
+/// }
+/// ```
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct FormKeyBindings {
+    /// What happens when tabbing past the last element of a form, or before the first element
+    /// using Shift+Tab.
+    pub focus_policy: TabFocusPolicy,
+    /// The chords triggering each action.
+    pub bindings: HashMap<FormKeyAction, Vec<KeyChord>>,
+}
+
+impl Default for FormKeyBindings {
+    fn default() -> Self {
+        let bindings = [
+            (FormKeyAction::NextField, vec![KeyChord::new(KeyCode::Tab)]),
+            (
+                FormKeyAction::PrevField,
+                vec![KeyChord::new(KeyCode::Tab).shift()],
+            ),
+            (
+                FormKeyAction::Submit,
+                vec![
+                    KeyChord::new(KeyCode::Enter),
+                    KeyChord::new(KeyCode::Enter).ctrl(),
+                ],
+            ),
+            (FormKeyAction::Cancel, vec![KeyChord::new(KeyCode::Escape)]),
+            (
+                FormKeyAction::Copy,
+                vec![
+                    KeyChord::new(KeyCode::KeyC).ctrl(),
+                    KeyChord::new(KeyCode::Copy),
+                ],
+            ),
+            (
+                FormKeyAction::Cut,
+                vec![
+                    KeyChord::new(KeyCode::KeyX).ctrl(),
+                    KeyChord::new(KeyCode::Cut),
+                ],
+            ),
+            (
+                FormKeyAction::SelectAll,
+                vec![KeyChord::new(KeyCode::KeyA).ctrl()],
+            ),
+            (
+                FormKeyAction::Paste,
+                vec![
+                    KeyChord::new(KeyCode::KeyV).ctrl(),
+                    KeyChord::new(KeyCode::Insert),
+                    KeyChord::new(KeyCode::Insert).shift(),
+                ],
+            ),
+            (
+                FormKeyAction::Undo,
+                vec![KeyChord::new(KeyCode::KeyZ).ctrl()],
+            ),
+            (
+                FormKeyAction::Redo,
+                vec![
+                    KeyChord::new(KeyCode::KeyZ).ctrl().shift(),
+                    KeyChord::new(KeyCode::KeyY).ctrl(),
+                ],
+            ),
+        ];
+
+        Self {
+            focus_policy: TabFocusPolicy::default(),
+            bindings: bindings.into_iter().collect(),
+        }
+    }
+}
+
+impl FormKeyBindings {
+    /// Replaces the chords triggering the action. Pass no chords to disable the action.
+    pub fn set(&mut self, action: FormKeyAction, chords: impl IntoIterator<Item = KeyChord>) {
+        self.bindings.insert(action, chords.into_iter().collect());
+    }
+
+    /// Adds a chord triggering the action, keeping the existing ones.
+    pub fn bind(&mut self, action: FormKeyAction, chord: KeyChord) {
+        self.bindings.entry(action).or_default().push(chord);
+    }
+
+    /// The chords triggering the action.
+    #[must_use]
+    pub fn chords(&self, action: FormKeyAction) -> &[KeyChord] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns true if a chord of the action was just pressed.
+    #[must_use]
+    pub fn just_pressed(&self, action: FormKeyAction, keys: &ButtonInput<KeyCode>) -> bool {
+        self.chords(action)
+            .iter()
+            .any(|chord| chord.just_pressed(keys))
+    }
+
+    /// Returns true if a chord of the action was just released.
+    #[must_use]
+    pub fn just_released(&self, action: FormKeyAction, keys: &ButtonInput<KeyCode>) -> bool {
+        self.chords(action)
+            .iter()
+            .any(|chord| chord.just_released(keys))
+    }
+}
+
+/// Actions of forms triggered by the keyboard. See [`FormKeyBindings`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum FormKeyAction {
+    /// Focus the next element of the form.
+    NextField,
+    /// Focus the previous element of the form.
+    PrevField,
+    /// Submit the form containing the focused element.
+    Submit,
+    /// Cancel the form containing the focused element.
+    Cancel,
+    /// Copy the selection of the focused element.
+    Copy,
+    /// Copy the selection of the focused element and remove it.
+    Cut,
+    /// Select the whole value of the focused element.
+    SelectAll,
+    /// Paste the clipboard into the focused element.
+    Paste,
+    /// Undo the last edit of the focused element.
+    Undo,
+    /// Redo the last undone edit of the focused element.
+    Redo,
+}
+
+/// A key together with the modifiers that have to be held.
+///
+/// The chord only matches if exactly its modifiers are held, so `Shift+Tab` does not trigger a
+/// chord of `Tab` alone. The left and right modifier keys are treated the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub struct KeyChord {
+    /// The key triggering the chord.
+    pub key: KeyCode,
+    /// Whether `Ctrl` has to be held.
+    pub ctrl: bool,
+    /// Whether `Shift` has to be held.
+    pub shift: bool,
+    /// Whether `Alt` has to be held.
+    pub alt: bool,
+}
+
+impl KeyChord {
+    /// A chord of the key without modifiers.
+    #[must_use]
+    pub fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    /// Requires `Ctrl` to be held.
+    #[must_use]
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Requires `Shift` to be held.
+    #[must_use]
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Requires `Alt` to be held.
+    #[must_use]
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Returns true if the key was just pressed while exactly the modifiers of the chord are held.
+    #[must_use]
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        keys.just_pressed(self.key) && self.modifiers_held(keys)
+    }
+
+    /// Returns true if the key was just released while exactly the modifiers of the chord are held.
+    #[must_use]
+    pub fn just_released(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        keys.just_released(self.key) && self.modifiers_held(keys)
+    }
+
+    fn modifiers_held(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) == self.ctrl
+            && keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) == self.shift
+            && keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]) == self.alt
+    }
+}
+
+/// Behavior when tabbing past the last element of a form, or before the first element using
+/// Shift+Tab.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum TabFocusPolicy {
+    /// Focus wraps around to the first element, or the last element using Shift+Tab, keeping it
+    /// inside the form.
+    #[default]
+    Trap,
+    /// Focus leaves the form, so no element of the form is focused afterwards.
+    Exit,
+}
+
+/// When changes of the validity of form elements are reported using [`FieldValidityChanged`].
+///
+/// Add this to a form to set the mode of all its elements, or to an element to override the mode
+/// of its form. Changes that are not reported yet are reported together once the moment of the
+/// mode is reached. Submitting a form reports the changes of all its elements.
+/// The validity itself is always up to date, so invalid forms can not be submitted in any mode.
+#[derive(Component, Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum ValidationMode {
+    /// Changes are reported as soon as they happen.
+    #[default]
+    OnChange,
+    /// Changes are reported when the element loses focus.
+    OnBlur,
+    /// Changes are reported when the form is submitted.
+    OnSubmit,
+}
+
+/// Marker component indicating that the validity of the element changed without being reported.
+#[derive(Component)]
+struct UnreportedValidity;
+
+/// Marker component indicating that the value was changed after the element was created.
 #[derive(Component, Reflect)]
 pub struct FormElementDirty;
 
@@ -53,10 +352,137 @@ pub struct FormElementTouched;
 #[derive(Component, Reflect)]
 pub struct FormElementOptional;
 
+/// Marker component indicating that the validation of the element is still in progress.
+/// Forms containing a pending element can not be submitted.
+#[derive(Component, Reflect)]
+pub struct FormElementPending;
+
+/// Marker component indicating that the element is disabled.
+/// Disabled elements can not be focused, edited or pressed and are exempt from required validation.
+/// Their text is rendered with half opacity. See [`FormDisabled`](crate::form::FormDisabled) to disable a whole form.
+#[derive(Component, Reflect)]
+pub struct FormElementDisabled;
+
+/// A convenience parameter for checking if a form contains pending elements.
+#[derive(SystemParam)]
+pub struct PendingElements<'w, 's> {
+    q_children: Query<'w, 's, &'static Children>,
+    q_pending: Query<'w, 's, (), With<FormElementPending>>,
+}
+
+impl PendingElements<'_, '_> {
+    /// Returns true if any descendant of the form is a [`FormElementPending`].
+    #[must_use]
+    pub fn is_pending(&self, form: Entity) -> bool {
+        self.q_children
+            .iter_descendants(form)
+            .any(|entity| self.q_pending.contains(entity))
+    }
+}
+
 /// Order of form elements. Elements are focused in ascending.
 #[derive(Component, Reflect)]
 pub struct FormElementOrder(pub usize);
 
+/// What pressing `Enter` in an element of the form does. Add this to a form.
+#[derive(Component, Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub enum EnterKeyBehavior {
+    /// The form is submitted.
+    #[default]
+    Submit,
+    /// The focus moves to the next element. The form is submitted from its last element, e.g. for
+    /// login forms on consoles.
+    Advance,
+}
+
+/// The focusable elements of forms in the order they are focused.
+#[derive(SystemParam)]
+pub(crate) struct FocusOrder<'w, 's> {
+    children: Query<'w, 's, &'static Children>,
+    elements: Query<'w, 's, (Entity, &'static FormElementOrder), Without<FormElementDisabled>>,
+    enter_key_behavior: Query<'w, 's, &'static EnterKeyBehavior>,
+}
+
+impl FocusOrder<'_, '_> {
+    fn ordered(&self, form: Entity) -> impl Iterator<Item = (Entity, usize)> + '_ {
+        self.children
+            .iter_descendants(form)
+            .filter_map(|child| self.elements.get(child).ok())
+            .map(|(entity, order)| (entity, order.0))
+    }
+
+    /// Returns the first element of the form, or the last one if `backwards` is set.
+    pub(crate) fn start(&self, form: Entity, backwards: bool) -> Option<Entity> {
+        let elements = self.ordered(form);
+        let start = if backwards {
+            elements.max_by_key(|(_, order)| *order)
+        } else {
+            elements.min_by_key(|(_, order)| *order)
+        };
+        start.map(|(entity, _)| entity)
+    }
+
+    /// Returns the element after the order `from`, or before it if `backwards` is set.
+    pub(crate) fn next(&self, form: Entity, from: usize, backwards: bool) -> Option<Entity> {
+        let elements = self.ordered(form);
+        let next = if backwards {
+            elements
+                .filter(|(_, order)| *order < from)
+                .max_by_key(|(_, order)| *order)
+        } else {
+            elements
+                .filter(|(_, order)| *order > from)
+                .min_by_key(|(_, order)| *order)
+        };
+        next.map(|(entity, _)| entity)
+    }
+
+    /// Returns the element the focus moves to when pressing `Enter` in the focused element, if the
+    /// form is not submitted. See [`EnterKeyBehavior`].
+    pub(crate) fn enter_advances_to(&self, form: Entity, focused: Entity) -> Option<Entity> {
+        if self
+            .enter_key_behavior
+            .get(form)
+            .copied()
+            .unwrap_or_default()
+            != EnterKeyBehavior::Advance
+        {
+            return None;
+        }
+
+        let (_, order) = self.elements.get(focused).ok()?;
+        self.next(form, order.0, false)
+    }
+}
+
+/// Event that is sent when a form element becomes valid or invalid.
+/// When the event is sent depends on the [`ValidationMode`] of the element.
+#[derive(Event, Debug, Clone)]
+pub struct FieldValidityChanged {
+    /// The form element that changed.
+    pub entity: Entity,
+    /// Whether the element is now valid.
+    pub valid: bool,
+    /// The validation error if the element is now invalid.
+    pub error: Option<FormValidationError>,
+}
+
+/// Event that is sent when submitting a form is attempted while it is invalid.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct FormSubmitInvalid {
+    /// The form that was not submitted.
+    pub form: Entity,
+}
+
+/// Event that is sent when a form element gains the focus, i.e. [`FormElementFocus`] is added.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct FormElementFocused(pub Entity);
+
+/// Event that is sent when a form element loses the focus, i.e. [`FormElementFocus`] is removed.
+/// Also sent when a focused element is despawned.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct FormElementBlurred(pub Entity);
+
 /// Style of a form element.
 #[derive(Component, Default, Clone, Debug, Reflect)]
 pub struct FormElementStyle {
@@ -70,6 +496,97 @@ pub struct FormElementStyle {
     pub background_color: Option<BackgroundColor>,
 }
 
+/// Insert this resource to run forms without drawing them, e.g. in CI tests or server-side tools
+/// driving the same form logic. Elements keep their value components and systems, but text inputs
+/// do not spawn the nodes drawing their text, caret, selection, placeholder and clear button.
+///
+/// The app still needs the `HierarchyPlugin`, `InputPlugin` and `AssetPlugin` with the `Font`
+/// asset, but no window, render or UI plugins.
+#[derive(Resource, Default, Debug)]
+pub struct HeadlessForms;
+
+/// Run condition for systems that only draw elements, see [`HeadlessForms`].
+#[allow(clippy::needless_pass_by_value)]
+pub(crate) fn is_drawn(headless: Option<Res<HeadlessForms>>) -> bool {
+    headless.is_none()
+}
+
+/// Style of the focus ring drawn around the focused form element.
+///
+/// The ring is drawn using the Bevy `Outline` component, offset from the element, so it is visible
+/// regardless of the element's own border.
+/// Default is a 2px amber ring with a 2px offset.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct FocusRingStyle {
+    /// Whether the focus ring is drawn.
+    pub enabled: bool,
+    /// The color of the focus ring.
+    pub color: Color,
+    /// The width of the focus ring.
+    pub width: Val,
+    /// The space between the element and the focus ring.
+    pub offset: Val,
+}
+
+impl Default for FocusRingStyle {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            color: Color::rgb(1.0, 0.75, 0.0),
+            width: Val::Px(2.0),
+            offset: Val::Px(2.0),
+        }
+    }
+}
+
+/// Style of the error messages shown below invalid form elements.
+///
+/// A message is shown once the element is reported invalid using [`FieldValidityChanged`] and
+/// removed once it is reported valid.
+/// Default is `TextStyle` with `font_size` 16.0 and a red `color`.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct ErrorTextStyle {
+    /// Whether error messages are shown.
+    pub enabled: bool,
+    /// The text style of the error messages.
+    pub text_style: TextStyle,
+}
+
+impl Default for ErrorTextStyle {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            text_style: TextStyle {
+                font_size: 16.0,
+                color: Color::rgb(0.8, 0.1, 0.1),
+                ..default()
+            },
+        }
+    }
+}
+
+/// The error message shown below a form element.
+#[derive(Component)]
+struct ErrorText {
+    element: Entity,
+}
+
+/// The error message of the form element.
+#[derive(Component)]
+struct FormElementErrorText(Entity);
+
+impl FocusRingStyle {
+    fn outline(&self, focused: bool) -> Outline {
+        let color = if focused && self.enabled {
+            self.color
+        } else {
+            Color::NONE
+        };
+
+        Outline::new(self.width, self.offset, color)
+    }
+}
+
 #[allow(clippy::needless_pass_by_value)]
 fn form_element_touched(
     mut commands: Commands,
@@ -80,20 +597,92 @@ fn form_element_touched(
     }
 }
 
+/// Returns true if the value was changed after it was added.
+fn modified<T: Component>(value: Option<Ref<T>>) -> bool {
+    value.is_some_and(|value| value.is_changed() && !value.is_added())
+}
+
 #[allow(clippy::needless_pass_by_value)]
-fn form_element_invalid(
+pub(crate) fn form_element_dirty(
     mut commands: Commands,
-    q_form_element_invalid: Query<(&Parent, &FormElementInvalid), Added<FormElementInvalid>>,
+    q_value: Query<
+        (
+            Entity,
+            AnyOf<(
+                Ref<TextInputValue>,
+                Ref<ColorPickerValue>,
+                Ref<SelectValue>,
+                Ref<SliderValue>,
+                Ref<TagInputValue>,
+                Ref<CheckboxValue>,
+            )>,
+            Has<FormElementReset>,
+        ),
+        Without<FormElementDirty>,
+    >,
+) {
+    for (entity, (text_input, color_picker, select, slider, tag_input, checkbox), reset) in &q_value
+    {
+        // Restoring the value of an element does not make it dirty.
+        if reset {
+            commands.entity(entity).remove::<FormElementReset>();
+        } else if modified(text_input)
+            || modified(color_picker)
+            || modified(select)
+            || modified(slider)
+            || modified(tag_input)
+            || modified(checkbox)
+        {
+            commands.entity(entity).insert(FormElementDirty);
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub(crate) fn form_element_invalid(
+    mut commands: Commands,
+    q_form_element_invalid: Query<(Entity, &FormElementInvalid), Added<FormElementInvalid>>,
     mut q_form: Query<Option<&mut FormInvalid>, With<Form>>,
+    q_parent: Query<&Parent>,
+    q_mode: Query<&ValidationMode>,
+    mut ev_validity: EventWriter<FieldValidityChanged>,
 ) {
-    for (parent, element_invalid) in q_form_element_invalid.iter() {
-        if let Ok(form_invalid) = q_form.get_mut(parent.get()) {
+    // Errors are collected first, as the component of a form without errors is only inserted once
+    // the commands are applied.
+    let mut form_errors = HashMap::<Entity, Vec<FormValidationError>>::new();
+    for (entity, element_invalid) in q_form_element_invalid.iter() {
+        let form = find_form_ancestor(entity, &q_parent, |e| q_form.contains(e));
+        if validation_mode(entity, form, &q_mode) == ValidationMode::OnChange {
+            ev_validity.send(FieldValidityChanged {
+                entity,
+                valid: false,
+                error: Some(element_invalid.0.clone()),
+            });
+        } else {
+            commands.entity(entity).insert(UnreportedValidity);
+        }
+
+        if let Some(form) = form {
+            form_errors
+                .entry(form)
+                .or_default()
+                .push(element_invalid.0.clone());
+        }
+    }
+
+    for (form, errors) in form_errors {
+        if let Ok(form_invalid) = q_form.get_mut(form) {
             if let Some(mut form_invalid) = form_invalid {
-                form_invalid.0.push(element_invalid.0.clone());
+                form_invalid.0.retain(|error| {
+                    !errors
+                        .iter()
+                        .any(|new_error| new_error.entity() == error.entity())
+                });
+                form_invalid.0.extend(errors);
             } else {
                 commands
-                    .entity(parent.get())
-                    .insert(FormInvalid(vec![element_invalid.0.clone()]))
+                    .entity(form)
+                    .insert(FormInvalid(errors))
                     .remove::<FormValid>();
             }
         }
@@ -101,22 +690,41 @@ fn form_element_invalid(
 }
 
 #[allow(clippy::needless_pass_by_value)]
-fn form_element_valid(
+pub(crate) fn form_element_valid(
     mut commands: Commands,
-    q_form_element_valid: Query<(&Parent, Entity), Added<FormElementValid>>,
+    q_form_element_valid: Query<Entity, Added<FormElementValid>>,
     mut q_form: Query<&mut FormInvalid, With<Form>>,
+    q_form_entity: Query<(), With<Form>>,
+    q_parent: Query<&Parent>,
+    q_mode: Query<&ValidationMode>,
+    mut ev_validity: EventWriter<FieldValidityChanged>,
 ) {
-    for (parent, element_entity) in q_form_element_valid.iter() {
-        if let Ok(mut form_invalid) = q_form.get_mut(parent.get()) {
-            form_invalid.0.retain(|error| match error {
-                FormValidationError::Required(entity)
-                | FormValidationError::Invalid(entity)
-                | FormValidationError::Custom(entity, _) => *entity != element_entity,
+    for element_entity in q_form_element_valid.iter() {
+        let mode_form =
+            find_form_ancestor(element_entity, &q_parent, |e| q_form_entity.contains(e));
+        if validation_mode(element_entity, mode_form, &q_mode) == ValidationMode::OnChange {
+            ev_validity.send(FieldValidityChanged {
+                entity: element_entity,
+                valid: true,
+                error: None,
             });
+        } else {
+            commands.entity(element_entity).insert(UnreportedValidity);
+        }
+
+        let Some(form) = find_form_ancestor(element_entity, &q_parent, |e| q_form.contains(e))
+        else {
+            continue;
+        };
+
+        if let Ok(mut form_invalid) = q_form.get_mut(form) {
+            form_invalid
+                .0
+                .retain(|error| error.entity() != element_entity);
 
             if form_invalid.0.is_empty() {
                 commands
-                    .entity(parent.get())
+                    .entity(form)
                     .remove::<FormInvalid>()
                     .insert(FormValid);
             }
@@ -124,35 +732,325 @@ fn form_element_valid(
     }
 }
 
+/// Returns the validation mode of the element, falling back to the mode of its form.
+fn validation_mode(
+    entity: Entity,
+    form: Option<Entity>,
+    q_mode: &Query<&ValidationMode>,
+) -> ValidationMode {
+    q_mode
+        .get(entity)
+        .ok()
+        .or_else(|| form.and_then(|form| q_mode.get(form).ok()))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Reports the current validity of the element.
+fn report_validity(
+    commands: &mut Commands,
+    ev_validity: &mut EventWriter<FieldValidityChanged>,
+    entity: Entity,
+    invalid: Option<&FormElementInvalid>,
+) {
+    ev_validity.send(FieldValidityChanged {
+        entity,
+        valid: invalid.is_none(),
+        error: invalid.map(|invalid| invalid.0.clone()),
+    });
+    commands.entity(entity).remove::<UnreportedValidity>();
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn report_on_blur(
+    mut commands: Commands,
+    mut focus_removed: RemovedComponents<FormElementFocus>,
+    q_unreported: Query<Option<&FormElementInvalid>, With<UnreportedValidity>>,
+    q_form: Query<(), With<Form>>,
+    q_parent: Query<&Parent>,
+    q_mode: Query<&ValidationMode>,
+    mut ev_validity: EventWriter<FieldValidityChanged>,
+) {
+    for entity in focus_removed.read() {
+        let Ok(invalid) = q_unreported.get(entity) else {
+            continue;
+        };
+
+        let form = find_form_ancestor(entity, &q_parent, |e| q_form.contains(e));
+        if validation_mode(entity, form, &q_mode) == ValidationMode::OnBlur {
+            report_validity(&mut commands, &mut ev_validity, entity, invalid);
+        }
+    }
+}
+
+/// Reports the changes of all elements of a form when submitting it, whether or not the form is
+/// valid. Sends [`FormSubmitInvalid`] if it is not.
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+fn report_on_submit(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<FormKeyBindings>,
+    mut ev_button: EventReader<ButtonPressEvent>,
+    q_form: Query<(), With<Form>>,
+    q_invalid: Query<(), With<FormInvalid>>,
+    q_focused: Query<Entity, With<FormElementFocus>>,
+    q_children: Query<&Children>,
+    q_parent: Query<&Parent>,
+    q_unreported: Query<Option<&FormElementInvalid>, With<UnreportedValidity>>,
+    focus_order: FocusOrder,
+    mut ev_validity: EventWriter<FieldValidityChanged>,
+    mut ev_submit_invalid: EventWriter<FormSubmitInvalid>,
+) {
+    let mut forms = ev_button
+        .read()
+        .filter(|ev| ev.role == ButtonRole::Submit)
+        .filter_map(|ev| ev.button.form)
+        .collect::<Vec<_>>();
+
+    if key_bindings.just_released(FormKeyAction::Submit, &keyboard_input) {
+        forms.extend(q_focused.iter().filter_map(|focused| {
+            find_form_ancestor(focused, &q_parent, |entity| q_form.contains(entity))
+                .filter(|form| focus_order.enter_advances_to(*form, focused).is_none())
+        }));
+    }
+
+    for form in forms {
+        for entity in q_children.iter_descendants(form) {
+            if let Ok(invalid) = q_unreported.get(entity) {
+                report_validity(&mut commands, &mut ev_validity, entity, invalid);
+            }
+        }
+
+        if q_invalid.contains(form) {
+            ev_submit_invalid.send(FormSubmitInvalid { form });
+        }
+    }
+}
+
+/// Moves the focus within the form containing the focused element. Elements of other forms are
+/// ignored. If no element is focused, the focus only moves into a form if it is the only one.
 #[allow(clippy::needless_pass_by_value)]
 fn form_element_keyboard(
     mut commands: Commands,
     keyboard_input: Res<ButtonInput<KeyCode>>,
-    q_form_children: Query<&Children, With<Form>>,
-    q_focused: Query<Option<&FormElementOrder>, With<FormElementFocus>>,
-    q_form_elements: Query<(Entity, Option<&FormElementOrder>)>,
+    key_bindings: Res<FormKeyBindings>,
+    q_form: Query<Entity, With<Form>>,
+    q_focused: Query<(Entity, Option<&FormElementOrder>), With<FormElementFocus>>,
+    q_parent: Query<&Parent>,
+    focus_order: FocusOrder,
 ) {
-    if keyboard_input.just_released(KeyCode::Tab) {
-        if let Ok(children) = q_form_children.get_single() {
-            let focus_order = q_focused
-                .get_single()
-                .map(|order| order.map_or(0, |o| o.0))
-                .unwrap_or(0);
-
-            let order = children
-                .iter()
-                .filter_map(|child| q_form_elements.get(*child).ok())
-                .filter(|(_, order)| order.is_some())
-                .map(|(entity, order)| (entity, order.unwrap().0));
-
-            let next = order
-                .clone()
-                .filter(|(_, order)| *order > focus_order)
-                .min_by_key(|(_, order)| *order);
-
-            if let Some((entity, _)) = next.or(order.min_by_key(|(_, order)| *order)) {
-                commands.entity(entity).insert(FormElementFocus);
+    let backwards = key_bindings.just_released(FormKeyAction::PrevField, &keyboard_input);
+    if !backwards && !key_bindings.just_released(FormKeyAction::NextField, &keyboard_input) {
+        return;
+    }
+
+    let focused = q_focused.iter().find_map(|(focused, order)| {
+        find_form_ancestor(focused, &q_parent, |entity| q_form.contains(entity))
+            .map(|form| (form, focused, order))
+    });
+
+    let next = match focused {
+        Some((form, focused, order)) => {
+            let next = focus_order.next(form, order.map_or(0, |order| order.0), backwards);
+            match (next, key_bindings.focus_policy) {
+                (None, TabFocusPolicy::Exit) => {
+                    commands.entity(focused).remove::<FormElementFocus>();
+                    None
+                }
+                (None, TabFocusPolicy::Trap) => focus_order.start(form, backwards),
+                (next, _) => next,
             }
         }
+        None => q_form
+            .get_single()
+            .ok()
+            .and_then(|form| focus_order.start(form, backwards)),
+    };
+
+    if let Some(entity) = next {
+        commands.entity(entity).insert(FormElementFocus);
+    }
+}
+
+/// Only one element can be focused at a time. The element focused last keeps the focus.
+#[allow(clippy::needless_pass_by_value)]
+pub(crate) fn form_element_focus(
+    mut commands: Commands,
+    q_focus_added: Query<Entity, Added<FormElementFocus>>,
+    q_focused: Query<Entity, With<FormElementFocus>>,
+) {
+    let Some(focused) = q_focus_added.iter().last() else {
+        return;
+    };
+
+    for entity in &q_focused {
+        if entity != focused {
+            commands.entity(entity).remove::<FormElementFocus>();
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn form_element_disabled(
+    mut commands: Commands,
+    q_disabled: Query<Entity, (Added<FormElementDisabled>, With<FormElementFocus>)>,
+) {
+    for entity in &q_disabled {
+        commands.entity(entity).remove::<FormElementFocus>();
+    }
+}
+
+/// Marker component for texts of disabled elements that are rendered with half opacity.
+#[derive(Component)]
+struct FormElementDimmed;
+
+/// Dims the texts of disabled elements. Text inputs dim their own text.
+#[allow(clippy::needless_pass_by_value)]
+fn dim_disabled(
+    mut commands: Commands,
+    q_disabled: Query<Entity, (With<FormElementDisabled>, Without<TextInputValue>)>,
+    mut enabled: RemovedComponents<FormElementDisabled>,
+    q_children: Query<&Children>,
+    mut q_text: Query<(&mut Text, Has<FormElementDimmed>)>,
+) {
+    for entity in enabled.read() {
+        for child in q_children.iter_descendants(entity) {
+            if let Ok((mut text, true)) = q_text.get_mut(child) {
+                scale_alpha(&mut text, 2.0);
+                commands.entity(child).remove::<FormElementDimmed>();
+            }
+        }
+    }
+
+    // Texts are dimmed once they are created, which may be after the element was disabled.
+    for entity in &q_disabled {
+        for child in q_children.iter_descendants(entity) {
+            if let Ok((mut text, false)) = q_text.get_mut(child) {
+                scale_alpha(&mut text, 0.5);
+                commands.entity(child).insert(FormElementDimmed);
+            }
+        }
+    }
+}
+
+fn scale_alpha(text: &mut Text, factor: f32) {
+    for section in &mut text.sections {
+        let alpha = section.style.color.a() * factor;
+        section.style.color.set_a(alpha);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn focus_ring(
+    mut commands: Commands,
+    style: Res<FocusRingStyle>,
+    q_focus_added: Query<Entity, Added<FormElementFocus>>,
+    q_focused: Query<Entity, With<FormElementFocus>>,
+    mut focus_removed: RemovedComponents<FormElementFocus>,
+) {
+    for entity in focus_removed.read() {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.insert(style.outline(false));
+        }
+    }
+
+    if style.is_changed() {
+        for entity in &q_focused {
+            commands.entity(entity).insert(style.outline(true));
+        }
+        return;
+    }
+
+    for entity in &q_focus_added {
+        commands.entity(entity).insert(style.outline(true));
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sync_form_focus(
+    mut focus: ResMut<FormFocus>,
+    q_focus_added: Query<Entity, Added<FormElementFocus>>,
+    mut focus_removed: RemovedComponents<FormElementFocus>,
+    mut ev_focused: EventWriter<FormElementFocused>,
+    mut ev_blurred: EventWriter<FormElementBlurred>,
+) {
+    for entity in focus_removed.read() {
+        if focus.0 == Some(entity) {
+            focus.0 = None;
+        }
+        ev_blurred.send(FormElementBlurred(entity));
+    }
+
+    for entity in &q_focus_added {
+        focus.0 = Some(entity);
+        ev_focused.send(FormElementFocused(entity));
+    }
+}
+
+/// Shows the reported errors below the form elements. The message is spawned as the next sibling of
+/// the element, so it is laid out by the container of the element.
+#[allow(clippy::needless_pass_by_value)]
+fn error_text(
+    mut commands: Commands,
+    style: Res<ErrorTextStyle>,
+    mut ev_validity: EventReader<FieldValidityChanged>,
+    q_error_text: Query<&FormElementErrorText>,
+    q_orphan: Query<(Entity, &ErrorText)>,
+    q_parent: Query<(), With<Parent>>,
+    mut q_text: Query<&mut Text>,
+) {
+    for ev in ev_validity.read() {
+        let existing = q_error_text
+            .get(ev.entity)
+            .ok()
+            .map(|error_text| error_text.0);
+        match (&ev.error, existing) {
+            (Some(error), Some(existing)) if style.enabled => {
+                if let Ok(mut text) = q_text.get_mut(existing) {
+                    text.sections[0].value = error.to_string();
+                }
+            }
+            (Some(error), None) if style.enabled => {
+                if !q_parent.contains(ev.entity) {
+                    continue;
+                }
+                let text = commands
+                    .spawn((
+                        TextBundle::from_section(error.to_string(), style.text_style.clone()),
+                        ErrorText { element: ev.entity },
+                        Name::new("error"),
+                    ))
+                    .id();
+                let element = ev.entity;
+                // The position is looked up when applied, as other messages may be inserted before.
+                commands.add(move |world: &mut World| {
+                    let Some(parent) = world.get::<Parent>(element).map(Parent::get) else {
+                        return;
+                    };
+                    let index = world
+                        .get::<Children>(parent)
+                        .and_then(|children| children.iter().position(|child| *child == element))
+                        .map_or(0, |index| index + 1);
+                    world.entity_mut(parent).insert_children(index, &[text]);
+                });
+                commands
+                    .entity(ev.entity)
+                    .insert(FormElementErrorText(text));
+            }
+            (_, Some(existing)) => {
+                commands.entity(existing).despawn_recursive();
+                if let Some(mut entity) = commands.get_entity(ev.entity) {
+                    entity.remove::<FormElementErrorText>();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Messages of despawned elements are removed as well.
+    for (entity, error_text) in &q_orphan {
+        if !q_error_text.contains(error_text.element) {
+            commands.entity(entity).despawn_recursive();
+        }
     }
 }
@@ -0,0 +1,344 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::prelude::*;
+
+use crate::form::form_keyboard;
+use crate::form_elements::text_input;
+use crate::prelude::{
+    FormElementDisabled, FormElementFocus, FormTheme, TextInputBundle, TextInputReadonly,
+    TextInputTextStyle, TextInputValue,
+};
+
+/// A Bevy `Plugin` providing the systems required to make an [`AutocompleteBundle`] work.
+pub struct AutocompletePlugin;
+
+impl Plugin for AutocompletePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FormTheme>()
+            .add_systems(
+                Update,
+                (
+                    create,
+                    filter.after(create).after(text_input::update_value),
+                    keyboard
+                        .after(text_input::keyboard)
+                        .before(text_input::update_value)
+                        .before(form_keyboard),
+                    suggestion_interaction.before(filter),
+                    close,
+                    update.after(filter).after(keyboard),
+                ),
+            )
+            .register_type::<SuggestionsProvider>()
+            .register_type::<AutocompleteSuggestion>();
+    }
+}
+
+/// Maximum number of suggestions shown at once.
+const MAX_SUGGESTIONS: usize = 8;
+
+const HIGHLIGHT_COLOR: Color = Color::rgb(0.85, 0.85, 0.85);
+
+/// A bundle providing the components required for a text input with suggestions.
+///
+/// Add this to a Bevy `NodeBundle`. While typing, the suggestions containing the typed text are
+/// listed below the input. They can be navigated using the arrow keys and chosen by clicking them
+/// or pressing `Enter`. `Escape` closes the list.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         AutocompleteBundle::new(["Apple", "Banana", "Cherry"]),
+///     ));
+/// }
+/// ```
+#[derive(Bundle, Default)]
+pub struct AutocompleteBundle {
+    /// The text input the suggestions are completing.
+    pub text_input: TextInputBundle,
+    /// A component containing the suggestions.
+    pub suggestions: SuggestionsProvider,
+}
+
+impl AutocompleteBundle {
+    /// Creates a text input with the given suggestions.
+    #[must_use]
+    pub fn new(suggestions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            suggestions: SuggestionsProvider::new(suggestions),
+            ..default()
+        }
+    }
+
+    /// Sets the text input the suggestions are completing.
+    #[must_use]
+    pub fn with_text_input(mut self, text_input: TextInputBundle) -> Self {
+        self.text_input = text_input;
+        self
+    }
+}
+
+/// A component containing the suggestions of a text input.
+///
+/// The suggestions can be changed at any time, e.g. to provide results of a search.
+#[derive(Component, Clone, Default, Debug, Reflect)]
+pub struct SuggestionsProvider(pub Vec<String>);
+
+impl SuggestionsProvider {
+    /// Creates a provider with the given suggestions.
+    #[must_use]
+    pub fn new(suggestions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self(suggestions.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the suggestions containing the value, ignoring case. Suggestions equal to the value
+    /// are skipped, as there is nothing left to complete.
+    #[must_use]
+    pub fn matches(&self, value: &str) -> Vec<&str> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Vec::new();
+        }
+
+        let lowercase = value.to_lowercase();
+        self.0
+            .iter()
+            .filter(|suggestion| {
+                *suggestion != value && suggestion.to_lowercase().contains(&lowercase)
+            })
+            .map(String::as_str)
+            .take(MAX_SUGGESTIONS)
+            .collect()
+    }
+}
+
+/// A suggestion in the list of a text input.
+#[derive(Component, Reflect)]
+pub struct AutocompleteSuggestion {
+    /// The text input the suggestion belongs to.
+    pub input: Entity,
+    /// The text of the suggestion.
+    pub value: String,
+}
+
+/// The list of matching suggestions and the highlighted one.
+#[derive(Component)]
+struct AutocompleteState {
+    list: Entity,
+    matches: Vec<String>,
+    items: Vec<Entity>,
+    highlighted: Option<usize>,
+    open: bool,
+    /// Set when a suggestion was chosen, so the list is not opened again by the changed value.
+    chosen: bool,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn create(
+    mut commands: Commands,
+    theme: Res<FormTheme>,
+    q_added: Query<Entity, Added<SuggestionsProvider>>,
+) {
+    for entity in &q_added {
+        let list = commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        display: Display::None,
+                        position_type: PositionType::Absolute,
+                        top: Val::Percent(100.0),
+                        left: Val::Px(0.0),
+                        min_width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    },
+                    background_color: theme.popup_background.into(),
+                    z_index: ZIndex::Global(1),
+                    ..default()
+                },
+                Name::new("suggestions"),
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(AutocompleteState {
+                list,
+                matches: Vec::new(),
+                items: Vec::new(),
+                highlighted: None,
+                open: false,
+                chosen: false,
+            })
+            .add_child(list);
+    }
+}
+
+/// Filters the suggestions while the user types.
+#[allow(clippy::needless_pass_by_value)]
+fn filter(
+    mut commands: Commands,
+    mut q_input: Query<
+        (
+            Entity,
+            &SuggestionsProvider,
+            &TextInputValue,
+            &TextInputTextStyle,
+            &mut AutocompleteState,
+            Has<FormElementFocus>,
+        ),
+        Or<(Changed<TextInputValue>, Changed<SuggestionsProvider>)>,
+    >,
+) {
+    for (entity, suggestions, value, text_style, mut state, focused) in &mut q_input {
+        let matches = suggestions
+            .matches(&value.0)
+            .into_iter()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+
+        let open = focused && !matches.is_empty() && !std::mem::take(&mut state.chosen);
+        if matches == state.matches {
+            state.open = open;
+            continue;
+        }
+
+        commands.entity(state.list).despawn_descendants();
+        state.items = matches
+            .iter()
+            .map(|suggestion| {
+                commands
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                padding: UiRect::horizontal(Val::Px(4.0)),
+                                ..default()
+                            },
+                            background_color: Color::NONE.into(),
+                            ..default()
+                        },
+                        AutocompleteSuggestion {
+                            input: entity,
+                            value: suggestion.clone(),
+                        },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            suggestion.clone(),
+                            text_style.0.clone(),
+                        ));
+                    })
+                    .set_parent(state.list)
+                    .id()
+            })
+            .collect();
+
+        state.open = open;
+        state.highlighted = None;
+        state.matches = matches;
+    }
+}
+
+fn keyboard(
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut q_input: Query<
+        (&mut TextInputValue, &mut AutocompleteState),
+        (
+            With<FormElementFocus>,
+            Without<FormElementDisabled>,
+            Without<TextInputReadonly>,
+        ),
+    >,
+) {
+    for (mut value, mut state) in &mut q_input {
+        if !state.open {
+            continue;
+        }
+
+        let last = state.matches.len().saturating_sub(1);
+        if keyboard_input.just_pressed(KeyCode::ArrowDown) {
+            state.highlighted = Some(state.highlighted.map_or(0, |index| (index + 1).min(last)));
+        }
+        if keyboard_input.just_pressed(KeyCode::ArrowUp) {
+            state.highlighted = state
+                .highlighted
+                .map(|index| index.saturating_sub(1))
+                .or(Some(last));
+        }
+
+        // The keys are consumed, so an open list does not submit or cancel the form.
+        if let Some(index) = state.highlighted {
+            if keyboard_input.clear_just_released(KeyCode::Enter) {
+                value.0 = state.matches[index].clone();
+                state.open = false;
+                state.chosen = true;
+            }
+        }
+        if keyboard_input.clear_just_released(KeyCode::Escape) {
+            state.open = false;
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn suggestion_interaction(
+    q_suggestion: Query<(&AutocompleteSuggestion, &Interaction), Changed<Interaction>>,
+    mut q_input: Query<
+        (&mut TextInputValue, &mut AutocompleteState),
+        (Without<FormElementDisabled>, Without<TextInputReadonly>),
+    >,
+) {
+    for (suggestion, interaction) in &q_suggestion {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Ok((mut value, mut state)) = q_input.get_mut(suggestion.input) {
+            value.0.clone_from(&suggestion.value);
+            state.open = false;
+            state.chosen = true;
+        }
+    }
+}
+
+fn close(
+    mut focus_removed: RemovedComponents<FormElementFocus>,
+    mut q_input: Query<&mut AutocompleteState>,
+) {
+    for entity in focus_removed.read() {
+        if let Ok(mut state) = q_input.get_mut(entity) {
+            state.open = false;
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update(
+    q_input: Query<&AutocompleteState, Changed<AutocompleteState>>,
+    mut q_style: Query<&mut Style>,
+    mut q_background: Query<&mut BackgroundColor>,
+) {
+    for state in &q_input {
+        if let Ok(mut style) = q_style.get_mut(state.list) {
+            style.display = if state.open {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+
+        for (index, item) in state.items.iter().enumerate() {
+            if let Ok(mut background) = q_background.get_mut(*item) {
+                background.0 = if Some(index) == state.highlighted {
+                    HIGHLIGHT_COLOR
+                } else {
+                    Color::NONE
+                };
+            }
+        }
+    }
+}
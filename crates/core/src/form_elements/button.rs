@@ -5,12 +5,15 @@
 #![allow(clippy::module_name_repetitions)]
 use bevy::prelude::*;
 
+use crate::prelude::{FormElementDisabled, FormTheme};
+
 /// A Bevy `Plugin` providing the systems and assets required to make a [`FormButtonBundle`] work.
 pub struct ButtonPlugin;
 
 impl Plugin for ButtonPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<ButtonPressEvent>()
+        app.init_resource::<FormTheme>()
+            .add_event::<ButtonPressEvent>()
             .add_systems(Update, (setup, interact));
     }
 }
@@ -103,6 +106,8 @@ pub enum ButtonRole {
     Cancel,
     /// Submits the form but does not close it.
     Apply,
+    /// Resets the elements of the form to their initial values.
+    Reset,
     /// Custom role.
     Custom(String),
 }
@@ -113,6 +118,7 @@ impl From<&str> for ButtonRole {
             "submit" => ButtonRole::Submit,
             "cancel" => ButtonRole::Cancel,
             "apply" => ButtonRole::Apply,
+            "reset" => ButtonRole::Reset,
             _ => ButtonRole::Custom(s.to_string()),
         }
     }
@@ -124,12 +130,17 @@ impl From<String> for ButtonRole {
     }
 }
 
-fn setup(mut commands: Commands, mut q_button: Query<(Entity, &FormButton), Added<FormButton>>) {
+#[allow(clippy::needless_pass_by_value)]
+fn setup(
+    mut commands: Commands,
+    theme: Res<FormTheme>,
+    mut q_button: Query<(Entity, &FormButton), Added<FormButton>>,
+) {
     for (entity, button) in &mut q_button {
         let text = commands
             .spawn(TextBundle::from_section(
                 button.text.clone(),
-                TextStyle::default(),
+                theme.button_text_style.clone(),
             ))
             .id();
 
@@ -142,7 +153,10 @@ fn setup(mut commands: Commands, mut q_button: Query<(Entity, &FormButton), Adde
 
 #[allow(clippy::needless_pass_by_value)]
 fn interact(
-    q_button: Query<(Entity, &FormButton, &ButtonRole, &Interaction), Changed<Interaction>>,
+    q_button: Query<
+        (Entity, &FormButton, &ButtonRole, &Interaction),
+        (Changed<Interaction>, Without<FormElementDisabled>),
+    >,
     mut ev_button: EventWriter<ButtonPressEvent>,
 ) {
     for (entity, button, role, _) in q_button
@@ -0,0 +1,154 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::prelude::*;
+
+use crate::prelude::{FormElementDisabled, FormElementFocus};
+
+/// A Bevy `Plugin` providing the systems required to make a [`CheckboxBundle`] work.
+pub struct CheckboxPlugin;
+
+impl Plugin for CheckboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                create,
+                toggle,
+                keyboard,
+                update.after(create).after(toggle).after(keyboard),
+            ),
+        )
+        .register_type::<CheckboxValue>()
+        .register_type::<CheckboxTextStyle>();
+    }
+}
+
+/// A bundle providing the components required for a checkbox.
+///
+/// Add this to a Bevy `NodeBundle`. The checkbox is toggled when clicked or with the `Space` key
+/// while it is focused.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((NodeBundle::default(), CheckboxBundle::new(true)));
+/// }
+/// ```
+#[derive(Bundle, Default)]
+pub struct CheckboxBundle {
+    /// A component containing whether the checkbox is checked.
+    pub value: CheckboxValue,
+    /// The style of the box.
+    pub text_style: CheckboxTextStyle,
+    /// This component's value is managed by Bevy's UI systems and enables tracking of hovers and presses.
+    pub interaction: Interaction,
+}
+
+impl CheckboxBundle {
+    /// Creates a checkbox that is checked if `checked` is true.
+    #[must_use]
+    pub fn new(checked: bool) -> Self {
+        Self {
+            value: CheckboxValue(checked),
+            ..default()
+        }
+    }
+
+    /// Sets the style of the box.
+    #[must_use]
+    pub fn with_text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = CheckboxTextStyle(text_style);
+        self
+    }
+}
+
+/// A component containing whether the checkbox is checked.
+#[derive(Component, Clone, Copy, Default, PartialEq, Eq, Debug, Reflect)]
+pub struct CheckboxValue(pub bool);
+
+/// The Bevy `TextStyle` used for the box of a checkbox.
+#[derive(Component, Clone, Default, Debug, Reflect)]
+pub struct CheckboxTextStyle(pub TextStyle);
+
+/// The text showing the box.
+#[derive(Component)]
+struct CheckboxInner {
+    text: Entity,
+}
+
+fn box_text(value: CheckboxValue) -> &'static str {
+    if value.0 {
+        "[x]"
+    } else {
+        "[ ]"
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn create(
+    mut commands: Commands,
+    q_added: Query<(Entity, &CheckboxValue, &CheckboxTextStyle), Added<CheckboxValue>>,
+) {
+    for (entity, value, text_style) in &q_added {
+        let text = commands
+            .spawn(TextBundle::from_section(
+                box_text(*value),
+                text_style.0.clone(),
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(CheckboxInner { text })
+            .add_child(text);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn toggle(
+    mut commands: Commands,
+    mut q_checkbox: Query<
+        (Entity, &Interaction, &mut CheckboxValue),
+        (Changed<Interaction>, Without<FormElementDisabled>),
+    >,
+) {
+    for (entity, interaction, mut value) in &mut q_checkbox {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        value.0 = !value.0;
+        commands.entity(entity).insert(FormElementFocus);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn keyboard(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut q_checkbox: Query<
+        &mut CheckboxValue,
+        (With<FormElementFocus>, Without<FormElementDisabled>),
+    >,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    for mut value in &mut q_checkbox {
+        value.0 = !value.0;
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update(
+    q_checkbox: Query<(&CheckboxValue, &CheckboxInner), Changed<CheckboxValue>>,
+    mut q_text: Query<&mut Text>,
+) {
+    for (value, inner) in &q_checkbox {
+        if let Ok(mut text) = q_text.get_mut(inner.text) {
+            text.sections[0].value = box_text(*value).to_string();
+        }
+    }
+}
@@ -0,0 +1,133 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::prelude::*;
+
+use crate::form_element::is_drawn;
+use crate::form_elements::text_input;
+use crate::prelude::{
+    FormElementDisabled, TextInputReadonly, TextInputSettings, TextInputTextStyle, TextInputValue,
+};
+
+/// A Bevy `Plugin` providing the systems required to make [`TextInputSettings::clearable`] work.
+pub struct ClearButtonPlugin;
+
+impl Plugin for ClearButtonPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                create.run_if(is_drawn).after(text_input::create),
+                interaction,
+                show_hide,
+            ),
+        )
+        .register_type::<TextInputClearButton>();
+    }
+}
+
+/// A button emptying a text input, rendered at its right edge if
+/// [`TextInputSettings::clearable`] is set. Hidden while the input is empty, disabled or read-only.
+#[derive(Component, Reflect)]
+pub struct TextInputClearButton {
+    /// The text input the button clears.
+    pub input: Entity,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn create(
+    mut commands: Commands,
+    q_added: Query<
+        (
+            Entity,
+            &TextInputSettings,
+            &TextInputTextStyle,
+            &TextInputValue,
+        ),
+        Added<TextInputValue>,
+    >,
+) {
+    for (entity, settings, style, value) in &q_added {
+        if !settings.clearable {
+            continue;
+        }
+
+        let button = commands
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::left(Val::Auto),
+                        padding: UiRect::horizontal(Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: Color::NONE.into(),
+                    visibility: if value.0.is_empty() {
+                        Visibility::Hidden
+                    } else {
+                        Visibility::Inherited
+                    },
+                    ..default()
+                },
+                TextInputClearButton { input: entity },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    "x",
+                    TextStyle {
+                        font_size: style.0.font_size * 0.75,
+                        color: style.0.color.with_a(style.0.color.a() * 0.5),
+                        ..style.0.clone()
+                    },
+                ));
+            })
+            .id();
+
+        commands.entity(entity).add_child(button);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn interaction(
+    q_button: Query<(&TextInputClearButton, &Interaction), Changed<Interaction>>,
+    mut q_input: Query<
+        &mut TextInputValue,
+        (Without<FormElementDisabled>, Without<TextInputReadonly>),
+    >,
+) {
+    for (button, interaction) in &q_button {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Ok(mut value) = q_input.get_mut(button.input) {
+            if !value.0.is_empty() {
+                value.0.clear();
+            }
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn show_hide(
+    mut q_button: Query<(&TextInputClearButton, &mut Visibility)>,
+    q_input: Query<(
+        &TextInputValue,
+        Has<FormElementDisabled>,
+        Has<TextInputReadonly>,
+    )>,
+) {
+    for (button, mut visibility) in &mut q_button {
+        let Ok((value, disabled, readonly)) = q_input.get(button.input) else {
+            continue;
+        };
+
+        let target = if value.0.is_empty() || disabled || readonly {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        if *visibility != target {
+            *visibility = target;
+        }
+    }
+}
@@ -0,0 +1,546 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::prelude::*;
+
+use crate::prelude::{FormElementDisabled, FormThemes, SliderBundle, SliderSettings, SliderValue};
+
+/// A Bevy `Plugin` providing the systems required to make a [`ColorPickerBundle`] work.
+pub struct ColorPickerPlugin;
+
+impl Plugin for ColorPickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                create,
+                toggle,
+                channel_interaction,
+                hsv_interaction,
+                update
+                    .after(create)
+                    .after(channel_interaction)
+                    .after(hsv_interaction),
+            ),
+        )
+        .register_type::<ColorPickerValue>()
+        .register_type::<ColorPickerTextStyle>()
+        .register_type::<ColorChannel>()
+        .register_type::<HsvChannel>()
+        .register_type::<ColorPickerChannelButton>()
+        .register_type::<ColorPickerHsvSlider>();
+    }
+}
+
+/// Amount a channel changes with each press of a channel button, out of 255.
+const CHANNEL_STEP: f32 = 17.0;
+
+/// A bundle providing the components required for a color picker.
+///
+/// Add this to a Bevy `NodeBundle`. The picker renders a swatch of the current color that opens a
+/// panel for adjusting the red, green and blue channels, and sliders for the hue, saturation and
+/// value of the color.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((NodeBundle::default(), ColorPickerBundle::new(Color::ORANGE)));
+/// }
+/// ```
+#[derive(Bundle, Default)]
+pub struct ColorPickerBundle {
+    /// A component containing the current color.
+    pub value: ColorPickerValue,
+    /// The style of the texts of the panel.
+    pub text_style: ColorPickerTextStyle,
+    /// This component's value is managed by Bevy's UI systems and enables tracking of hovers and presses.
+    pub interaction: Interaction,
+}
+
+impl ColorPickerBundle {
+    /// Creates a color picker with the given initial color.
+    #[must_use]
+    pub fn new(color: Color) -> Self {
+        Self {
+            value: ColorPickerValue(color),
+            ..default()
+        }
+    }
+
+    /// Sets the style of the texts of the panel.
+    #[must_use]
+    pub fn with_text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = ColorPickerTextStyle(Some(text_style));
+        self
+    }
+}
+
+/// A component containing the current color of the color picker.
+#[derive(Component, Clone, Copy, Default, Debug, Reflect)]
+pub struct ColorPickerValue(pub Color);
+
+/// The Bevy `TextStyle` used for the texts of a color picker. Uses the text style of the
+/// [`FormTheme`](crate::prelude::FormTheme) if not set.
+#[derive(Component, Clone, Default, Debug, Reflect)]
+pub struct ColorPickerTextStyle(pub Option<TextStyle>);
+
+/// A channel of a color that can be adjusted in the picker.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+pub enum ColorChannel {
+    /// The red channel.
+    Red,
+    /// The green channel.
+    Green,
+    /// The blue channel.
+    Blue,
+}
+
+impl ColorChannel {
+    const ALL: [ColorChannel; 3] = [ColorChannel::Red, ColorChannel::Green, ColorChannel::Blue];
+
+    fn label(self) -> &'static str {
+        match self {
+            ColorChannel::Red => "R",
+            ColorChannel::Green => "G",
+            ColorChannel::Blue => "B",
+        }
+    }
+
+    /// Returns the value of the channel in the range `0.0` - `1.0`.
+    #[must_use]
+    pub fn get(self, color: Color) -> f32 {
+        match self {
+            ColorChannel::Red => color.r(),
+            ColorChannel::Green => color.g(),
+            ColorChannel::Blue => color.b(),
+        }
+    }
+
+    /// Sets the value of the channel. The value is clamped to `0.0` - `1.0`.
+    pub fn set(self, color: &mut Color, value: f32) {
+        let value = value.clamp(0.0, 1.0);
+        match self {
+            ColorChannel::Red => color.set_r(value),
+            ColorChannel::Green => color.set_g(value),
+            ColorChannel::Blue => color.set_b(value),
+        };
+    }
+}
+
+/// A component of the color in the HSV model that can be adjusted in the picker.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+pub enum HsvChannel {
+    /// The hue in degrees, from `0` to `360`.
+    Hue,
+    /// The saturation, from `0.0` to `1.0`.
+    Saturation,
+    /// The value (brightness), from `0.0` to `1.0`.
+    Value,
+}
+
+impl HsvChannel {
+    const ALL: [HsvChannel; 3] = [HsvChannel::Hue, HsvChannel::Saturation, HsvChannel::Value];
+
+    fn label(self) -> &'static str {
+        match self {
+            HsvChannel::Hue => "H",
+            HsvChannel::Saturation => "S",
+            HsvChannel::Value => "V",
+        }
+    }
+
+    /// Returns the range of the slider adjusting the channel.
+    #[must_use]
+    pub fn slider_settings(self) -> SliderSettings {
+        match self {
+            HsvChannel::Hue => SliderSettings {
+                min: 0.0,
+                max: 360.0,
+                step: 1.0,
+            },
+            HsvChannel::Saturation | HsvChannel::Value => SliderSettings {
+                min: 0.0,
+                max: 1.0,
+                step: 0.01,
+            },
+        }
+    }
+}
+
+/// A button of the picker panel that increments or decrements a channel.
+#[derive(Component, Reflect)]
+pub struct ColorPickerChannelButton {
+    /// The color picker the button belongs to.
+    pub picker: Entity,
+    /// The channel the button changes.
+    pub channel: ColorChannel,
+    /// If true the channel is incremented, otherwise decremented.
+    pub increment: bool,
+}
+
+/// A slider of the picker panel adjusting a channel of the HSV model.
+#[derive(Component, Reflect)]
+pub struct ColorPickerHsvSlider {
+    /// The color picker the slider belongs to.
+    pub picker: Entity,
+    /// The channel the slider changes.
+    pub channel: HsvChannel,
+}
+
+/// Entities making up a color picker.
+#[derive(Component)]
+struct ColorPickerInner {
+    swatch: Entity,
+    panel: Entity,
+    channel_texts: [Entity; 3],
+    hsv_sliders: [Entity; 3],
+}
+
+/// The swatch showing the current color. Pressing it opens or closes the panel.
+#[derive(Component)]
+struct ColorPickerSwatch {
+    picker: Entity,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn create(
+    mut commands: Commands,
+    themes: FormThemes,
+    q_added: Query<(Entity, &ColorPickerValue, &ColorPickerTextStyle), Added<ColorPickerValue>>,
+) {
+    for (entity, value, text_style) in &q_added {
+        let text_style = text_style
+            .0
+            .clone()
+            .unwrap_or_else(|| themes.get(entity).text_style.clone());
+
+        let swatch = commands
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        width: Val::Px(32.0),
+                        height: Val::Px(20.0),
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    background_color: value.0.into(),
+                    border_color: Color::GRAY.into(),
+                    ..default()
+                },
+                ColorPickerSwatch { picker: entity },
+                Name::new("swatch"),
+            ))
+            .id();
+
+        let mut channel_texts = [Entity::PLACEHOLDER; 3];
+        let mut hsv_sliders = [Entity::PLACEHOLDER; 3];
+        let hsv = hsv_values(value.0, 0.0);
+        let panel = commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        display: Display::None,
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.15, 0.15, 0.15).into(),
+                    ..default()
+                },
+                Name::new("panel"),
+            ))
+            .with_children(|parent| {
+                for (channel, channel_text) in ColorChannel::ALL.into_iter().zip(&mut channel_texts)
+                {
+                    parent
+                        .spawn(NodeBundle {
+                            style: Style {
+                                column_gap: Val::Px(4.0),
+                                ..default()
+                            },
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                channel.label(),
+                                text_style.clone(),
+                            ));
+                            spawn_channel_button(parent, entity, channel, false, &text_style);
+                            *channel_text = parent
+                                .spawn(TextBundle::from_section(
+                                    channel_value_text(channel, value.0),
+                                    text_style.clone(),
+                                ))
+                                .id();
+                            spawn_channel_button(parent, entity, channel, true, &text_style);
+                        });
+                }
+
+                for ((channel, value), hsv_slider) in
+                    HsvChannel::ALL.into_iter().zip(hsv).zip(&mut hsv_sliders)
+                {
+                    *hsv_slider = spawn_hsv_slider(parent, entity, channel, value, &text_style);
+                }
+            })
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(ColorPickerInner {
+                swatch,
+                panel,
+                channel_texts,
+                hsv_sliders,
+            })
+            .push_children(&[swatch, panel]);
+    }
+}
+
+fn spawn_channel_button(
+    parent: &mut ChildBuilder,
+    picker: Entity,
+    channel: ColorChannel,
+    increment: bool,
+    text_style: &TextStyle,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    justify_content: JustifyContent::Center,
+                    padding: UiRect::horizontal(Val::Px(4.0)),
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                ..default()
+            },
+            ColorPickerChannelButton {
+                picker,
+                channel,
+                increment,
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                if increment { "+" } else { "-" },
+                text_style.clone(),
+            ));
+        });
+}
+
+/// Spawns a row with the label and slider of the channel and returns the slider.
+fn spawn_hsv_slider(
+    parent: &mut ChildBuilder,
+    picker: Entity,
+    channel: HsvChannel,
+    value: SliderValue,
+    text_style: &TextStyle,
+) -> Entity {
+    let mut slider = Entity::PLACEHOLDER;
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(4.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                channel.label(),
+                text_style.clone(),
+            ));
+            slider = parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(160.0),
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(4.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    SliderBundle::new(channel.slider_settings())
+                        .with_value(value.0)
+                        .with_text_style(text_style.clone()),
+                    ColorPickerHsvSlider { picker, channel },
+                ))
+                .id();
+        });
+    slider
+}
+
+fn channel_value_text(channel: ColorChannel, color: Color) -> String {
+    format!("{:>3}", (channel.get(color) * 255.0).round())
+}
+
+/// Returns the hue (`0` - `360`), saturation and value of the color.
+fn to_hsv(color: Color) -> [f32; 3] {
+    let (r, g, b) = (color.r(), color.g(), color.b());
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+
+    let hue = if delta <= 0.0 {
+        0.0
+    } else if r >= g && r >= b {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if g >= b {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let saturation = if max <= 0.0 { 0.0 } else { delta / max };
+
+    [hue, saturation, max]
+}
+
+/// Returns the color of the hue (`0` - `360`), saturation and value, keeping the alpha of `alpha`.
+fn from_hsv(hue: f32, saturation: f32, value: f32, alpha: f32) -> Color {
+    let chroma = value * saturation;
+    let sector = (hue / 60.0).rem_euclid(6.0);
+    let second = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+    let (red, green, blue) = if sector < 1.0 {
+        (chroma, second, 0.0)
+    } else if sector < 2.0 {
+        (second, chroma, 0.0)
+    } else if sector < 3.0 {
+        (0.0, chroma, second)
+    } else if sector < 4.0 {
+        (0.0, second, chroma)
+    } else if sector < 5.0 {
+        (second, 0.0, chroma)
+    } else {
+        (chroma, 0.0, second)
+    };
+
+    let offset = value - chroma;
+    Color::rgba(red + offset, green + offset, blue + offset, alpha)
+}
+
+/// Returns the values of the HSV sliders showing the color. Grays have no hue, so the current
+/// hue is kept for them.
+fn hsv_values(color: Color, hue: f64) -> [SliderValue; 3] {
+    let [color_hue, saturation, value] = to_hsv(color);
+    let hue = if saturation > 0.0 {
+        f64::from(color_hue)
+    } else {
+        hue
+    };
+
+    HsvChannel::ALL.map(|channel| {
+        let channel_value = match channel {
+            HsvChannel::Hue => hue,
+            HsvChannel::Saturation => f64::from(saturation),
+            HsvChannel::Value => f64::from(value),
+        };
+        SliderValue(channel.slider_settings().snap(channel_value))
+    })
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn toggle(
+    q_swatch: Query<(&ColorPickerSwatch, &Interaction), Changed<Interaction>>,
+    q_picker: Query<&ColorPickerInner, Without<FormElementDisabled>>,
+    mut q_style: Query<&mut Style>,
+) {
+    for (swatch, interaction) in &q_swatch {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(inner) = q_picker.get(swatch.picker) else {
+            continue;
+        };
+
+        if let Ok(mut style) = q_style.get_mut(inner.panel) {
+            style.display = match style.display {
+                Display::None => Display::Flex,
+                _ => Display::None,
+            };
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn channel_interaction(
+    q_button: Query<(&ColorPickerChannelButton, &Interaction), Changed<Interaction>>,
+    mut q_picker: Query<&mut ColorPickerValue, Without<FormElementDisabled>>,
+) {
+    for (button, interaction) in &q_button {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut value) = q_picker.get_mut(button.picker) else {
+            continue;
+        };
+
+        let step = if button.increment {
+            CHANNEL_STEP
+        } else {
+            -CHANNEL_STEP
+        };
+        let channel = (button.channel.get(value.0) * 255.0 + step).round() / 255.0;
+        button.channel.set(&mut value.0, channel);
+    }
+}
+
+/// Applies the HSV sliders to the color once one of them was moved.
+#[allow(clippy::needless_pass_by_value)]
+fn hsv_interaction(
+    q_changed: Query<&ColorPickerHsvSlider, Changed<SliderValue>>,
+    q_slider: Query<&SliderValue>,
+    mut q_picker: Query<(&mut ColorPickerValue, &ColorPickerInner), Without<FormElementDisabled>>,
+) {
+    for slider in &q_changed {
+        let Ok((mut value, inner)) = q_picker.get_mut(slider.picker) else {
+            continue;
+        };
+
+        let Ok(hsv) = q_slider.get_many(inner.hsv_sliders) else {
+            continue;
+        };
+
+        let hsv = hsv.map(|slider_value| *slider_value);
+        // The sliders were only updated to show the color, e.g. after pressing a channel button.
+        if hsv == hsv_values(value.0, hsv[0].0) {
+            continue;
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let [hue, saturation, brightness] = hsv.map(|slider_value| slider_value.0 as f32);
+        value.0 = from_hsv(hue, saturation, brightness, value.0.a());
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update(
+    q_picker: Query<(&ColorPickerValue, &ColorPickerInner), Changed<ColorPickerValue>>,
+    mut q_swatch: Query<&mut BackgroundColor>,
+    mut q_text: Query<&mut Text>,
+    mut q_slider: Query<&mut SliderValue>,
+) {
+    for (value, inner) in &q_picker {
+        if let Ok(mut background_color) = q_swatch.get_mut(inner.swatch) {
+            background_color.0 = value.0;
+        }
+
+        for (channel, entity) in ColorChannel::ALL.into_iter().zip(inner.channel_texts) {
+            if let Ok(mut text) = q_text.get_mut(entity) {
+                text.sections[0].value = channel_value_text(channel, value.0);
+            }
+        }
+
+        let hue = q_slider.get(inner.hsv_sliders[0]).map_or(0.0, |hue| hue.0);
+        for (entity, slider_value) in inner.hsv_sliders.into_iter().zip(hsv_values(value.0, hue)) {
+            if let Ok(mut current) = q_slider.get_mut(entity) {
+                // Only trigger change detection when the value actually changed.
+                current.set_if_neq(slider_value);
+            }
+        }
+    }
+}
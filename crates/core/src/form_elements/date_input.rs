@@ -0,0 +1,225 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::prelude::*;
+use chrono::NaiveDate;
+
+use crate::form_elements::text_input;
+use crate::prelude::{
+    FormElementInvalid, FormElementValid, FormValidationError, TextInputCursorPos, TextInputFilter,
+    TextInputValue,
+};
+
+/// A Bevy `Plugin` providing the systems required to make a [`DateInputSettings`] work.
+pub struct DateInputPlugin;
+
+impl Plugin for DateInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                create,
+                insert_separators
+                    .after(text_input::keyboard)
+                    .before(text_input::update_value),
+                validate.after(text_input::validate),
+            ),
+        );
+    }
+}
+
+/// Settings for a date text input.
+///
+/// Add this next to a [`TextInputBundle`](crate::prelude::TextInputBundle) to mark the input
+/// invalid until the value is a date in the given format and within the bounds.
+/// Formats consisting only of numeric fields (`%Y`, `%y`, `%m`, `%d`) and separators only accept
+/// digits, the separators are inserted while typing.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default(),
+///         DateInputSettings::new("%Y-%m-%d").with_min("2000-01-01"),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug)]
+pub struct DateInputSettings {
+    /// The `chrono` format of the date.
+    pub format: String,
+    /// The earliest valid date.
+    pub min: Option<NaiveDate>,
+    /// The latest valid date.
+    pub max: Option<NaiveDate>,
+}
+
+impl DateInputSettings {
+    /// Creates settings for dates in the given format without bounds.
+    #[must_use]
+    pub fn new(format: impl Into<String>) -> Self {
+        Self {
+            format: format.into(),
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Sets the earliest valid date. The date is parsed using the format.
+    /// Invalid dates are ignored with a warning.
+    #[must_use]
+    pub fn with_min(mut self, min: &str) -> Self {
+        self.min = self.parse_bound(min);
+        self
+    }
+
+    /// Sets the latest valid date. The date is parsed using the format.
+    /// Invalid dates are ignored with a warning.
+    #[must_use]
+    pub fn with_max(mut self, max: &str) -> Self {
+        self.max = self.parse_bound(max);
+        self
+    }
+
+    fn parse_bound(&self, value: &str) -> Option<NaiveDate> {
+        let date = self.parse(value);
+        if date.is_none() {
+            warn!(
+                "Date bound {value} does not match the format {}",
+                self.format
+            );
+        }
+        date
+    }
+
+    /// Parses the value using the format. Surrounding whitespace is ignored.
+    #[must_use]
+    pub fn parse(&self, value: &str) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(value.trim(), &self.format).ok()
+    }
+
+    /// Returns true if the date is within the bounds.
+    #[must_use]
+    pub fn in_range(&self, date: NaiveDate) -> bool {
+        self.min.map_or(true, |min| date >= min) && self.max.map_or(true, |max| date <= max)
+    }
+
+    /// Parses the value and returns the date if it is within the bounds.
+    #[must_use]
+    pub fn resolve(&self, value: &str) -> Option<NaiveDate> {
+        self.parse(value).filter(|date| self.in_range(*date))
+    }
+
+    /// Formats the date using the format.
+    #[must_use]
+    pub fn display(&self, date: NaiveDate) -> String {
+        date.format(&self.format).to_string()
+    }
+
+    /// Returns the template of the format with `0` for every digit, e.g. `0000-00-00` for
+    /// `%Y-%m-%d`. Returns `None` if the format contains non-numeric fields.
+    fn template(&self) -> Option<String> {
+        let mut template = String::new();
+        let mut chars = self.format.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                if c.is_ascii_digit() {
+                    return None;
+                }
+                template.push(c);
+                continue;
+            }
+
+            let digits = match chars.next()? {
+                'Y' => 4,
+                'y' | 'm' | 'd' => 2,
+                _ => return None,
+            };
+            template.extend(std::iter::repeat('0').take(digits));
+        }
+
+        Some(template)
+    }
+}
+
+/// Template of a date input typing only digits. Separators are inserted automatically.
+#[derive(Component)]
+struct DateInputTemplate {
+    template: String,
+    len: usize,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn create(
+    mut commands: Commands,
+    q_added: Query<(Entity, &DateInputSettings, &TextInputValue), Added<DateInputSettings>>,
+) {
+    for (entity, settings, value) in &q_added {
+        if let Some(template) = settings.template() {
+            commands.entity(entity).insert((
+                DateInputTemplate {
+                    template,
+                    len: value.0.chars().count(),
+                },
+                TextInputFilter::Digits,
+            ));
+        }
+    }
+}
+
+fn insert_separators(
+    mut q_input: Query<
+        (
+            &mut DateInputTemplate,
+            &mut TextInputValue,
+            &mut TextInputCursorPos,
+        ),
+        Changed<TextInputValue>,
+    >,
+) {
+    for (mut template, mut value, mut cursor_pos) in &mut q_input {
+        let len = value.0.chars().count();
+
+        // Only apply the template while typing at the end, so editing in the middle stays possible.
+        if len > template.len && cursor_pos.0 == len {
+            let mut digits = value.0.chars().filter(char::is_ascii_digit);
+            let mut formatted = String::new();
+            for t in template.template.chars() {
+                if t != '0' {
+                    formatted.push(t);
+                } else if let Some(digit) = digits.next() {
+                    formatted.push(digit);
+                } else {
+                    break;
+                }
+            }
+
+            if formatted != value.0 {
+                cursor_pos.0 = formatted.chars().count();
+                value.0 = formatted;
+            }
+        }
+
+        template.len = value.0.chars().count();
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn validate(
+    mut commands: Commands,
+    q_input: Query<(Entity, &DateInputSettings, &TextInputValue), Changed<TextInputValue>>,
+) {
+    for (entity, settings, value) in &q_input {
+        // Empty values are handled by the required validation of the text input.
+        if value.0.trim().is_empty() || settings.resolve(&value.0).is_some() {
+            continue;
+        }
+
+        commands
+            .entity(entity)
+            .insert(FormElementInvalid(FormValidationError::Invalid(entity)))
+            .remove::<FormElementValid>();
+    }
+}
@@ -0,0 +1,453 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::prelude::*;
+use chrono::{Datelike, Days, Months, NaiveDate};
+
+use crate::form::form_keyboard;
+use crate::prelude::{
+    DateInputSettings, FormElementDisabled, FormElementFocus, FormTheme, TextInputReadonly,
+    TextInputTextStyle, TextInputValue,
+};
+
+/// A Bevy `Plugin` providing the systems required to make a [`DatePicker`] work.
+pub struct DatePickerPlugin;
+
+impl Plugin for DatePickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FormTheme>()
+            .add_systems(
+                Update,
+                (
+                    create,
+                    toggle,
+                    navigate,
+                    day_interaction,
+                    keyboard.before(form_keyboard),
+                    close,
+                    update.after(create),
+                ),
+            )
+            .register_type::<DatePicker>();
+    }
+}
+
+/// Number of days shown in the calendar, six weeks.
+const CALENDAR_DAYS: usize = 42;
+
+const SELECTED_COLOR: Color = Color::rgb(0.6, 0.75, 0.95);
+
+/// Adds a popup calendar to a date input.
+///
+/// Add this next to a [`TextInputBundle`](crate::prelude::TextInputBundle) and a
+/// [`DateInputSettings`]. The date can still be typed, the button beside the input opens a
+/// calendar to choose it instead. Days outside of the bounds of the settings can not be chosen.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default(),
+///         DateInputSettings::new("%Y-%m-%d"),
+///         DatePicker,
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Default, Debug, Reflect)]
+pub struct DatePicker;
+
+/// Entities making up the calendar of a date picker and the month it shows.
+#[derive(Component)]
+struct DatePickerState {
+    popup: Entity,
+    title: Entity,
+    days: Vec<Entity>,
+    month: NaiveDate,
+    open: bool,
+}
+
+/// The button opening the calendar.
+#[derive(Component)]
+struct DatePickerToggle {
+    picker: Entity,
+}
+
+/// A button switching the calendar to the previous or next month.
+#[derive(Component)]
+struct DatePickerNavigation {
+    picker: Entity,
+    forward: bool,
+}
+
+/// A day of the calendar.
+#[derive(Component)]
+struct DatePickerDay {
+    picker: Entity,
+    index: usize,
+}
+
+/// Returns the first day of the month of the date.
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// Returns the date shown in the calendar cell. The calendar starts on the Monday of the week
+/// containing the first day of the month.
+fn calendar_date(month: NaiveDate, index: usize) -> Option<NaiveDate> {
+    let start =
+        month.checked_sub_days(Days::new(u64::from(month.weekday().num_days_from_monday())))?;
+    start.checked_add_days(Days::new(index as u64))
+}
+
+/// Returns the current date in UTC.
+fn today() -> NaiveDate {
+    let days = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / 86_400);
+
+    NaiveDate::default()
+        .checked_add_days(Days::new(days))
+        .unwrap_or_default()
+}
+
+/// Returns the month shown when the calendar is opened: the month of the entered date, or the
+/// current month clamped to the bounds.
+fn initial_month(settings: &DateInputSettings, value: &str) -> NaiveDate {
+    let date = settings.parse(value).unwrap_or_else(|| {
+        let today = today();
+        let today = settings.min.map_or(today, |min| today.max(min));
+        settings.max.map_or(today, |max| today.min(max))
+    });
+
+    first_of_month(date)
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn create(
+    mut commands: Commands,
+    theme: Res<FormTheme>,
+    q_added: Query<
+        (
+            Entity,
+            &DateInputSettings,
+            &TextInputValue,
+            &TextInputTextStyle,
+        ),
+        Added<DatePicker>,
+    >,
+) {
+    for (entity, settings, value, text_style) in &q_added {
+        let text_style = text_style.0.clone();
+        let month = initial_month(settings, &value.0);
+
+        let toggle = commands
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        margin: UiRect::left(Val::Auto),
+                        padding: UiRect::horizontal(Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: Color::NONE.into(),
+                    ..default()
+                },
+                DatePickerToggle { picker: entity },
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section("v", text_style.clone()));
+            })
+            .id();
+
+        let mut title = Entity::PLACEHOLDER;
+        let mut days = Vec::new();
+        let popup = commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        display: Display::None,
+                        position_type: PositionType::Absolute,
+                        top: Val::Percent(100.0),
+                        left: Val::Px(0.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: theme.popup_background.into(),
+                    z_index: ZIndex::Global(1),
+                    ..default()
+                },
+                Name::new("calendar"),
+            ))
+            .with_children(|parent| {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            justify_content: JustifyContent::SpaceBetween,
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        spawn_navigation(parent, entity, false, &text_style);
+                        title = parent
+                            .spawn(TextBundle::from_section(String::new(), text_style.clone()))
+                            .id();
+                        spawn_navigation(parent, entity, true, &text_style);
+                    });
+
+                days = spawn_days(parent, entity, &text_style);
+            })
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(DatePickerState {
+                popup,
+                title,
+                days,
+                month,
+                open: false,
+            })
+            .push_children(&[toggle, popup]);
+    }
+}
+
+/// Spawns the grid of weekday names and calendar days. Returns the day buttons.
+fn spawn_days(parent: &mut ChildBuilder, picker: Entity, text_style: &TextStyle) -> Vec<Entity> {
+    let mut days = Vec::with_capacity(CALENDAR_DAYS);
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                display: Display::Grid,
+                grid_template_columns: RepeatedGridTrack::flex(7, 1.0),
+                column_gap: Val::Px(2.0),
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            for weekday in ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"] {
+                parent.spawn(TextBundle::from_section(weekday, text_style.clone()));
+            }
+
+            for index in 0..CALENDAR_DAYS {
+                let day = parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            background_color: Color::NONE.into(),
+                            ..default()
+                        },
+                        DatePickerDay { picker, index },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(String::new(), text_style.clone()));
+                    })
+                    .id();
+                days.push(day);
+            }
+        });
+
+    days
+}
+
+fn spawn_navigation(
+    parent: &mut ChildBuilder,
+    picker: Entity,
+    forward: bool,
+    text_style: &TextStyle,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    padding: UiRect::horizontal(Val::Px(4.0)),
+                    ..default()
+                },
+                background_color: Color::NONE.into(),
+                ..default()
+            },
+            DatePickerNavigation { picker, forward },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                if forward { ">" } else { "<" },
+                text_style.clone(),
+            ));
+        });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn toggle(
+    mut commands: Commands,
+    q_toggle: Query<(&DatePickerToggle, &Interaction), Changed<Interaction>>,
+    mut q_picker: Query<
+        (&DateInputSettings, &TextInputValue, &mut DatePickerState),
+        (Without<FormElementDisabled>, Without<TextInputReadonly>),
+    >,
+) {
+    for (toggle, interaction) in &q_toggle {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok((settings, value, mut state)) = q_picker.get_mut(toggle.picker) else {
+            continue;
+        };
+
+        state.open = !state.open;
+        if state.open {
+            state.month = initial_month(settings, &value.0);
+        }
+        commands.entity(toggle.picker).insert(FormElementFocus);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn navigate(
+    q_navigation: Query<(&DatePickerNavigation, &Interaction), Changed<Interaction>>,
+    mut q_picker: Query<&mut DatePickerState>,
+) {
+    for (navigation, interaction) in &q_navigation {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok(mut state) = q_picker.get_mut(navigation.picker) else {
+            continue;
+        };
+
+        let month = if navigation.forward {
+            state.month.checked_add_months(Months::new(1))
+        } else {
+            state.month.checked_sub_months(Months::new(1))
+        };
+        if let Some(month) = month {
+            state.month = month;
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn day_interaction(
+    q_day: Query<(&DatePickerDay, &Interaction), Changed<Interaction>>,
+    mut q_picker: Query<
+        (
+            &DateInputSettings,
+            &mut TextInputValue,
+            &mut DatePickerState,
+        ),
+        (Without<FormElementDisabled>, Without<TextInputReadonly>),
+    >,
+) {
+    for (day, interaction) in &q_day {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Ok((settings, mut value, mut state)) = q_picker.get_mut(day.picker) else {
+            continue;
+        };
+
+        let Some(date) = calendar_date(state.month, day.index) else {
+            continue;
+        };
+        if !settings.in_range(date) {
+            continue;
+        }
+
+        value.0 = settings.display(date);
+        state.open = false;
+    }
+}
+
+fn keyboard(
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut q_picker: Query<&mut DatePickerState, With<FormElementFocus>>,
+) {
+    for mut state in &mut q_picker {
+        // The key is consumed, so an open calendar does not cancel the form.
+        if state.open && keyboard_input.clear_just_released(KeyCode::Escape) {
+            state.open = false;
+        }
+    }
+}
+
+fn close(
+    mut focus_removed: RemovedComponents<FormElementFocus>,
+    mut q_picker: Query<&mut DatePickerState>,
+) {
+    for entity in focus_removed.read() {
+        if let Ok(mut state) = q_picker.get_mut(entity) {
+            state.open = false;
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update(
+    q_picker: Query<
+        (
+            &DateInputSettings,
+            &TextInputValue,
+            &TextInputTextStyle,
+            &DatePickerState,
+        ),
+        Or<(Changed<DatePickerState>, Changed<TextInputValue>)>,
+    >,
+    q_children: Query<&Children>,
+    mut q_text: Query<&mut Text>,
+    mut q_style: Query<&mut Style>,
+    mut q_background: Query<&mut BackgroundColor>,
+) {
+    for (settings, value, text_style, state) in &q_picker {
+        if let Ok(mut style) = q_style.get_mut(state.popup) {
+            style.display = if state.open {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+
+        if let Ok(mut text) = q_text.get_mut(state.title) {
+            text.sections[0].value = state.month.format("%B %Y").to_string();
+        }
+
+        let selected = settings.parse(&value.0);
+        for (index, day) in state.days.iter().enumerate() {
+            let Some(date) = calendar_date(state.month, index) else {
+                continue;
+            };
+
+            if let Ok(mut background) = q_background.get_mut(*day) {
+                background.0 = if Some(date) == selected {
+                    SELECTED_COLOR
+                } else {
+                    Color::NONE
+                };
+            }
+
+            // Days of other months and days outside of the bounds are dimmed.
+            let color = text_style.0.color;
+            let color = if date.month() != state.month.month() || !settings.in_range(date) {
+                color.with_a(color.a() * 0.4)
+            } else {
+                color
+            };
+
+            let Some(text_entity) = q_children.get(*day).ok().and_then(|c| c.first()) else {
+                continue;
+            };
+            if let Ok(mut text) = q_text.get_mut(*text_entity) {
+                text.sections[0].value = date.day().to_string();
+                text.sections[0].style.color = color;
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::prelude::*;
+
+use crate::form_element::is_drawn;
+use crate::form_elements::text_area;
+use crate::form_elements::text_input::TextInputPlaceholderInner;
+use crate::prelude::{FormElementFocus, TextInputPlaceholder, TextInputTextStyle, TextInputValue};
+
+/// A Bevy `Plugin` providing the systems required to make a [`FloatingLabel`] work.
+pub struct FloatingLabelPlugin;
+
+impl Plugin for FloatingLabelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, animate.run_if(is_drawn))
+            .register_type::<FloatingLabel>();
+    }
+}
+
+/// Turns the placeholder of a text input into a label floating above the value.
+///
+/// While the input is empty and not focused, the placeholder is shown in place of the value. Once
+/// the input is focused or has a value, the placeholder shrinks and moves up above the input.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default().with_placeholder("Email", None),
+///         FloatingLabel::default(),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct FloatingLabel {
+    /// The time in seconds the label takes to move up or down.
+    pub duration: f32,
+    /// The size of the floating label relative to the placeholder.
+    pub scale: f32,
+    /// How far the label has moved up, from `0.0` to `1.0`.
+    progress: f32,
+}
+
+impl Default for FloatingLabel {
+    fn default() -> Self {
+        Self {
+            duration: 0.15,
+            scale: 0.75,
+            progress: 0.0,
+        }
+    }
+}
+
+impl FloatingLabel {
+    /// Returns true if the label is above the input, or moving there.
+    #[must_use]
+    pub fn is_floating(&self) -> bool {
+        self.progress > 0.0
+    }
+}
+
+// The progress and font sizes are only compared to skip unchanged values.
+#[allow(
+    clippy::needless_pass_by_value,
+    clippy::type_complexity,
+    clippy::float_cmp
+)]
+fn animate(
+    time: Res<Time>,
+    mut q_input: Query<(
+        Entity,
+        &mut FloatingLabel,
+        &TextInputValue,
+        &TextInputTextStyle,
+        &TextInputPlaceholder,
+        Has<FormElementFocus>,
+    )>,
+    q_children: Query<&Children>,
+    mut q_placeholder: Query<&mut Style, With<TextInputPlaceholderInner>>,
+    mut q_text: Query<&mut Text>,
+) {
+    for (entity, mut label, value, text_style, placeholder, focused) in &mut q_input {
+        let target = if focused || !value.0.is_empty() {
+            1.0
+        } else {
+            0.0
+        };
+        let step = if label.duration > 0.0 {
+            time.delta_seconds() / label.duration
+        } else {
+            1.0
+        };
+        let progress = if label.progress < target {
+            (label.progress + step).min(target)
+        } else {
+            (label.progress - step).max(target)
+        };
+        if label.progress != progress {
+            label.progress = progress;
+        }
+
+        let Some(placeholder_node) = q_children.get(entity).ok().and_then(|children| {
+            children
+                .iter()
+                .find(|child| q_placeholder.contains(**child))
+        }) else {
+            continue;
+        };
+
+        let style = placeholder.get_style(&text_style.0);
+        let scale = 1.0 + (label.scale - 1.0) * progress;
+        let top = Val::Px(-progress * text_area::line_height(&style) * label.scale);
+        if let Ok(mut node_style) = q_placeholder.get_mut(*placeholder_node) {
+            if node_style.top != top {
+                node_style.top = top;
+            }
+        }
+
+        let font_size = style.font_size * scale;
+        for child in q_children.iter_descendants(*placeholder_node) {
+            let Ok(mut text) = q_text.get_mut(child) else {
+                continue;
+            };
+            if text
+                .sections
+                .iter()
+                .any(|section| section.style.font_size != font_size)
+            {
+                for section in &mut text.sections {
+                    section.style.font_size = font_size;
+                }
+            }
+        }
+    }
+}
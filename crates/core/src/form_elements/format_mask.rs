@@ -0,0 +1,133 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::prelude::*;
+
+use crate::form_elements::text_input;
+use crate::prelude::{TextInputCursorPos, TextInputValue};
+
+/// A Bevy `Plugin` providing the systems required to make a [`TextInputFormatMask`] work.
+pub struct FormatMaskPlugin;
+
+impl Plugin for FormatMaskPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            format
+                .after(text_input::keyboard)
+                .before(text_input::update_value),
+        )
+        .register_type::<TextInputFormatMask>();
+    }
+}
+
+/// Formats the value of a text input while typing, e.g. `(###) ###-####` for phone numbers or
+/// `AAAA-AAAA-AAAA` for serial keys.
+///
+/// `#` is replaced by a digit, `A` by a letter and `*` by a letter or digit. All other characters
+/// are separators that are inserted automatically and can not be typed. Characters not fitting the
+/// mask are dropped. Generated forms submit the value without the separators.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default(),
+///         TextInputFormatMask::new("####-####-####"),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct TextInputFormatMask(pub String);
+
+impl TextInputFormatMask {
+    /// Creates a format mask from the pattern.
+    #[must_use]
+    pub fn new(mask: impl Into<String>) -> Self {
+        Self(mask.into())
+    }
+
+    /// Returns the value without the separators of the mask.
+    #[must_use]
+    pub fn strip(&self, value: &str) -> String {
+        value.chars().filter(|c| !self.is_separator(*c)).collect()
+    }
+
+    /// Returns the value formatted with the mask, and the cursor position after the same number of
+    /// typed characters as before `cursor_pos`.
+    #[must_use]
+    pub fn format(&self, value: &str, cursor_pos: usize) -> (String, usize) {
+        let typed_before_cursor = value
+            .chars()
+            .take(cursor_pos)
+            .filter(|c| !self.is_separator(*c))
+            .count();
+        let stripped = self.strip(value);
+        let mut typed = stripped.chars().enumerate().peekable();
+
+        let mut formatted = String::new();
+        let mut cursor = 0;
+        for m in self.0.chars() {
+            if typed.peek().is_none() {
+                break;
+            }
+
+            if !is_slot(m) {
+                formatted.push(m);
+                continue;
+            }
+
+            let Some((i, c)) = typed.find(|(_, c)| slot_accepts(m, *c)) else {
+                break;
+            };
+            formatted.push(c);
+            if i < typed_before_cursor {
+                cursor = formatted.chars().count();
+            }
+        }
+
+        (formatted, cursor)
+    }
+
+    fn is_separator(&self, c: char) -> bool {
+        self.0.chars().any(|m| m == c && !is_slot(m))
+    }
+}
+
+fn is_slot(m: char) -> bool {
+    matches!(m, '#' | 'A' | '*')
+}
+
+fn slot_accepts(m: char, c: char) -> bool {
+    match m {
+        '#' => c.is_ascii_digit(),
+        'A' => c.is_alphabetic(),
+        _ => c.is_alphanumeric(),
+    }
+}
+
+fn format(
+    mut q_input: Query<
+        (
+            &TextInputFormatMask,
+            &mut TextInputValue,
+            &mut TextInputCursorPos,
+        ),
+        Or<(Changed<TextInputValue>, Changed<TextInputFormatMask>)>,
+    >,
+) {
+    for (mask, mut value, mut cursor_pos) in &mut q_input {
+        let (formatted, cursor) = mask.format(&value.0, cursor_pos.0);
+        if formatted == value.0 {
+            continue;
+        }
+
+        // Values set by other systems keep the cursor at the end.
+        if cursor_pos.is_changed() {
+            cursor_pos.0 = cursor;
+        }
+        value.0 = formatted;
+    }
+}
@@ -1,4 +1,42 @@
+/// An autocomplete extension for text inputs.
+pub mod autocomplete;
 /// A Button element for forms.
 pub mod button;
+/// A checkbox element for forms.
+pub mod checkbox;
+/// A clear button for text inputs.
+pub mod clear_button;
+/// A color picker element for forms.
+pub mod color_picker;
+/// A date extension for text inputs.
+#[cfg(feature = "date")]
+pub mod date_input;
+/// A calendar popup for date inputs.
+#[cfg(feature = "date")]
+pub mod date_picker;
+/// A floating label extension for text inputs.
+pub mod floating_label;
+/// A format mask extension for text inputs.
+pub mod format_mask;
+/// A numeric input extension for text inputs.
+pub mod number_input;
+/// A select element for forms.
+pub mod select;
+/// A slider element for forms.
+pub mod slider;
+/// A tag extension for text inputs.
+pub mod tag_input;
+/// A multi-line extension for text inputs.
+pub mod text_area;
+/// Caret rendering for text inputs.
+pub mod text_caret;
 /// A text input element for forms.
 pub mod text_input;
+/// Text selection for text inputs.
+pub mod text_selection;
+/// Value transforms for text inputs.
+pub mod text_transform;
+/// Undo and redo for text inputs.
+pub mod text_undo;
+/// A tooltip for form elements.
+pub mod tooltip;
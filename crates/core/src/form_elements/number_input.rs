@@ -0,0 +1,501 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::{input::keyboard::KeyboardInput, prelude::*};
+
+use crate::form_elements::text_input;
+use crate::prelude::{
+    FormElementDisabled, FormElementFocus, TextInputCursorPos, TextInputFilter, TextInputReadonly,
+    TextInputTextStyle, TextInputValue,
+};
+
+/// A Bevy `Plugin` providing the systems required to make a [`NumberInputSettings`] work.
+pub struct NumberInputPlugin;
+
+impl Plugin for NumberInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<NumberInputStepEvent>()
+            .add_systems(
+                Update,
+                (
+                    create.after(text_input::create),
+                    group_on_blur,
+                    normalize_on_focus,
+                    keyboard,
+                    spinner_interaction,
+                    spinner_repeat,
+                    step.after(keyboard)
+                        .after(spinner_interaction)
+                        .after(spinner_repeat),
+                ),
+            )
+            .register_type::<NumberInputSettings>()
+            .register_type::<NumberInputSpinner>();
+    }
+}
+
+/// Delay before a held spinner button starts repeating.
+const SPINNER_REPEAT_DELAY: f32 = 0.4;
+/// Interval between repeated steps while a spinner button is held.
+const SPINNER_REPEAT_INTERVAL: f32 = 0.05;
+
+/// Settings for a numeric text input.
+///
+/// Add this next to a [`TextInputBundle`](crate::prelude::TextInputBundle) to allow stepping the
+/// value with the `ArrowUp` and `ArrowDown` keys.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default().with_value("0"),
+///         NumberInputSettings {
+///             min: Some(0.0),
+///             max: Some(10.0),
+///             spinner: true,
+///             ..default()
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct NumberInputSettings {
+    /// The minimum value. Values are clamped to this when stepping.
+    pub min: Option<f64>,
+    /// The maximum value. Values are clamped to this when stepping.
+    pub max: Option<f64>,
+    /// The amount the value changes by with each step.
+    pub step: f64,
+    /// If true, increment and decrement buttons are rendered beside the input.
+    pub spinner: bool,
+    /// Separator used to group thousands (e.g. `1,000,000`) while the input is not focused.
+    /// The separators are removed again when the input is focused.
+    pub grouping: Option<char>,
+    /// Character separating the integer and fractional part.
+    pub decimal_separator: char,
+    /// Fixed number of decimal places. Values are formatted with exactly this many decimals when
+    /// stepping or when the input loses focus. If not set the decimals of `step` are used.
+    pub decimals: Option<usize>,
+    /// Non-editable text rendered before the value (e.g. a currency symbol).
+    pub prefix: Option<String>,
+    /// Non-editable text rendered after the value (e.g. a unit).
+    pub suffix: Option<String>,
+}
+
+impl Default for NumberInputSettings {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: 1.0,
+            spinner: false,
+            grouping: None,
+            decimal_separator: '.',
+            decimals: None,
+            prefix: None,
+            suffix: None,
+        }
+    }
+}
+
+impl NumberInputSettings {
+    /// Settings for a currency input showing the symbol in front of the value.
+    /// Values are grouped, formatted with `decimals` decimal places and can not be negative.
+    #[must_use]
+    pub fn currency(symbol: impl Into<String>, decimals: usize) -> Self {
+        Self {
+            min: Some(0.0),
+            grouping: Some(','),
+            decimals: Some(decimals),
+            prefix: Some(symbol.into()),
+            ..default()
+        }
+    }
+
+    /// Clamps the value to the configured range.
+    #[must_use]
+    pub fn clamp(&self, value: f64) -> f64 {
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+
+    /// Returns true if the value is outside of the range.
+    #[must_use]
+    pub fn is_out_of_range(&self, value: f64) -> bool {
+        self.min.is_some_and(|min| value < min) || self.max.is_some_and(|max| value > max)
+    }
+
+    /// Formats the value using the configured decimals or as many decimal places as the step has.
+    #[must_use]
+    pub fn format(&self, value: f64) -> String {
+        let decimals = self.decimals.unwrap_or_else(|| {
+            self.step
+                .to_string()
+                .split_once('.')
+                .map_or(0, |(_, fraction)| fraction.len())
+        });
+
+        format!("{value:.decimals$}").replace('.', &self.decimal_separator.to_string())
+    }
+
+    /// Settings for a percentage input between 0 and 100 showing a trailing `%`.
+    #[must_use]
+    pub fn percent() -> Self {
+        Self {
+            min: Some(0.0),
+            max: Some(100.0),
+            suffix: Some("%".to_string()),
+            ..default()
+        }
+    }
+
+    /// Returns a [`TextInputFilter`] only accepting numbers valid for these settings.
+    #[must_use]
+    pub fn filter(&self) -> TextInputFilter {
+        TextInputFilter::Decimal {
+            separator: self.decimal_separator,
+            negative: self.min.map_or(true, |min| min < 0.0),
+        }
+    }
+
+    /// Parses the value, ignoring grouping separators.
+    #[must_use]
+    pub fn parse(&self, value: &str) -> Option<f64> {
+        self.normalize(value)
+            .trim()
+            .replace(self.decimal_separator, ".")
+            .parse()
+            .ok()
+    }
+
+    /// Returns the value as it is displayed while the input is not focused.
+    /// Values outside of the range are clamped.
+    #[must_use]
+    pub fn display(&self, value: &str) -> String {
+        match self.parse(value) {
+            Some(parsed) if self.decimals.is_some() || self.is_out_of_range(parsed) => {
+                self.group(&self.format(self.clamp(parsed)))
+            }
+            _ => self.group(value),
+        }
+    }
+
+    /// Removes grouping separators from the value.
+    #[must_use]
+    pub fn normalize(&self, value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| Some(*c) != self.grouping)
+            .collect()
+    }
+
+    /// Inserts grouping separators into the integer part of the value.
+    /// Values that are not plain decimal numbers are returned normalized but otherwise unchanged.
+    #[must_use]
+    pub fn group(&self, value: &str) -> String {
+        let value = self.normalize(value);
+        let Some(separator) = self.grouping else {
+            return value;
+        };
+
+        let (sign, unsigned) = value
+            .strip_prefix('-')
+            .map_or(("", value.as_str()), |unsigned| ("-", unsigned));
+        let (integer, fraction) = unsigned
+            .split_once(self.decimal_separator)
+            .map_or((unsigned, None), |(integer, fraction)| {
+                (integer, Some(fraction))
+            });
+
+        if integer.is_empty()
+            || !integer.chars().all(|c| c.is_ascii_digit())
+            || !fraction.map_or(true, |fraction| {
+                fraction.chars().all(|c| c.is_ascii_digit())
+            })
+        {
+            return value;
+        }
+
+        let mut grouped = String::with_capacity(value.len() + integer.len() / 3);
+        grouped.push_str(sign);
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            grouped.push(self.decimal_separator);
+            grouped.push_str(fraction);
+        }
+
+        grouped
+    }
+
+    /// Returns the value after stepping once in the given direction, clamped to the range.
+    /// Values that can not be parsed start from zero clamped to the range.
+    #[must_use]
+    pub fn step_value(&self, value: &str, direction: StepDirection) -> f64 {
+        let current = self.parse(value).unwrap_or_else(|| self.clamp(0.0));
+
+        let delta = match direction {
+            StepDirection::Up => self.step,
+            StepDirection::Down => -self.step,
+        };
+
+        self.clamp(current + delta)
+    }
+}
+
+/// Direction of a step on a numeric input.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+pub enum StepDirection {
+    /// Increment the value.
+    Up,
+    /// Decrement the value.
+    Down,
+}
+
+/// Event that is sent to step the value of a numeric input.
+///
+/// Sent by the keyboard and the spinner buttons. Can also be sent by the application.
+#[derive(Event, Debug)]
+pub struct NumberInputStepEvent {
+    /// The numeric input to step.
+    pub entity: Entity,
+    /// The direction to step in.
+    pub direction: StepDirection,
+}
+
+/// A spinner button belonging to a numeric input.
+#[derive(Component, Reflect)]
+pub struct NumberInputSpinner {
+    /// The numeric input the button steps.
+    pub input: Entity,
+    /// The direction the button steps in.
+    pub direction: StepDirection,
+}
+
+/// Auto-repeat timer of a held spinner button.
+#[derive(Component)]
+struct NumberInputSpinnerRepeat(Timer);
+
+#[allow(clippy::needless_pass_by_value)]
+fn create(
+    mut commands: Commands,
+    q_added: Query<(Entity, &NumberInputSettings, &TextInputTextStyle), Added<NumberInputSettings>>,
+) {
+    for (entity, settings, style) in &q_added {
+        if let Some(prefix) = &settings.prefix {
+            let prefix = commands
+                .spawn(TextBundle::from_section(prefix.clone(), style.0.clone()))
+                .id();
+            commands.entity(entity).insert_children(0, &[prefix]);
+        }
+
+        if let Some(suffix) = &settings.suffix {
+            let suffix = commands
+                .spawn(TextBundle::from_section(suffix.clone(), style.0.clone()))
+                .id();
+            commands.entity(entity).add_child(suffix);
+        }
+
+        if !settings.spinner {
+            continue;
+        }
+
+        let spinner = commands
+            .spawn(NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    margin: UiRect::left(Val::Auto),
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|parent| {
+                for (text, direction) in [("+", StepDirection::Up), ("-", StepDirection::Down)] {
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    justify_content: JustifyContent::Center,
+                                    padding: UiRect::horizontal(Val::Px(4.0)),
+                                    ..default()
+                                },
+                                background_color: Color::NONE.into(),
+                                ..default()
+                            },
+                            NumberInputSpinner {
+                                input: entity,
+                                direction,
+                            },
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                text,
+                                TextStyle {
+                                    font_size: style.0.font_size * 0.5,
+                                    ..style.0.clone()
+                                },
+                            ));
+                        });
+                }
+            })
+            .id();
+
+        commands.entity(entity).add_child(spinner);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn keyboard(
+    mut events: EventReader<KeyboardInput>,
+    q_focused: Query<Entity, (With<NumberInputSettings>, With<FormElementFocus>)>,
+    mut ev_step: EventWriter<NumberInputStepEvent>,
+) {
+    for event in events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        let direction = match event.key_code {
+            KeyCode::ArrowUp => StepDirection::Up,
+            KeyCode::ArrowDown => StepDirection::Down,
+            _ => continue,
+        };
+
+        for entity in &q_focused {
+            ev_step.send(NumberInputStepEvent { entity, direction });
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn spinner_interaction(
+    mut commands: Commands,
+    q_spinner: Query<(Entity, &NumberInputSpinner, &Interaction), Changed<Interaction>>,
+    mut ev_step: EventWriter<NumberInputStepEvent>,
+) {
+    for (entity, spinner, interaction) in &q_spinner {
+        if *interaction == Interaction::Pressed {
+            ev_step.send(NumberInputStepEvent {
+                entity: spinner.input,
+                direction: spinner.direction,
+            });
+            commands
+                .entity(entity)
+                .insert(NumberInputSpinnerRepeat(Timer::from_seconds(
+                    SPINNER_REPEAT_DELAY,
+                    TimerMode::Once,
+                )));
+        } else {
+            commands.entity(entity).remove::<NumberInputSpinnerRepeat>();
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn spinner_repeat(
+    mut q_spinner: Query<(
+        &NumberInputSpinner,
+        &Interaction,
+        &mut NumberInputSpinnerRepeat,
+    )>,
+    mut ev_step: EventWriter<NumberInputStepEvent>,
+    time: Res<Time>,
+) {
+    for (spinner, interaction, mut repeat) in &mut q_spinner {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if !repeat.0.tick(time.delta()).just_finished() {
+            continue;
+        }
+
+        for _ in 0..repeat.0.times_finished_this_tick() {
+            ev_step.send(NumberInputStepEvent {
+                entity: spinner.input,
+                direction: spinner.direction,
+            });
+        }
+
+        if repeat.0.mode() == TimerMode::Once {
+            repeat.0 = Timer::from_seconds(SPINNER_REPEAT_INTERVAL, TimerMode::Repeating);
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn group_on_blur(
+    mut focus_removed: RemovedComponents<FormElementFocus>,
+    q_added: Query<Entity, (Added<NumberInputSettings>, Without<FormElementFocus>)>,
+    mut q_number_input: Query<
+        (&NumberInputSettings, &mut TextInputValue),
+        Without<FormElementFocus>,
+    >,
+) {
+    for entity in focus_removed.read().chain(q_added.iter()) {
+        let Ok((settings, mut value)) = q_number_input.get_mut(entity) else {
+            continue;
+        };
+
+        let display = settings.display(&value.0);
+        if display != value.0 {
+            value.0 = display;
+        }
+    }
+}
+
+fn normalize_on_focus(
+    mut q_focused: Query<
+        (
+            &NumberInputSettings,
+            &mut TextInputValue,
+            &mut TextInputCursorPos,
+        ),
+        Added<FormElementFocus>,
+    >,
+) {
+    for (settings, mut value, mut cursor_pos) in &mut q_focused {
+        let normalized = settings.normalize(&value.0);
+        if normalized == value.0 {
+            continue;
+        }
+
+        // Keep the cursor next to the same digit by skipping the removed separators.
+        cursor_pos.0 = settings
+            .normalize(&value.0.chars().take(cursor_pos.0).collect::<String>())
+            .chars()
+            .count();
+        value.0 = normalized;
+    }
+}
+
+fn step(
+    mut events: EventReader<NumberInputStepEvent>,
+    mut q_number_input: Query<
+        (
+            &NumberInputSettings,
+            &mut TextInputValue,
+            Has<FormElementFocus>,
+        ),
+        (Without<FormElementDisabled>, Without<TextInputReadonly>),
+    >,
+) {
+    for event in events.read() {
+        let Ok((settings, mut value, focused)) = q_number_input.get_mut(event.entity) else {
+            continue;
+        };
+
+        let stepped = settings.format(settings.step_value(&value.0, event.direction));
+        value.0 = if focused {
+            stepped
+        } else {
+            settings.group(&stepped)
+        };
+    }
+}
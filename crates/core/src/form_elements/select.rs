@@ -0,0 +1,436 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::prelude::*;
+
+use crate::form::form_keyboard;
+use crate::prelude::{
+    FormElementDisabled, FormElementFocus, FormElementInvalid, FormElementOptional,
+    FormElementValid, FormTheme, FormValidationError,
+};
+
+/// A Bevy `Plugin` providing the systems required to make a [`SelectBundle`] work.
+pub struct SelectPlugin;
+
+impl Plugin for SelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FormTheme>()
+            .add_systems(
+                Update,
+                (
+                    create,
+                    toggle,
+                    option_interaction,
+                    keyboard.before(form_keyboard),
+                    close,
+                    update.after(create),
+                    validate,
+                ),
+            )
+            .register_type::<SelectOptions>()
+            .register_type::<SelectValue>()
+            .register_type::<SelectPlaceholder>()
+            .register_type::<SelectTextStyle>()
+            .register_type::<SelectOption>();
+    }
+}
+
+/// Trait for types that can be chosen in a select, usually a fieldless enum.
+/// Can be derived using `#[derive(FormSelectOptions)]`.
+pub trait FormSelectOptions: Sized {
+    /// The texts of the options in the order they are displayed.
+    fn options() -> Vec<String>;
+
+    /// Returns the option at the given index.
+    fn from_index(index: usize) -> Option<Self>;
+
+    /// Returns the index of the option.
+    fn index(&self) -> usize;
+}
+
+/// A bundle providing the components required for a select.
+///
+/// Add this to a Bevy `NodeBundle`. The select shows the chosen option and opens a list of all
+/// options when clicked. While focused the list can be navigated using the arrow keys, `Enter`
+/// chooses the highlighted option and `Escape` closes the list.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         SelectBundle::new(["Red", "Green", "Blue"]).with_placeholder("Color"),
+///     ));
+/// }
+/// ```
+#[derive(Bundle, Default)]
+pub struct SelectBundle {
+    /// A component containing the options to choose from.
+    pub options: SelectOptions,
+    /// A component containing the index of the chosen option.
+    pub value: SelectValue,
+    /// A component containing the text displayed while no option is chosen.
+    pub placeholder: SelectPlaceholder,
+    /// The style of the option texts.
+    pub text_style: SelectTextStyle,
+    /// This component's value is managed by Bevy's UI systems and enables tracking of hovers and presses.
+    pub interaction: Interaction,
+}
+
+impl SelectBundle {
+    /// Creates a select with the given options.
+    #[must_use]
+    pub fn new(options: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            options: SelectOptions(options.into_iter().map(Into::into).collect()),
+            ..default()
+        }
+    }
+
+    /// Creates a select with the options of `T`.
+    #[must_use]
+    pub fn from_options<T: FormSelectOptions>() -> Self {
+        Self::new(T::options())
+    }
+
+    /// Chooses the option at the given index.
+    #[must_use]
+    pub fn with_value(mut self, index: usize) -> Self {
+        self.value = SelectValue(Some(index));
+        self
+    }
+
+    /// Sets the text displayed while no option is chosen.
+    #[must_use]
+    pub fn with_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = SelectPlaceholder(placeholder.into());
+        self
+    }
+
+    /// Sets the style of the option texts.
+    #[must_use]
+    pub fn with_text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = SelectTextStyle(text_style);
+        self
+    }
+}
+
+/// A component containing the options of a select.
+#[derive(Component, Clone, Default, Debug, Reflect)]
+pub struct SelectOptions(pub Vec<String>);
+
+/// A component containing the index of the chosen option, if any.
+#[derive(Component, Clone, Copy, Default, Debug, Reflect)]
+pub struct SelectValue(pub Option<usize>);
+
+/// A component containing the text displayed while no option is chosen.
+#[derive(Component, Clone, Default, Debug, Reflect)]
+pub struct SelectPlaceholder(pub String);
+
+/// The Bevy `TextStyle` used for the texts of a select.
+#[derive(Component, Clone, Default, Debug, Reflect)]
+pub struct SelectTextStyle(pub TextStyle);
+
+/// An option in the list of a select.
+#[derive(Component, Reflect)]
+pub struct SelectOption {
+    /// The select the option belongs to.
+    pub select: Entity,
+    /// The index of the option.
+    pub index: usize,
+}
+
+/// Entities making up a select and whether its list is open.
+#[derive(Component)]
+struct SelectState {
+    text: Entity,
+    list: Entity,
+    options: Vec<Entity>,
+    open: bool,
+    highlighted: usize,
+}
+
+const HIGHLIGHT_COLOR: Color = Color::rgb(0.85, 0.85, 0.85);
+
+#[allow(clippy::needless_pass_by_value)]
+fn create(
+    mut commands: Commands,
+    theme: Res<FormTheme>,
+    q_added: Query<
+        (
+            Entity,
+            &SelectOptions,
+            &SelectValue,
+            &SelectPlaceholder,
+            &SelectTextStyle,
+        ),
+        Added<SelectOptions>,
+    >,
+) {
+    for (entity, options, value, placeholder, text_style) in &q_added {
+        let text = commands
+            .spawn(TextBundle::from_section(
+                selected_text(options, value, placeholder),
+                text_style.0.clone(),
+            ))
+            .id();
+
+        let arrow = commands
+            .spawn(TextBundle::from_section("v", text_style.0.clone()))
+            .id();
+
+        let option_entities = options
+            .0
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                commands
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                padding: UiRect::horizontal(Val::Px(4.0)),
+                                ..default()
+                            },
+                            background_color: Color::NONE.into(),
+                            ..default()
+                        },
+                        SelectOption {
+                            select: entity,
+                            index,
+                        },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            option.clone(),
+                            text_style.0.clone(),
+                        ));
+                    })
+                    .id()
+            })
+            .collect::<Vec<_>>();
+
+        let list = commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        display: Display::None,
+                        position_type: PositionType::Absolute,
+                        top: Val::Percent(100.0),
+                        left: Val::Px(0.0),
+                        min_width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    },
+                    background_color: theme.popup_background.into(),
+                    z_index: ZIndex::Global(1),
+                    ..default()
+                },
+                Name::new("options"),
+            ))
+            .push_children(&option_entities)
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(SelectState {
+                text,
+                list,
+                options: option_entities,
+                open: false,
+                highlighted: value.0.unwrap_or(0),
+            })
+            .push_children(&[text, arrow, list]);
+    }
+}
+
+fn selected_text(
+    options: &SelectOptions,
+    value: &SelectValue,
+    placeholder: &SelectPlaceholder,
+) -> String {
+    value
+        .0
+        .and_then(|index| options.0.get(index))
+        .unwrap_or(&placeholder.0)
+        .clone()
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn toggle(
+    mut commands: Commands,
+    mut q_select: Query<
+        (Entity, &Interaction, &mut SelectState, &SelectValue),
+        (Changed<Interaction>, Without<FormElementDisabled>),
+    >,
+) {
+    for (entity, interaction, mut state, value) in &mut q_select {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        state.open = !state.open;
+        state.highlighted = value.0.unwrap_or(0);
+        commands.entity(entity).insert(FormElementFocus);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn option_interaction(
+    q_option: Query<(&SelectOption, &Interaction), Changed<Interaction>>,
+    mut q_select: Query<(&mut SelectValue, &mut SelectState), Without<FormElementDisabled>>,
+) {
+    for (option, interaction) in &q_option {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Ok((mut value, mut state)) = q_select.get_mut(option.select) {
+            value.0 = Some(option.index);
+            state.open = false;
+        }
+    }
+}
+
+fn keyboard(
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut q_select: Query<
+        (&SelectOptions, &mut SelectValue, &mut SelectState),
+        (With<FormElementFocus>, Without<FormElementDisabled>),
+    >,
+) {
+    for (options, mut value, mut state) in &mut q_select {
+        let last = options.0.len().saturating_sub(1);
+
+        if keyboard_input.just_pressed(KeyCode::ArrowDown) {
+            if state.open {
+                state.highlighted = (state.highlighted + 1).min(last);
+            } else {
+                state.open = true;
+            }
+        }
+        if keyboard_input.just_pressed(KeyCode::ArrowUp) {
+            if state.open {
+                state.highlighted = state.highlighted.saturating_sub(1);
+            } else {
+                state.open = true;
+            }
+        }
+        if keyboard_input.just_pressed(KeyCode::Space) {
+            state.open = !state.open;
+        }
+
+        if !state.open {
+            continue;
+        }
+
+        // The keys are consumed, so an open list does not submit or cancel the form.
+        if keyboard_input.clear_just_released(KeyCode::Enter) {
+            if !options.0.is_empty() {
+                value.0 = Some(state.highlighted);
+            }
+            state.open = false;
+        }
+        if keyboard_input.clear_just_released(KeyCode::Escape) {
+            state.open = false;
+        }
+    }
+}
+
+/// Closes the list when the select loses focus or the mouse is pressed outside of it.
+#[allow(clippy::needless_pass_by_value)]
+fn close(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    mut focus_removed: RemovedComponents<FormElementFocus>,
+    mut q_select: Query<(Entity, &Interaction, &mut SelectState)>,
+    q_option: Query<(&SelectOption, &Interaction)>,
+) {
+    for entity in focus_removed.read() {
+        if let Ok((_, _, mut state)) = q_select.get_mut(entity) {
+            state.open = false;
+        }
+    }
+
+    if !mouse_input.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    for (entity, interaction, mut state) in &mut q_select {
+        let pressed = *interaction == Interaction::Pressed
+            || q_option.iter().any(|(option, interaction)| {
+                option.select == entity && *interaction == Interaction::Pressed
+            });
+
+        if state.open && !pressed {
+            state.open = false;
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update(
+    q_select: Query<
+        (
+            &SelectOptions,
+            &SelectValue,
+            &SelectPlaceholder,
+            &SelectState,
+        ),
+        Or<(Changed<SelectValue>, Changed<SelectState>)>,
+    >,
+    mut q_text: Query<&mut Text>,
+    mut q_style: Query<&mut Style>,
+    mut q_background: Query<&mut BackgroundColor>,
+) {
+    for (options, value, placeholder, state) in &q_select {
+        if let Ok(mut text) = q_text.get_mut(state.text) {
+            text.sections[0].value = selected_text(options, value, placeholder);
+        }
+
+        if let Ok(mut style) = q_style.get_mut(state.list) {
+            style.display = if state.open {
+                Display::Flex
+            } else {
+                Display::None
+            };
+        }
+
+        for (index, option) in state.options.iter().enumerate() {
+            if let Ok(mut background) = q_background.get_mut(*option) {
+                background.0 = if index == state.highlighted {
+                    HIGHLIGHT_COLOR
+                } else {
+                    Color::NONE
+                };
+            }
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn validate(
+    mut commands: Commands,
+    q_select: Query<
+        (
+            Entity,
+            &SelectValue,
+            Has<FormElementOptional>,
+            Has<FormElementDisabled>,
+        ),
+        Changed<SelectValue>,
+    >,
+) {
+    for (entity, value, optional, disabled) in &q_select {
+        if value.0.is_none() && !optional && !disabled {
+            commands
+                .entity(entity)
+                .insert(FormElementInvalid(FormValidationError::Required(entity)))
+                .remove::<FormElementValid>();
+        } else {
+            commands
+                .entity(entity)
+                .remove::<FormElementInvalid>()
+                .insert(FormElementValid);
+        }
+    }
+}
@@ -0,0 +1,297 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+
+use crate::prelude::{FormElementDisabled, FormElementFocus, FormTheme};
+
+/// A Bevy `Plugin` providing the systems required to make a [`SliderBundle`] work.
+pub struct SliderPlugin;
+
+impl Plugin for SliderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FormTheme>()
+            .add_systems(
+                Update,
+                (
+                    create,
+                    drag,
+                    keyboard,
+                    update.after(create).after(drag).after(keyboard),
+                ),
+            )
+            .register_type::<SliderSettings>()
+            .register_type::<SliderValue>();
+    }
+}
+
+/// A bundle providing the components required for a slider.
+///
+/// Add this to a Bevy `NodeBundle`. The value is changed by dragging the thumb along the track or
+/// with the `ArrowLeft` and `ArrowRight` keys while the slider is focused.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         SliderBundle::new(SliderSettings {
+///             min: 0.0,
+///             max: 1.0,
+///             step: 0.05,
+///         })
+///         .with_value(0.5),
+///     ));
+/// }
+/// ```
+#[derive(Bundle, Default)]
+pub struct SliderBundle {
+    /// A component containing the range of the slider.
+    pub settings: SliderSettings,
+    /// A component containing the current value.
+    pub value: SliderValue,
+    /// The style of the value text.
+    pub text_style: SliderTextStyle,
+}
+
+impl SliderBundle {
+    /// Creates a slider with the given settings. The value starts at the minimum.
+    #[must_use]
+    pub fn new(settings: SliderSettings) -> Self {
+        Self {
+            value: SliderValue(settings.min),
+            settings,
+            ..default()
+        }
+    }
+
+    /// Sets the value. The value is snapped to the step and clamped to the range.
+    #[must_use]
+    pub fn with_value(mut self, value: f64) -> Self {
+        self.value = SliderValue(self.settings.snap(value));
+        self
+    }
+
+    /// Sets the style of the value text.
+    #[must_use]
+    pub fn with_text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = SliderTextStyle(text_style);
+        self
+    }
+}
+
+/// Range of a slider.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub struct SliderSettings {
+    /// The minimum value.
+    pub min: f64,
+    /// The maximum value.
+    pub max: f64,
+    /// The amount the value changes by with each step. Values are snapped to multiples of the step
+    /// starting at `min`.
+    pub step: f64,
+}
+
+impl Default for SliderSettings {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: 1.0,
+            step: 0.1,
+        }
+    }
+}
+
+impl SliderSettings {
+    /// Snaps the value to the step and clamps it to the range.
+    #[must_use]
+    pub fn snap(&self, value: f64) -> f64 {
+        let value = if self.step > 0.0 {
+            self.min + ((value - self.min) / self.step).round() * self.step
+        } else {
+            value
+        };
+        value.clamp(self.min, self.max)
+    }
+
+    /// Returns the position of the value on the track, from `0.0` to `1.0`.
+    #[must_use]
+    pub fn fraction(&self, value: f64) -> f64 {
+        if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Formats the value with as many decimal places as the step has.
+    #[must_use]
+    pub fn format(&self, value: f64) -> String {
+        let decimals = self
+            .step
+            .to_string()
+            .split_once('.')
+            .map_or(0, |(_, fraction)| fraction.len());
+
+        format!("{value:.decimals$}")
+    }
+}
+
+/// A component containing the current value of a slider.
+#[derive(Component, Clone, Copy, Default, PartialEq, Debug, Reflect)]
+pub struct SliderValue(pub f64);
+
+/// The Bevy `TextStyle` used for the value text of a slider.
+#[derive(Component, Clone, Default, Debug, Reflect)]
+pub struct SliderTextStyle(pub TextStyle);
+
+/// Entities making up a slider.
+#[derive(Component)]
+struct SliderInner {
+    thumb: Entity,
+    text: Entity,
+}
+
+/// The track of a slider. Pressing it moves the thumb to the cursor.
+#[derive(Component)]
+struct SliderTrack {
+    slider: Entity,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn create(
+    mut commands: Commands,
+    theme: Res<FormTheme>,
+    q_added: Query<(Entity, &SliderSettings, &SliderValue, &SliderTextStyle), Added<SliderValue>>,
+) {
+    for (entity, settings, value, text_style) in &q_added {
+        let thumb = commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: thumb_position(settings, *value),
+                    width: Val::Px(10.0),
+                    height: Val::Px(16.0),
+                    margin: UiRect::left(Val::Px(-5.0)),
+                    ..default()
+                },
+                background_color: theme.accent_color.into(),
+                ..default()
+            })
+            .id();
+
+        let track = commands
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        flex_grow: 1.0,
+                        height: Val::Px(16.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    background_color: Color::NONE.into(),
+                    ..default()
+                },
+                RelativeCursorPosition::default(),
+                SliderTrack { slider: entity },
+                Name::new("track"),
+            ))
+            .with_children(|parent| {
+                parent.spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(4.0),
+                        ..default()
+                    },
+                    background_color: theme.border_color.normal.into(),
+                    ..default()
+                });
+            })
+            .add_child(thumb)
+            .id();
+
+        let text = commands
+            .spawn(TextBundle::from_section(
+                settings.format(value.0),
+                text_style.0.clone(),
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(SliderInner { thumb, text })
+            .push_children(&[track, text]);
+    }
+}
+
+fn thumb_position(settings: &SliderSettings, value: SliderValue) -> Val {
+    #[allow(clippy::cast_possible_truncation)]
+    Val::Percent((settings.fraction(value.0) * 100.0) as f32)
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn drag(
+    mut commands: Commands,
+    q_track: Query<(&SliderTrack, &Interaction, &RelativeCursorPosition)>,
+    mut q_slider: Query<(&SliderSettings, &mut SliderValue), Without<FormElementDisabled>>,
+) {
+    for (track, interaction, cursor) in &q_track {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Some(position) = cursor.normalized else {
+            continue;
+        };
+
+        let Ok((settings, mut value)) = q_slider.get_mut(track.slider) else {
+            continue;
+        };
+
+        let snapped =
+            settings.snap(settings.min + f64::from(position.x) * (settings.max - settings.min));
+        // Only trigger change detection when the value actually changed.
+        value.set_if_neq(SliderValue(snapped));
+        commands.entity(track.slider).insert(FormElementFocus);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn keyboard(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut q_slider: Query<
+        (&SliderSettings, &mut SliderValue),
+        (With<FormElementFocus>, Without<FormElementDisabled>),
+    >,
+) {
+    let steps = match (
+        keyboard_input.just_pressed(KeyCode::ArrowRight),
+        keyboard_input.just_pressed(KeyCode::ArrowLeft),
+    ) {
+        (true, false) => 1.0,
+        (false, true) => -1.0,
+        _ => return,
+    };
+
+    for (settings, mut value) in &mut q_slider {
+        value.0 = settings.snap(value.0 + steps * settings.step);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update(
+    q_slider: Query<(&SliderSettings, &SliderValue, &SliderInner), Changed<SliderValue>>,
+    mut q_style: Query<&mut Style>,
+    mut q_text: Query<&mut Text>,
+) {
+    for (settings, value, inner) in &q_slider {
+        if let Ok(mut style) = q_style.get_mut(inner.thumb) {
+            style.left = thumb_position(settings, *value);
+        }
+
+        if let Ok(mut text) = q_text.get_mut(inner.text) {
+            text.sections[0].value = settings.format(value.0);
+        }
+    }
+}
@@ -0,0 +1,254 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::{input::keyboard::KeyboardInput, prelude::*};
+
+use crate::form::form_keyboard;
+use crate::form_elements::text_input;
+use crate::prelude::{
+    FormElementDisabled, FormElementFocus, FormElementInvalid, FormElementOptional,
+    FormElementValid, FormValidationError, TextInputReadonly, TextInputSubmitEvent,
+    TextInputTextStyle, TextInputValue,
+};
+
+/// A Bevy `Plugin` providing the systems required to make a [`TagInputValue`] work.
+pub struct TagInputPlugin;
+
+impl Plugin for TagInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                create,
+                add_tag
+                    .after(text_input::keyboard)
+                    .before(text_input::update_value)
+                    .before(form_keyboard),
+                remove_last.before(text_input::keyboard),
+                remove_interaction,
+                update.after(create).after(add_tag),
+                validate.after(text_input::validate),
+            ),
+        )
+        .register_type::<TagInputValue>();
+    }
+}
+
+/// The tags of a tag input.
+///
+/// Add this next to a [`TextInputBundle`](crate::prelude::TextInputBundle) to turn it into a tag
+/// input. Pressing `Enter` adds the typed text as a tag, which is shown as a removable chip in front
+/// of the input. `Backspace` on an empty input removes the last tag.
+/// Forms containing the input are submitted when `Enter` is pressed on an empty input.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default().with_placeholder("Add tag", None),
+///         TagInputValue(vec!["bevy".to_string()]),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Default, Debug, Reflect)]
+pub struct TagInputValue(pub Vec<String>);
+
+/// The container of the chips and whether the release of `Enter` needs to be consumed.
+#[derive(Component)]
+struct TagInputInner {
+    chips: Entity,
+    consume_enter: bool,
+}
+
+/// The button removing a tag.
+#[derive(Component)]
+struct TagInputRemove {
+    input: Entity,
+    index: usize,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn create(mut commands: Commands, q_added: Query<Entity, Added<TagInputValue>>) {
+    for entity in &q_added {
+        let chips = commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_wrap: FlexWrap::Wrap,
+                        column_gap: Val::Px(4.0),
+                        row_gap: Val::Px(2.0),
+                        margin: UiRect::right(Val::Px(4.0)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("tags"),
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(TagInputInner {
+                chips,
+                consume_enter: false,
+            })
+            .insert_children(0, &[chips]);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_tag(
+    mut events: EventReader<TextInputSubmitEvent>,
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut q_tag_input: Query<
+        (&mut TagInputValue, &mut TextInputValue, &mut TagInputInner),
+        (Without<FormElementDisabled>, Without<TextInputReadonly>),
+    >,
+) {
+    for event in events.read() {
+        let Ok((mut tags, mut text_input, mut inner)) = q_tag_input.get_mut(event.entity) else {
+            continue;
+        };
+
+        let tag = event.value.trim();
+        if tag.is_empty() {
+            continue;
+        }
+
+        if !tags.0.iter().any(|existing| existing == tag) {
+            tags.0.push(tag.to_string());
+        }
+        text_input.0.clear();
+        inner.consume_enter = true;
+    }
+
+    // The key is consumed, so adding a tag does not submit the form.
+    for (_, _, mut inner) in &mut q_tag_input {
+        if inner.consume_enter && keyboard_input.clear_just_released(KeyCode::Enter) {
+            inner.consume_enter = false;
+        }
+    }
+}
+
+fn remove_last(
+    mut events: EventReader<KeyboardInput>,
+    mut q_tag_input: Query<
+        (&mut TagInputValue, &TextInputValue),
+        (
+            With<FormElementFocus>,
+            Without<FormElementDisabled>,
+            Without<TextInputReadonly>,
+        ),
+    >,
+) {
+    for event in events.read() {
+        if !event.state.is_pressed() || event.key_code != KeyCode::Backspace {
+            continue;
+        }
+
+        for (mut tags, text_input) in &mut q_tag_input {
+            if text_input.0.is_empty() {
+                tags.0.pop();
+            }
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn remove_interaction(
+    q_remove: Query<(&TagInputRemove, &Interaction), Changed<Interaction>>,
+    mut q_tag_input: Query<
+        &mut TagInputValue,
+        (Without<FormElementDisabled>, Without<TextInputReadonly>),
+    >,
+) {
+    for (remove, interaction) in &q_remove {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let Ok(mut tags) = q_tag_input.get_mut(remove.input) {
+            if remove.index < tags.0.len() {
+                tags.0.remove(remove.index);
+            }
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update(
+    mut commands: Commands,
+    q_tag_input: Query<
+        (Entity, &TagInputValue, &TextInputTextStyle, &TagInputInner),
+        Changed<TagInputValue>,
+    >,
+) {
+    for (entity, tags, text_style, inner) in &q_tag_input {
+        commands.entity(inner.chips).despawn_descendants();
+
+        for (index, tag) in tags.0.iter().enumerate() {
+            let chip = commands
+                .spawn(NodeBundle {
+                    style: Style {
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(2.0),
+                        padding: UiRect::horizontal(Val::Px(4.0)),
+                        ..default()
+                    },
+                    background_color: Color::rgb(0.85, 0.85, 0.85).into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(tag.clone(), text_style.0.clone()));
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                background_color: Color::NONE.into(),
+                                ..default()
+                            },
+                            TagInputRemove {
+                                input: entity,
+                                index,
+                            },
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section("x", text_style.0.clone()));
+                        });
+                })
+                .id();
+
+            commands.entity(inner.chips).add_child(chip);
+        }
+    }
+}
+
+/// A required tag input is valid once it has a tag, regardless of the typed text.
+#[allow(clippy::needless_pass_by_value)]
+fn validate(
+    mut commands: Commands,
+    q_tag_input: Query<
+        (
+            Entity,
+            &TagInputValue,
+            Has<FormElementOptional>,
+            Has<FormElementDisabled>,
+        ),
+        Or<(Changed<TagInputValue>, Changed<TextInputValue>)>,
+    >,
+) {
+    for (entity, tags, optional, disabled) in &q_tag_input {
+        if tags.0.is_empty() && !optional && !disabled {
+            commands
+                .entity(entity)
+                .insert(FormElementInvalid(FormValidationError::Required(entity)))
+                .remove::<FormElementValid>();
+        } else {
+            commands
+                .entity(entity)
+                .remove::<FormElementInvalid>()
+                .insert(FormElementValid);
+        }
+    }
+}
@@ -0,0 +1,225 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::{input::keyboard::KeyboardInput, prelude::*};
+
+use crate::form::form_keyboard;
+use crate::form_elements::text_input::{self, TextInputInner};
+use crate::prelude::{
+    FormElementDisabled, FormElementFocus, TextInputCursorPos, TextInputCursorTimer,
+    TextInputTextStyle, TextInputValue,
+};
+
+/// A Bevy `Plugin` providing the systems required to make a [`TextAreaSettings`] work.
+pub struct TextAreaPlugin;
+
+impl Plugin for TextAreaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                consume_enter.before(form_keyboard),
+                keyboard.before(text_input::update_value),
+                scroll.after(text_input::update_value),
+            ),
+        )
+        .register_type::<TextAreaSettings>();
+    }
+}
+
+/// Settings for a multi-line text input.
+///
+/// Add this next to a [`TextInputBundle`](crate::prelude::TextInputBundle) to turn it into a text
+/// area. `Enter` inserts a new line instead of submitting, the cursor can be moved between lines
+/// using the `ArrowUp` and `ArrowDown` keys and long lines are wrapped at word boundaries. `Home` and
+/// `End` move to the start or end of the line, and to the start or end of the text with `Ctrl`.
+/// Forms containing a focused text area are submitted using `Ctrl` + `Enter`.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default(),
+///         TextAreaSettings {
+///             rows: 5,
+///             max_lines: Some(10),
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct TextAreaSettings {
+    /// The number of visible lines. The text area scrolls to keep the cursor visible.
+    pub rows: usize,
+    /// The maximum number of lines that can be entered.
+    pub max_lines: Option<usize>,
+}
+
+impl Default for TextAreaSettings {
+    fn default() -> Self {
+        Self {
+            rows: 3,
+            max_lines: None,
+        }
+    }
+}
+
+impl TextAreaSettings {
+    /// Returns true if another line can be added to the value.
+    #[must_use]
+    pub fn accepts_line(&self, value: &str) -> bool {
+        self.max_lines
+            .map_or(true, |max_lines| value.split('\n').count() < max_lines)
+    }
+}
+
+/// Index of the first visible line of a text area.
+#[derive(Component, Default)]
+struct TextAreaScroll(usize);
+
+/// Returns the height of a line of text using the given style.
+pub(crate) fn line_height(style: &TextStyle) -> f32 {
+    style.font_size * 1.2
+}
+
+/// Returns the line and column of the character position.
+fn line_col(value: &str, pos: usize) -> (usize, usize) {
+    let before = value.chars().take(pos).collect::<String>();
+    let line = before.matches('\n').count();
+    let col = before
+        .rsplit_once('\n')
+        .map_or(before.chars().count(), |(_, last)| last.chars().count());
+
+    (line, col)
+}
+
+/// Returns the character position of the column in the line, clamped to the length of the line.
+fn line_pos(value: &str, line: usize, col: usize) -> Option<usize> {
+    let mut start = 0;
+    for (i, text) in value.split('\n').enumerate() {
+        let len = text.chars().count();
+        if i == line {
+            return Some(start + col.min(len));
+        }
+        start += len + 1;
+    }
+
+    None
+}
+
+/// Keeps the `Enter` key from submitting the form while a text area is focused, unless `Ctrl` is
+/// held.
+#[allow(clippy::needless_pass_by_value)]
+fn consume_enter(
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    q_focused: Query<
+        (),
+        (
+            With<TextAreaSettings>,
+            With<FormElementFocus>,
+            Without<FormElementDisabled>,
+        ),
+    >,
+) {
+    if q_focused.is_empty()
+        || keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+    {
+        return;
+    }
+
+    keyboard_input.clear_just_released(KeyCode::Enter);
+}
+
+fn keyboard(
+    mut events: EventReader<KeyboardInput>,
+    mut q_text_area: Query<
+        (
+            &TextInputValue,
+            &mut TextInputCursorPos,
+            &mut TextInputCursorTimer,
+        ),
+        (
+            With<TextAreaSettings>,
+            With<FormElementFocus>,
+            Without<FormElementDisabled>,
+        ),
+    >,
+) {
+    for event in events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        let up = match event.key_code {
+            KeyCode::ArrowUp => true,
+            KeyCode::ArrowDown => false,
+            _ => continue,
+        };
+
+        for (value, mut cursor_pos, mut cursor_timer) in &mut q_text_area {
+            let (line, col) = line_col(&value.0, cursor_pos.0);
+            let target = if up {
+                line.checked_sub(1)
+            } else {
+                Some(line + 1)
+            };
+
+            if let Some(pos) = target.and_then(|line| line_pos(&value.0, line, col)) {
+                cursor_pos.0 = pos;
+                cursor_timer.reset();
+            }
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn scroll(
+    mut commands: Commands,
+    q_text_area: Query<
+        (
+            Entity,
+            &TextAreaSettings,
+            &TextInputValue,
+            &TextInputCursorPos,
+            &TextInputTextStyle,
+            Option<&TextAreaScroll>,
+        ),
+        Or<(Changed<TextInputCursorPos>, Changed<TextInputValue>)>,
+    >,
+    q_children: Query<&Children>,
+    mut q_inner: Query<&mut Style, With<TextInputInner>>,
+) {
+    for (entity, settings, value, cursor_pos, text_style, scroll) in &q_text_area {
+        let (line, _) = line_col(&value.0, cursor_pos.0);
+        let rows = settings.rows.max(1);
+        let first = scroll.map_or(0, |scroll| scroll.0);
+        let first = if line < first {
+            line
+        } else if line >= first + rows {
+            line + 1 - rows
+        } else {
+            first
+        };
+
+        if scroll.map(|scroll| scroll.0) == Some(first) {
+            continue;
+        }
+
+        let Some(inner) = q_children
+            .iter_descendants(entity)
+            .find(|child| q_inner.contains(*child))
+        else {
+            continue;
+        };
+        let Ok(mut style) = q_inner.get_mut(inner) else {
+            continue;
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let offset = first as f32 * line_height(&text_style.0);
+        style.top = Val::Px(-offset);
+        commands.entity(entity).insert(TextAreaScroll(first));
+    }
+}
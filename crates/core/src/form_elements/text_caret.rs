@@ -0,0 +1,238 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::{prelude::*, text::TextLayoutInfo};
+
+use crate::form_element::is_drawn;
+use crate::form_elements::text_area::{self, TextAreaSettings};
+use crate::form_elements::text_input::{self, TextInputInner, UiWindow};
+use crate::prelude::{TextInputActive, TextInputCursorTimer, TextInputTextStyle};
+
+/// A Bevy `Plugin` providing the systems required to draw the caret of text inputs.
+pub struct TextCaretPlugin;
+
+impl Plugin for TextCaretPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            place_caret
+                .run_if(is_drawn)
+                .after(text_input::place_cursor)
+                .after(text_input::keyboard)
+                .before(text_input::update_value),
+        )
+        .register_type::<TextInputCaretStyle>()
+        .register_type::<CaretShape>();
+    }
+}
+
+/// The look of the caret of a text input.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default().with_caret_style(TextInputCaretStyle {
+///             shape: CaretShape::Block,
+///             blink_interval: None,
+///             ..default()
+///         }),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct TextInputCaretStyle {
+    /// The color of the caret. If not set, the text color is used. Blocks are drawn behind the
+    /// text, so they use the text color with half opacity.
+    pub color: Option<Color>,
+    /// The width of a bar caret in logical pixels. Default is `2.0`.
+    pub width: f32,
+    /// The shape of the caret.
+    pub shape: CaretShape,
+    /// The time in seconds the caret is shown and hidden while blinking. `None` disables blinking.
+    /// Default is `0.5`.
+    pub blink_interval: Option<f32>,
+}
+
+impl Default for TextInputCaretStyle {
+    fn default() -> Self {
+        Self {
+            color: None,
+            width: 2.0,
+            shape: CaretShape::Bar,
+            blink_interval: Some(0.5),
+        }
+    }
+}
+
+impl TextInputCaretStyle {
+    fn color(&self, text_style: &TextStyle) -> Color {
+        self.color.unwrap_or_else(|| match self.shape {
+            CaretShape::Bar => text_style.color,
+            CaretShape::Block => text_style.color.with_a(text_style.color.a() * 0.5),
+        })
+    }
+}
+
+/// The shape of the caret of a text input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum CaretShape {
+    /// A vertical line between two characters.
+    #[default]
+    Bar,
+    /// A box covering the character after the caret.
+    Block,
+}
+
+/// The node drawing the caret of a text input.
+#[derive(Component)]
+struct TextInputCaret;
+
+/// Places a node at the position of the cursor glyph of each text input.
+#[allow(
+    clippy::needless_pass_by_value,
+    clippy::type_complexity,
+    clippy::too_many_arguments
+)]
+fn place_caret(
+    mut commands: Commands,
+    window: UiWindow,
+    q_input: Query<(
+        Entity,
+        &TextInputCaretStyle,
+        &TextInputCursorTimer,
+        &TextInputActive,
+        &TextInputTextStyle,
+        Has<TextAreaSettings>,
+    )>,
+    q_children: Query<&Children>,
+    q_text: Query<(&TextLayoutInfo, &Node, &GlobalTransform, &Parent), With<TextInputInner>>,
+    q_container: Query<(&Node, &GlobalTransform)>,
+    mut q_caret: Query<(&mut Style, &mut BackgroundColor, &mut Visibility), With<TextInputCaret>>,
+) {
+    let scale_factor = window.scale_factor();
+
+    for (entity, caret_style, cursor_timer, active, text_style, text_area) in &q_input {
+        let Some((layout, node, transform, container)) = q_children
+            .iter_descendants(entity)
+            .find_map(|child| q_text.get(child).ok())
+        else {
+            continue;
+        };
+        let Ok((container_node, container_transform)) = q_container.get(container.get()) else {
+            continue;
+        };
+
+        let visibility = if active.0 && cursor_timer.is_visible() {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        let Some(rect) = caret_rect(
+            caret_style,
+            text_style,
+            text_area,
+            layout,
+            node,
+            scale_factor,
+        ) else {
+            continue;
+        };
+
+        // The position of the text inside of the container the caret is placed in.
+        let offset = (transform.translation() - container_transform.translation()).truncate()
+            - (node.size() - container_node.size()) / 2.0;
+        let caret_style_node = Style {
+            position_type: PositionType::Absolute,
+            left: Val::Px(offset.x + rect.min.x),
+            top: Val::Px(offset.y + rect.min.y),
+            width: Val::Px(rect.width()),
+            height: Val::Px(rect.height()),
+            ..default()
+        };
+        let color = caret_style.color(&text_style.0);
+
+        let caret = q_children.get(container.get()).ok().and_then(|children| {
+            children
+                .iter()
+                .copied()
+                .find(|child| q_caret.contains(*child))
+        });
+        if let Some((mut current, mut background, mut current_visibility)) =
+            caret.and_then(|caret| q_caret.get_mut(caret).ok())
+        {
+            if *current != caret_style_node {
+                *current = caret_style_node;
+            }
+            if background.0 != color {
+                background.0 = color;
+            }
+            if *current_visibility != visibility {
+                *current_visibility = visibility;
+            }
+            continue;
+        }
+
+        let caret = commands
+            .spawn((
+                NodeBundle {
+                    style: caret_style_node,
+                    background_color: color.into(),
+                    visibility,
+                    ..default()
+                },
+                TextInputCaret,
+            ))
+            .id();
+        // The caret is placed in front of the text, so it is drawn behind it.
+        commands
+            .entity(container.get())
+            .insert_children(0, &[caret]);
+    }
+}
+
+/// Returns the area of the caret relative to the top left corner of the text, in logical pixels.
+#[allow(clippy::float_cmp)] // Lines are whole numbers.
+fn caret_rect(
+    caret_style: &TextInputCaretStyle,
+    text_style: &TextInputTextStyle,
+    text_area: bool,
+    layout: &TextLayoutInfo,
+    node: &Node,
+    scale_factor: f32,
+) -> Option<Rect> {
+    let mut glyphs = layout
+        .glyphs
+        .iter()
+        .skip_while(|glyph| glyph.section_index != 1);
+    let cursor = glyphs.next()?;
+
+    let line_height = text_area::line_height(&text_style.0);
+    let glyph_line_height = if text_area {
+        line_height * scale_factor
+    } else {
+        f32::INFINITY
+    };
+    let line = text_input::line_of(cursor.position.y, glyph_line_height);
+    let (top, height) = if text_area {
+        (line * line_height, line_height)
+    } else {
+        (0.0, node.size().y)
+    };
+
+    let x = cursor.position.x / scale_factor;
+    let (left, width) = match caret_style.shape {
+        CaretShape::Bar => (x - caret_style.width / 2.0, caret_style.width),
+        CaretShape::Block => glyphs
+            .next()
+            .filter(|next| text_input::line_of(next.position.y, glyph_line_height) == line)
+            .map_or((x, text_style.0.font_size / 2.0), |next| {
+                let width = next.size.x / scale_factor;
+                (next.position.x / scale_factor - width / 2.0, width)
+            }),
+    };
+
+    Some(Rect::new(left, top, left + width, top + height))
+}
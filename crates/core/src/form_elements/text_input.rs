@@ -1,19 +1,37 @@
 #![allow(clippy::module_name_repetitions)]
+use std::ops::Range;
+use std::time::Duration;
+
 use bevy::{
     asset::load_internal_binary_asset,
-    ecs::system::SystemParam,
+    ecs::system::{Command, SystemParam},
     input::keyboard::{Key, KeyboardInput},
     prelude::*,
-    text::BreakLineOn,
+    text::{BreakLineOn, PositionedGlyph, TextLayoutInfo},
+    window::PrimaryWindow,
 };
+use unicode_segmentation::UnicodeSegmentation;
 
-#[cfg(feature = "clipboard")]
-use crate::clipboard::ClipboardEvent;
 #[cfg(feature = "clipboard")]
 use crate::clipboard::ClipboardPlugin;
+#[cfg(feature = "clipboard")]
+use crate::clipboard::{ClipboardEvent, SystemClipboard};
+use crate::form_element::{is_drawn, HeadlessForms};
+use crate::form_elements::clear_button::ClearButtonPlugin;
+#[cfg(feature = "date")]
+use crate::form_elements::date_input::DateInputPlugin;
+#[cfg(feature = "date")]
+use crate::form_elements::date_picker::DatePickerPlugin;
+use crate::form_elements::floating_label::{FloatingLabel, FloatingLabelPlugin};
+use crate::form_elements::format_mask::{FormatMaskPlugin, TextInputFormatMask};
+use crate::form_elements::text_area::{self, TextAreaSettings};
+use crate::form_elements::text_caret::{TextCaretPlugin, TextInputCaretStyle};
+use crate::form_elements::text_selection::{TextInputSelection, TextSelectionPlugin};
+use crate::form_elements::text_transform::{TextInputTransform, TextTransformPlugin};
+use crate::form_elements::text_undo::TextUndoPlugin;
 use crate::prelude::{
-    FormElementFocus, FormElementInvalid, FormElementOptional, FormElementValid,
-    FormValidationError,
+    FormElementDisabled, FormElementFocus, FormElementInvalid, FormElementOptional,
+    FormElementValid, FormTheme, FormThemed, FormThemes, FormValidationError, NumberInputSettings,
 };
 
 /// A Bevy `Plugin` providing the systems and assets required to make a [`TextInputBundle`] work.
@@ -32,7 +50,23 @@ impl Plugin for TextInputPlugin {
         #[cfg(feature = "clipboard")]
         app.add_plugins(ClipboardPlugin);
 
-        app.add_event::<TextInputSubmitEvent>()
+        #[cfg(feature = "date")]
+        app.add_plugins((DateInputPlugin, DatePickerPlugin));
+
+        app.add_plugins((
+            TextSelectionPlugin,
+            TextUndoPlugin,
+            FormatMaskPlugin,
+            TextTransformPlugin,
+            TextCaretPlugin,
+            FloatingLabelPlugin,
+            ClearButtonPlugin,
+        ));
+
+        app.init_resource::<FormTheme>()
+            .add_event::<TextInputSubmitEvent>()
+            .add_event::<TextInputMaxLengthEvent>()
+            .add_event::<TextInputChanged>()
             .add_systems(
                 Update,
                 (
@@ -44,21 +78,34 @@ impl Plugin for TextInputPlugin {
                     update_value.after(keyboard).after(clipboard),
                     #[cfg(not(feature = "clipboard"))]
                     update_value.after(keyboard),
+                    scroll.run_if(is_drawn).after(update_value),
+                    send_changed.after(update_value),
+                    send_debounced_changed.after(send_changed),
+                    history_record.after(keyboard),
+                    history_recall.before(update_value),
                     validate.after(create).after(update_value),
                     focus_interaction,
+                    place_cursor.after(focus_interaction).before(update_value),
                     focus_added.after(focus_interaction),
+                    focus_removed,
                     blink_cursor,
                     show_hide_cursor.after(focus_added),
                     update_style,
-                    set_placeholder.after(create),
+                    focus_ring,
+                    set_placeholder.run_if(is_drawn).after(create),
                 ),
             )
             .register_type::<TextInputSettings>()
+            .register_type::<TextInputAlignment>()
             .register_type::<TextInputTextStyle>()
             .register_type::<TextInputActive>()
+            .register_type::<TextInputReadonly>()
+            .register_type::<DebouncedChange>()
             .register_type::<TextInputCursorTimer>()
             .register_type::<TextInputInner>()
             .register_type::<TextInputValue>()
+            .register_type::<TextInputFilter>()
+            .register_type::<TextInputHistory>()
             .register_type::<TextInputPlaceholder>();
     }
 }
@@ -88,6 +135,8 @@ pub struct TextInputBundle {
     pub active: TextInputActive,
     /// A component that manages the cursor's blinking.
     pub cursor_timer: TextInputCursorTimer,
+    /// A component containing the color, shape and blinking of the cursor.
+    pub caret_style: TextInputCaretStyle,
     /// A component containing the current text cursor position.
     pub cursor_pos: TextInputCursorPos,
     /// A component containing the current value of the text input.
@@ -140,6 +189,13 @@ impl TextInputBundle {
         self
     }
 
+    /// Returns this [`TextInputBundle`] with a new [`TextInputCaretStyle`].
+    #[must_use]
+    pub fn with_caret_style(mut self, caret_style: TextInputCaretStyle) -> Self {
+        self.caret_style = caret_style;
+        self
+    }
+
     /// Returns this [`TextInputBundle`] with a new [`TextInputSettings`].
     #[must_use]
     pub fn with_settings(mut self, settings: TextInputSettings) -> Self {
@@ -156,12 +212,57 @@ pub struct TextInputTextStyle(pub TextStyle);
 #[derive(Component, Default, Reflect)]
 pub struct TextInputActive(pub bool);
 
+/// Marker component for text inputs whose value can be focused, selected and copied, but not
+/// edited. Use [`FormElementDisabled`] to block focusing the input as well.
+#[derive(Component, Reflect)]
+pub struct TextInputReadonly;
+
+/// Delays the [`TextInputChanged`] event of a text input until the value did not change for the
+/// duration, e.g. for search fields running expensive queries. The event then carries the value
+/// of the previous event as `old`.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default().with_placeholder("Search", None),
+///         DebouncedChange::from_millis(300),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct DebouncedChange {
+    /// The time the value has to stay unchanged before the event is sent.
+    pub delay: Duration,
+    timer: Option<Timer>,
+}
+
+impl DebouncedChange {
+    /// Creates a debounce with the delay.
+    #[must_use]
+    pub fn new(delay: Duration) -> Self {
+        Self { delay, timer: None }
+    }
+
+    /// Creates a debounce with the delay in milliseconds.
+    #[must_use]
+    pub fn from_millis(millis: u64) -> Self {
+        Self::new(Duration::from_millis(millis))
+    }
+}
+
 /// A component that manages the cursor's blinking.
 #[derive(Component, Reflect)]
 pub struct TextInputCursorTimer {
     /// The timer that blinks the cursor on and off, and resets when the user types.
+    /// Its duration is set from [`TextInputCaretStyle::blink_interval`].
     pub timer: Timer,
     should_reset: bool,
+    visible: bool,
 }
 
 impl Default for TextInputCursorTimer {
@@ -169,23 +270,269 @@ impl Default for TextInputCursorTimer {
         Self {
             timer: Timer::from_seconds(0.5, TimerMode::Repeating),
             should_reset: false,
+            visible: true,
         }
     }
 }
 
+impl TextInputCursorTimer {
+    /// Shows the cursor and restarts the blinking, e.g. after the cursor was moved.
+    pub(crate) fn reset(&mut self) {
+        self.should_reset = true;
+    }
+
+    /// Returns true if the cursor is in the visible phase of the blinking.
+    #[must_use]
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+}
+
 /// A component containing the text input's settings.
-#[derive(Component, Default, Reflect)]
+#[derive(Component, Reflect)]
 pub struct TextInputSettings {
     /// If true, text is not cleared after pressing enter.
+    /// Inputs of forms generated by the `form_struct` macro retain their value and are cleared by
+    /// the form once it was submitted, depending on the `retain` option of the field.
     pub retain_on_submit: bool,
     /// Mask text with the provided character.
     pub mask_character: Option<char>,
+    /// The number of edits that can be undone. `0` disables undo. Default is `100`.
+    pub undo_depth: usize,
+    /// The maximum number of characters. Typed and pasted text exceeding it is dropped.
+    pub max_length: Option<usize>,
+    /// The horizontal alignment of the text and placeholder. Text areas are always left aligned.
+    pub alignment: TextInputAlignment,
+    /// If true, a button emptying the input is rendered at its right edge.
+    pub clearable: bool,
+    /// If true, the border color of the input follows the `border_color` of the [`FormTheme`],
+    /// highlighting it while it is active. Inputs marked with [`FormThemed`] are always styled by
+    /// the theme.
+    pub focus_ring: bool,
+}
+
+impl Default for TextInputSettings {
+    fn default() -> Self {
+        Self {
+            retain_on_submit: false,
+            mask_character: None,
+            undo_depth: 100,
+            max_length: None,
+            alignment: TextInputAlignment::Left,
+            clearable: false,
+            focus_ring: false,
+        }
+    }
+}
+
+/// The horizontal alignment of the text of a text input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum TextInputAlignment {
+    /// The text starts at the left edge of the input.
+    #[default]
+    Left,
+    /// The text is centered in the input.
+    Center,
+    /// The text ends at the right edge of the input, e.g. for numbers.
+    Right,
+}
+
+impl TextInputAlignment {
+    /// The share of the free space in front of the text.
+    fn factor(self) -> f32 {
+        match self {
+            TextInputAlignment::Left => 0.0,
+            TextInputAlignment::Center => 0.5,
+            TextInputAlignment::Right => 1.0,
+        }
+    }
+
+    fn justify_content(self) -> JustifyContent {
+        match self {
+            TextInputAlignment::Left => JustifyContent::FlexStart,
+            TextInputAlignment::Center => JustifyContent::Center,
+            TextInputAlignment::Right => JustifyContent::FlexEnd,
+        }
+    }
 }
 
 /// A component containing the current value of the text input.
-#[derive(Component, Default, Reflect)]
+#[derive(Component, Clone, Default, Reflect)]
 pub struct TextInputValue(pub String);
 
+/// Restricts which characters can be typed or pasted into the text input.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default(),
+///         TextInputFilter::Chars("0123456789abcdef".to_string()),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+pub enum TextInputFilter {
+    /// Only digits can be typed.
+    Digits,
+    /// Digits can be typed.
+    Integer {
+        /// If true, a leading minus sign can be typed.
+        negative: bool,
+    },
+    /// Digits and a single decimal separator can be typed.
+    Decimal {
+        /// The character separating the integer and fractional part.
+        separator: char,
+        /// If true, a leading minus sign can be typed.
+        negative: bool,
+    },
+    /// Only letters can be typed.
+    Alphabetic,
+    /// Only letters and digits can be typed.
+    Alphanumeric,
+    /// Letters, digits and underscores can be typed, but no leading digit.
+    Identifier,
+    /// Only the given characters can be typed.
+    Chars(String),
+    /// Each character needs to match the regular expression, e.g. `[a-z]`. Requires the `regex`
+    /// feature.
+    #[cfg(feature = "regex")]
+    Regex(#[reflect(ignore, default = "reject_all_regex")] regex::Regex),
+    /// The function is called with each character and returns true if it can be typed.
+    Custom(#[reflect(ignore, default = "reject_all_fn")] fn(char) -> bool),
+}
+
+/// The regular expression of a [`TextInputFilter::Regex`] created through reflection, which can not
+/// be reflected and rejects everything.
+#[cfg(feature = "regex")]
+fn reject_all_regex() -> regex::Regex {
+    regex::Regex::new(r"[^\s\S]").expect("the regular expression is valid")
+}
+
+/// The function of a [`TextInputFilter::Custom`] created through reflection, which can not be
+/// reflected and rejects everything.
+fn reject_all_fn() -> fn(char) -> bool {
+    |_| false
+}
+
+impl TextInputFilter {
+    /// Returns true if the character can be inserted into the value at the given position.
+    #[must_use]
+    pub fn accepts(&self, value: &str, pos: usize, c: char) -> bool {
+        match self {
+            TextInputFilter::Digits => c.is_ascii_digit(),
+            TextInputFilter::Integer { negative } => {
+                c.is_ascii_digit() || (*negative && c == '-' && pos == 0 && !value.starts_with('-'))
+            }
+            TextInputFilter::Decimal {
+                separator,
+                negative,
+            } => {
+                c.is_ascii_digit()
+                    || (c == *separator && !value.contains(*separator))
+                    || (*negative && c == '-' && pos == 0 && !value.starts_with('-'))
+            }
+            TextInputFilter::Alphabetic => c.is_alphabetic(),
+            TextInputFilter::Alphanumeric => c.is_alphanumeric(),
+            TextInputFilter::Identifier => {
+                c == '_' || c.is_alphabetic() || (c.is_numeric() && pos > 0)
+            }
+            TextInputFilter::Chars(chars) => chars.contains(c),
+            #[cfg(feature = "regex")]
+            TextInputFilter::Regex(regex) => regex.is_match(c.encode_utf8(&mut [0; 4])),
+            TextInputFilter::Custom(accepts) => accepts(c),
+        }
+    }
+}
+
+/// Stores the submitted values of a text input, so they can be recalled with the `ArrowUp` and
+/// `ArrowDown` keys while the input is focused.
+///
+/// Inputs with [`NumberInputSettings`] use the arrow keys for stepping and ignore the history.
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct TextInputHistory {
+    /// The maximum number of stored values. The oldest values are dropped first.
+    pub capacity: usize,
+    entries: Vec<String>,
+    index: Option<usize>,
+    draft: String,
+}
+
+impl Default for TextInputHistory {
+    fn default() -> Self {
+        Self::new(50)
+    }
+}
+
+impl TextInputHistory {
+    /// Creates an empty history storing up to `capacity` values.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Vec::new(),
+            index: None,
+            draft: String::new(),
+        }
+    }
+
+    /// The stored values, oldest first.
+    #[must_use]
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Adds a value to the history and resets the recall position.
+    /// Empty values and repetitions of the latest value are not stored.
+    pub fn push(&mut self, value: impl Into<String>) {
+        let value = value.into();
+        self.index = None;
+
+        if value.is_empty() || self.entries.last() == Some(&value) {
+            return;
+        }
+
+        self.entries.push(value);
+        if self.entries.len() > self.capacity {
+            let excess = self.entries.len() - self.capacity;
+            self.entries.drain(..excess);
+        }
+    }
+
+    /// Steps back to the previous value. The current value is kept to return to it later.
+    fn previous(&mut self, current: &str) -> Option<&str> {
+        let index = match self.index {
+            Some(0) => return None,
+            Some(index) => index - 1,
+            None => {
+                self.draft = current.to_string();
+                self.entries.len().checked_sub(1)?
+            }
+        };
+
+        self.index = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Steps forward to the next value, ending at the value that was typed before recalling.
+    fn next(&mut self) -> Option<&str> {
+        let index = self.index? + 1;
+
+        if index < self.entries.len() {
+            self.index = Some(index);
+            self.entries.get(index).map(String::as_str)
+        } else {
+            self.index = None;
+            Some(&self.draft)
+        }
+    }
+}
+
 /// A component containing the placeholder text that is displayed when the text input is empty.
 #[derive(Component, Default, Reflect)]
 pub struct TextInputPlaceholder {
@@ -214,27 +561,125 @@ impl TextInputPlaceholder {
 }
 
 #[derive(Component, Reflect)]
-struct TextInputPlaceholderInner;
+pub(crate) struct TextInputPlaceholderInner;
 
 /// A component containing the current text cursor position.
 #[derive(Component, Default, Reflect)]
 pub struct TextInputCursorPos(pub usize);
 
 #[derive(Component, Reflect)]
-struct TextInputInner;
+pub(crate) struct TextInputInner;
 
-/// An event that is fired when the user presses the enter key.
+/// How far the text of a single line text input is scrolled to the left, in logical pixels.
+#[derive(Component, Default)]
+struct TextInputScroll(f32);
+
+/// A command that moves the text cursor of a text input.
+///
+/// The position is counted in characters and clamped to the length of the value, so it can be
+/// queued right after changing the [`TextInputValue`].
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn prefill(mut commands: Commands, q_input: Query<Entity, With<TextInputValue>>) {
+///     for entity in &q_input {
+///         commands.entity(entity).insert(TextInputValue("hello".to_string()));
+///         commands.add(SetTextInputCursor::end(entity));
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SetTextInputCursor {
+    /// The text input to move the cursor of.
+    pub entity: Entity,
+    /// The new cursor position.
+    pub position: usize,
+}
+
+impl SetTextInputCursor {
+    /// Moves the cursor to the given position.
+    #[must_use]
+    pub fn new(entity: Entity, position: usize) -> Self {
+        Self { entity, position }
+    }
+
+    /// Moves the cursor to the start of the value.
+    #[must_use]
+    pub fn start(entity: Entity) -> Self {
+        Self::new(entity, 0)
+    }
+
+    /// Moves the cursor to the end of the value.
+    #[must_use]
+    pub fn end(entity: Entity) -> Self {
+        Self::new(entity, usize::MAX)
+    }
+}
+
+impl Command for SetTextInputCursor {
+    fn apply(self, world: &mut World) {
+        let Some(mut entity) = world.get_entity_mut(self.entity) else {
+            return;
+        };
+        let Some(len) = entity
+            .get::<TextInputValue>()
+            .map(|value| value.0.chars().count())
+        else {
+            return;
+        };
+
+        if let Some(mut cursor_pos) = entity.get_mut::<TextInputCursorPos>() {
+            cursor_pos.0 = self.position.min(len);
+        }
+        if let Some(mut cursor_timer) = entity.get_mut::<TextInputCursorTimer>() {
+            cursor_timer.should_reset = true;
+        }
+    }
+}
+
+/// An event that is fired when the user presses the enter key in a text input, or `Ctrl+Enter` in
+/// a text area. It is sent whether or not the input is part of a form, so standalone inputs like a
+/// chat box or a console can react to it.
 #[derive(Event)]
 pub struct TextInputSubmitEvent {
     /// The text input that triggered the event.
     pub entity: Entity,
     /// The string contained in the text input at the time of the event.
+    /// The separators of a [`TextInputFormatMask`] are removed and the [`TextInputTransform`] is
+    /// applied.
     pub value: String,
 }
 
+/// An event that is fired when text was dropped because it would exceed
+/// [`TextInputSettings::max_length`].
+#[derive(Event)]
+pub struct TextInputMaxLengthEvent {
+    /// The text input that reached its maximum length.
+    pub entity: Entity,
+}
+
+/// An event that is fired whenever the value of a text input changes, e.g. to update a preview
+/// while typing. See [`DebouncedChange`] to only fire it once the user stopped typing.
+#[derive(Event, Debug, Clone)]
+pub struct TextInputChanged {
+    /// The text input that changed.
+    pub entity: Entity,
+    /// The value before the change.
+    pub old: String,
+    /// The value after the change.
+    pub new: String,
+}
+
+/// The value of a text input when [`TextInputChanged`] was last sent.
+#[derive(Component)]
+struct TextInputPreviousValue(String);
+
 /// A convenience parameter for dealing with a text input's inner Bevy `Text` entity.
 #[derive(SystemParam)]
-struct InnerText<'w, 's> {
+pub(crate) struct InnerText<'w, 's> {
     text_query: Query<'w, 's, &'static mut Text, With<TextInputInner>>,
     children_query: Query<'w, 's, &'static Children>,
 }
@@ -247,8 +692,8 @@ impl<'w, 's> InnerText<'w, 's> {
     }
 }
 
-#[allow(clippy::needless_pass_by_value)]
-fn keyboard(
+#[allow(clippy::needless_pass_by_value, clippy::too_many_lines)]
+pub(crate) fn keyboard(
     mut events: EventReader<KeyboardInput>,
     res_keys: Res<ButtonInput<KeyCode>>,
     mut text_input_query: Query<
@@ -258,21 +703,38 @@ fn keyboard(
             &mut TextInputValue,
             &mut TextInputCursorPos,
             &mut TextInputCursorTimer,
+            Option<&TextInputFilter>,
+            Option<&TextAreaSettings>,
+            Option<&mut TextInputSelection>,
+            Option<&TextInputFormatMask>,
+            Option<&TextInputTransform>,
+            Has<TextInputReadonly>,
         ),
-        With<FormElementFocus>,
+        (With<FormElementFocus>, Without<FormElementDisabled>),
     >,
     mut submit_writer: EventWriter<TextInputSubmitEvent>,
+    mut max_length_writer: EventWriter<TextInputMaxLengthEvent>,
 ) {
     if events.is_empty() {
         return;
     }
 
-    if res_keys.pressed(KeyCode::ControlLeft) || res_keys.pressed(KeyCode::ControlRight) {
-        return;
-    }
-
-    for (input_entity, settings, mut text_input, mut cursor_pos, mut cursor_timer) in
-        &mut text_input_query
+    let ctrl = res_keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift = res_keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    for (
+        input_entity,
+        settings,
+        mut text_input,
+        mut cursor_pos,
+        mut cursor_timer,
+        filter,
+        text_area,
+        mut selection,
+        format_mask,
+        transform,
+        readonly,
+    ) in &mut text_input_query
     {
         let mut submitted_value = None;
 
@@ -282,76 +744,109 @@ fn keyboard(
             };
 
             let pos = cursor_pos.bypass_change_detection().0;
+            let selected = selection
+                .as_deref()
+                .map(TextInputSelection::range)
+                .filter(|range| !range.is_empty());
+
+            let target = cursor_target(&text_input.0, pos, selected.clone(), event, ctrl, shift);
+            if let Some(target) = target {
+                move_cursor(&mut cursor_pos, selection.as_mut(), target, shift);
+                cursor_timer.should_reset = true;
+                continue;
+            }
 
-            match event.key_code {
-                KeyCode::ArrowLeft => {
-                    if pos > 0 {
-                        cursor_pos.0 -= 1;
-
-                        cursor_timer.should_reset = true;
-                        continue;
-                    }
-                }
-                KeyCode::ArrowRight => {
-                    if pos < text_input.0.len() {
-                        cursor_pos.0 += 1;
+            // Read-only inputs only move the cursor.
+            if readonly {
+                continue;
+            }
 
-                        cursor_timer.should_reset = true;
-                        continue;
-                    }
-                }
-                KeyCode::Backspace => {
-                    if pos > 0 {
-                        cursor_pos.0 -= 1;
-                        text_input.0 = remove_char_at(&text_input.0, cursor_pos.0);
+            // Other shortcuts are handled elsewhere and not typed.
+            if ctrl
+                && !matches!(
+                    event.key_code,
+                    KeyCode::Backspace | KeyCode::Delete | KeyCode::Enter
+                )
+            {
+                continue;
+            }
 
-                        cursor_timer.should_reset = true;
-                        continue;
+            let mut handled = true;
+            match (event.key_code, selected.clone()) {
+                (KeyCode::Backspace | KeyCode::Delete, selected) => {
+                    let range = selected
+                        .unwrap_or_else(|| deleted_range(&text_input.0, pos, event.key_code, ctrl));
+                    handled = !range.is_empty();
+                    if handled {
+                        text_input.0 = remove_range(&text_input.0, range.clone());
+                        // Also marks the cursor as changed if it stays, so it isn't reset.
+                        cursor_pos.0 = range.start;
                     }
                 }
-                KeyCode::Delete => {
-                    if pos < text_input.0.len() {
-                        text_input.0 = remove_char_at(&text_input.0, cursor_pos.0);
-
-                        // Ensure that the cursor isn't reset
-                        cursor_pos.set_changed();
-
-                        cursor_timer.should_reset = true;
-                        continue;
+                // Text areas are submitted using `Ctrl+Enter`.
+                (KeyCode::Enter, _) if text_area.is_some() && !ctrl => {
+                    if text_area.is_some_and(|text_area| text_area.accepts_line(&text_input.0)) {
+                        let (s, exceeded) =
+                            fit_max_length(&text_input.0, selected.as_ref(), "\n", settings);
+                        if exceeded {
+                            max_length_writer.send(TextInputMaxLengthEvent {
+                                entity: input_entity,
+                            });
+                        }
+                        if !s.is_empty() {
+                            replace(&mut text_input, &mut cursor_pos, selected, s, None);
+                        }
                     }
                 }
-                KeyCode::Enter => {
+                (KeyCode::Enter, _) => {
                     if settings.retain_on_submit {
                         submitted_value = Some(text_input.0.clone());
                     } else {
                         submitted_value = Some(std::mem::take(&mut text_input.0));
                         cursor_pos.0 = 0;
                     };
-
-                    continue;
+                    handled = false;
                 }
-                KeyCode::Space => {
-                    text_input.0.insert(pos, ' ');
-                    cursor_pos.0 += 1;
-
-                    cursor_timer.should_reset = true;
-                    continue;
+                _ => {
+                    let typed = match &event.logical_key {
+                        Key::Space => " ",
+                        Key::Character(s) => s.as_str(),
+                        _ => "",
+                    };
+                    let (s, exceeded) =
+                        fit_max_length(&text_input.0, selected.as_ref(), typed, settings);
+                    if exceeded {
+                        max_length_writer.send(TextInputMaxLengthEvent {
+                            entity: input_entity,
+                        });
+                    }
+                    handled = !s.is_empty()
+                        && replace(&mut text_input, &mut cursor_pos, selected, s, filter);
                 }
-                _ => {}
             }
 
-            if let Key::Character(ref s) = event.logical_key {
-                let before = text_input.0.chars().take(cursor_pos.0);
-                let after = text_input.0.chars().skip(cursor_pos.0);
-                text_input.0 = before.chain(s.chars()).chain(after).collect();
-
-                cursor_pos.0 += 1;
-
+            if handled {
                 cursor_timer.should_reset = true;
             }
+
+            // Editing and moving the cursor ends the selection. Modifiers alone keep it.
+            if let Some(selection) = selection
+                .as_mut()
+                .filter(|_| !is_modifier(&event.logical_key))
+            {
+                selection.set_if_neq(TextInputSelection::collapsed(cursor_pos.0));
+            }
         }
 
         if let Some(value) = submitted_value {
+            let value = match format_mask {
+                Some(mask) => mask.strip(&value),
+                None => value,
+            };
+            let value = match transform {
+                Some(transform) => transform.apply(&value),
+                None => value,
+            };
             submit_writer.send(TextInputSubmitEvent {
                 entity: input_entity,
                 value,
@@ -360,33 +855,108 @@ fn keyboard(
     }
 }
 
-fn update_value(
+/// Sends a [`TextInputChanged`] event for each text input whose value changed. Setting the initial
+/// value of a new input is not a change.
+fn send_changed(
+    mut commands: Commands,
+    mut q_input: Query<
+        (
+            Entity,
+            &TextInputValue,
+            Option<&mut TextInputPreviousValue>,
+            Option<&mut DebouncedChange>,
+        ),
+        Changed<TextInputValue>,
+    >,
+    mut changed_writer: EventWriter<TextInputChanged>,
+) {
+    for (entity, value, previous, debounce) in &mut q_input {
+        let Some(mut previous) = previous else {
+            commands
+                .entity(entity)
+                .insert(TextInputPreviousValue(value.0.clone()));
+            continue;
+        };
+        if let Some(mut debounce) = debounce {
+            let delay = debounce.delay;
+            debounce.timer = Some(Timer::new(delay, TimerMode::Once));
+            continue;
+        }
+        if previous.0 == value.0 {
+            continue;
+        }
+
+        let old = std::mem::replace(&mut previous.0, value.0.clone());
+        changed_writer.send(TextInputChanged {
+            entity,
+            old,
+            new: value.0.clone(),
+        });
+    }
+}
+
+/// Sends the [`TextInputChanged`] event of debounced text inputs once their delay has passed.
+#[allow(clippy::needless_pass_by_value)]
+fn send_debounced_changed(
+    time: Res<Time>,
+    mut q_input: Query<(
+        Entity,
+        &TextInputValue,
+        &mut TextInputPreviousValue,
+        &mut DebouncedChange,
+    )>,
+    mut changed_writer: EventWriter<TextInputChanged>,
+) {
+    for (entity, value, mut previous, mut debounce) in &mut q_input {
+        let Some(timer) = &mut debounce.bypass_change_detection().timer else {
+            continue;
+        };
+        if !timer.tick(time.delta()).finished() {
+            continue;
+        }
+
+        debounce.timer = None;
+        if previous.0 != value.0 {
+            let old = std::mem::replace(&mut previous.0, value.0.clone());
+            changed_writer.send(TextInputChanged {
+                entity,
+                old,
+                new: value.0.clone(),
+            });
+        }
+    }
+}
+
+pub(crate) fn update_value(
     mut input_query: Query<
         (
             Entity,
             Ref<TextInputValue>,
             &TextInputSettings,
             &mut TextInputCursorPos,
+            Option<&mut TextInputSelection>,
         ),
         Or<(Changed<TextInputValue>, Changed<TextInputCursorPos>)>,
     >,
     mut inner_text: InnerText,
 ) {
-    for (entity, text_input, settings, mut cursor_pos) in &mut input_query {
-        let Some(mut text) = inner_text.get_mut(entity) else {
-            continue;
-        };
-
+    for (entity, text_input, settings, mut cursor_pos, selection) in &mut input_query {
         // Reset the cursor to the end of the input when the value is changed by
         // a user manipulating the value component.
         if text_input.is_changed() && !cursor_pos.is_changed() {
             cursor_pos.0 = text_input.0.chars().count();
+            if let Some(mut selection) = selection {
+                selection.set_if_neq(TextInputSelection::collapsed(cursor_pos.0));
+            }
         }
 
         if cursor_pos.is_changed() {
             cursor_pos.0 = cursor_pos.0.clamp(0, text_input.0.chars().count());
         }
 
+        let Some(mut text) = inner_text.get_mut(entity) else {
+            continue;
+        };
         set_section_values(
             &masked_value(&text_input.0, settings),
             cursor_pos.0,
@@ -395,16 +965,137 @@ fn update_value(
     }
 }
 
+/// Aligns the text of single line text inputs, and scrolls it horizontally so the caret stays
+/// visible when the value is wider than the input.
+#[allow(clippy::needless_pass_by_value, clippy::float_cmp)]
+fn scroll(
+    mut commands: Commands,
+    window: UiWindow,
+    q_input: Query<
+        (
+            Entity,
+            &TextInputSettings,
+            Option<&TextInputScroll>,
+            Option<&TextInputCaretStyle>,
+        ),
+        (With<TextInputValue>, Without<TextAreaSettings>),
+    >,
+    q_children: Query<&Children>,
+    mut q_inner: Query<(&TextLayoutInfo, &mut Style, &Parent), With<TextInputInner>>,
+    mut q_container: Query<(&Node, &mut Style), Without<TextInputInner>>,
+) {
+    let scale_factor = window.scale_factor();
+
+    for (entity, settings, scroll, caret_style) in &q_input {
+        let Some(inner) = q_children
+            .iter_descendants(entity)
+            .find(|child| q_inner.contains(*child))
+        else {
+            continue;
+        };
+        let Ok((layout, mut style, container)) = q_inner.get_mut(inner) else {
+            continue;
+        };
+        let Ok((container, mut container_style)) = q_container.get_mut(container.get()) else {
+            continue;
+        };
+
+        // Aligned text needs the whole width of the input to move in.
+        let container_width = match settings.alignment {
+            TextInputAlignment::Left => Val::Auto,
+            _ => Val::Percent(100.),
+        };
+        if container_style.width != container_width {
+            container_style.width = container_width;
+        }
+
+        let Some(cursor) = layout.glyphs.iter().find(|glyph| glyph.section_index == 1) else {
+            continue;
+        };
+
+        let width = container.size().x - caret_style.map_or(0.0, |caret_style| caret_style.width);
+        let text_width = layout.logical_size.x / scale_factor;
+        let caret = cursor.position.x / scale_factor;
+
+        let current = scroll.map_or(0.0, |scroll| scroll.0);
+        let offset = if caret < current {
+            caret
+        } else if caret > current + width {
+            caret - width
+        } else {
+            current
+        };
+        // Scroll back when the value got shorter.
+        let offset = offset.min(text_width - width).max(0.0);
+        if offset != current {
+            commands.entity(entity).insert(TextInputScroll(offset));
+        }
+
+        let free = (width - text_width).max(0.0);
+        let left = Val::Px(free * settings.alignment.factor() - offset);
+        if style.left != left {
+            style.left = left;
+        }
+    }
+}
+
+fn history_record(
+    mut events: EventReader<TextInputSubmitEvent>,
+    mut q_history: Query<&mut TextInputHistory>,
+) {
+    for event in events.read() {
+        if let Ok(mut history) = q_history.get_mut(event.entity) {
+            history.push(event.value.clone());
+        }
+    }
+}
+
+fn history_recall(
+    mut events: EventReader<KeyboardInput>,
+    mut q_history: Query<
+        (&mut TextInputValue, &mut TextInputHistory),
+        (
+            With<FormElementFocus>,
+            Without<NumberInputSettings>,
+            Without<TextAreaSettings>,
+        ),
+    >,
+) {
+    for event in events.read() {
+        if !event.state.is_pressed() {
+            continue;
+        }
+
+        for (mut text_input, mut history) in &mut q_history {
+            let recalled = match event.key_code {
+                KeyCode::ArrowUp => history.previous(&text_input.0),
+                KeyCode::ArrowDown => history.next(),
+                _ => None,
+            };
+
+            if let Some(value) = recalled {
+                text_input.0 = value.to_string();
+            }
+        }
+    }
+}
+
 #[allow(clippy::needless_pass_by_value)]
-fn validate(
+pub(crate) fn validate(
     mut commands: Commands,
     q_text_input: Query<
-        (Entity, &TextInputValue, Option<&FormElementOptional>),
+        (
+            Entity,
+            &TextInputValue,
+            Option<&FormElementOptional>,
+            Has<FormElementDisabled>,
+        ),
         Or<(Added<TextInputValue>, Changed<TextInputValue>)>,
     >,
 ) {
-    for (entity, text_input, optional) in &q_text_input {
-        if text_input.0.is_empty() && optional.is_none() {
+    for (entity, text_input, optional, disabled) in &q_text_input {
+        // A value consisting only of whitespace does not satisfy a required field.
+        if text_input.0.trim().is_empty() && optional.is_none() && !disabled {
             commands
                 .entity(entity)
                 .insert(FormElementInvalid(FormValidationError::Required(entity)))
@@ -421,22 +1112,89 @@ fn validate(
 #[cfg(feature = "clipboard")]
 fn clipboard(
     mut events: EventReader<ClipboardEvent>,
-    mut q_text_input: Query<(&mut TextInputValue, &mut TextInputCursorPos), With<FormElementFocus>>,
+    mut system_clipboard: ResMut<SystemClipboard>,
+    mut max_length_writer: EventWriter<TextInputMaxLengthEvent>,
+    mut q_text_input: Query<
+        (
+            Entity,
+            &mut TextInputValue,
+            &mut TextInputCursorPos,
+            Option<&mut TextInputSelection>,
+            &TextInputSettings,
+            Option<&TextInputFilter>,
+            Has<TextInputReadonly>,
+        ),
+        With<FormElementFocus>,
+    >,
 ) {
     for event in events.read() {
+        if let ClipboardEvent::Copy = event {
+            for (_, text_input, _, selection, settings, _, _) in &q_text_input {
+                // Masked values like passwords are not copied.
+                if settings.mask_character.is_some() {
+                    continue;
+                }
+
+                let text = match selection.filter(|selection| !selection.is_empty()) {
+                    Some(selection) => selection.text(&text_input.0),
+                    None => text_input.0.clone(),
+                };
+                system_clipboard.set_text(text);
+            }
+        }
+
+        if let ClipboardEvent::Cut = event {
+            for (_, mut text_input, mut cursor_pos, selection, settings, _, readonly) in
+                &mut q_text_input
+            {
+                let Some(mut selection) = selection.filter(|selection| !selection.is_empty())
+                else {
+                    continue;
+                };
+                if settings.mask_character.is_some() || readonly {
+                    continue;
+                }
+
+                let range = selection.range();
+                system_clipboard.set_text(selection.text(&text_input.0));
+                text_input.0 = remove_range(&text_input.0, range.clone());
+                cursor_pos.0 = range.start;
+                *selection = TextInputSelection::collapsed(range.start);
+            }
+        }
+
         if let ClipboardEvent::Paste(value) = event {
-            for (mut text_input, mut cursor_pos) in &mut q_text_input {
-                let value = value.replace(['\n', '\r'], "");
+            for (entity, mut text_input, mut cursor_pos, selection, settings, filter, readonly) in
+                &mut q_text_input
+            {
+                if readonly {
+                    continue;
+                }
 
-                text_input.0.insert_str(cursor_pos.0, &value);
-                cursor_pos.0 += value.chars().count();
+                let value = value.replace(['\n', '\r'], "");
+                let selected = selection
+                    .as_deref()
+                    .map(TextInputSelection::range)
+                    .filter(|range| !range.is_empty());
+
+                let (value, exceeded) =
+                    fit_max_length(&text_input.0, selected.as_ref(), &value, settings);
+                if exceeded {
+                    max_length_writer.send(TextInputMaxLengthEvent { entity });
+                }
+                replace(&mut text_input, &mut cursor_pos, selected, value, filter);
+                if let Some(mut selection) = selection {
+                    selection.set_if_neq(TextInputSelection::collapsed(cursor_pos.0));
+                }
             }
         }
     }
 }
 
+// Elements added to the input by other plugins (e.g. a suffix) are created after this, so they
+// are placed behind the text.
 #[allow(clippy::needless_pass_by_value)]
-fn create(
+pub(crate) fn create(
     mut commands: Commands,
     query: Query<
         (
@@ -447,25 +1205,44 @@ fn create(
             &TextInputActive,
             &TextInputSettings,
             Option<&FormElementOptional>,
+            Has<FormElementDisabled>,
+            Option<&TextAreaSettings>,
         ),
         Added<TextInputValue>,
     >,
+    headless: Option<Res<HeadlessForms>>,
 ) {
-    for (entity, style, text_input, cursor_pos, active, settings, optional) in &query {
+    for (entity, style, text_input, cursor_pos, active, settings, optional, disabled, text_area) in
+        &query
+    {
         info!("Creating text input");
+        let style = TextInputTextStyle(text_style(&style.0, disabled));
+        let active = TextInputActive(active.0 && !disabled);
+
+        // Set focus to new entity when spawned with active set to true.
+        if active.0 {
+            commands.entity(entity).insert(FormElementFocus);
+        }
+
+        if text_input.0.trim().is_empty() && optional.is_none() && !disabled {
+            commands
+                .entity(entity)
+                .insert(FormElementInvalid(FormValidationError::Required(entity)));
+        }
+
+        if headless.is_some() {
+            continue;
+        }
+
         let mut sections = vec![
             // Pre-cursor
             TextSection {
                 style: style.0.clone(),
                 ..default()
             },
-            // cursor
+            // cursor, only marking the position of the caret
             TextSection {
-                style: TextStyle {
-                    font: CURSOR_HANDLE,
-                    color: if active.0 { style.0.color } else { Color::NONE },
-                    ..style.0.clone()
-                },
+                style: cursor_style(&style.0),
                 ..default()
             },
             // Post-cursor
@@ -481,11 +1258,38 @@ fn create(
             &mut sections,
         );
 
+        // Text areas wrap long lines and scroll vertically instead of horizontally.
+        let (linebreak_behavior, container_style) = match text_area {
+            Some(text_area) => {
+                #[allow(clippy::cast_precision_loss)]
+                let height = text_area.rows.max(1) as f32 * text_area::line_height(&style.0);
+                (
+                    BreakLineOn::WordBoundary,
+                    Style {
+                        overflow: Overflow::clip(),
+                        flex_direction: FlexDirection::Column,
+                        width: Val::Percent(100.),
+                        height: Val::Px(height),
+                        ..default()
+                    },
+                )
+            }
+            // The text is scrolled horizontally to keep the caret visible.
+            None => (
+                BreakLineOn::NoWrap,
+                Style {
+                    overflow: Overflow::clip(),
+                    max_width: Val::Percent(100.),
+                    ..default()
+                },
+            ),
+        };
+
         let text = commands
             .spawn((
                 TextBundle {
                     text: Text {
-                        linebreak_behavior: BreakLineOn::NoWrap,
+                        linebreak_behavior,
                         sections,
                         ..default()
                     },
@@ -497,52 +1301,21 @@ fn create(
 
         let overflow_container = commands
             .spawn(NodeBundle {
-                style: Style {
-                    overflow: Overflow::clip(),
-                    justify_content: JustifyContent::FlexEnd,
-                    max_width: Val::Percent(100.),
-                    ..default()
-                },
+                style: container_style,
                 ..default()
             })
             .id();
 
-        // Set focus to new entity when spawned with active set to true.
-        if active.0 {
-            commands.entity(entity).insert(FormElementFocus);
-        }
-
-        if optional.is_none() {
-            commands
-                .entity(entity)
-                .insert(FormElementInvalid(FormValidationError::Required(entity)));
-        }
-
         commands.entity(overflow_container).add_child(text);
         commands.entity(entity).add_child(overflow_container);
     }
 }
 
-// Shows or hides the cursor based on the text input's [`TextInputInactive`] property.
-fn show_hide_cursor(
-    mut input_query: Query<
-        (
-            Entity,
-            &TextInputTextStyle,
-            &mut TextInputCursorTimer,
-            &TextInputActive,
-        ),
-        Changed<TextInputActive>,
-    >,
-    mut inner_text: InnerText,
-) {
-    for (entity, style, mut cursor_timer, active) in &mut input_query {
-        let Some(mut text) = inner_text.get_mut(entity) else {
-            continue;
-        };
-
-        text.sections[1].style.color = if active.0 { style.0.color } else { Color::NONE };
-
+// Restarts the blinking when the text input's [`TextInputActive`] property changes.
+// The caret is only shown while the input is active.
+fn show_hide_cursor(mut input_query: Query<&mut TextInputCursorTimer, Changed<TextInputActive>>) {
+    for mut cursor_timer in &mut input_query {
+        cursor_timer.visible = true;
         cursor_timer.timer.reset();
     }
 }
@@ -552,12 +1325,24 @@ fn focus_interaction(
     mut commands: Commands,
     q_interaction: Query<(Entity, &Interaction)>,
     mut q_text_input: Query<(Entity, &mut TextInputActive), With<TextInputValue>>,
+    q_disabled: Query<(), With<FormElementDisabled>>,
+    q_parent: Query<&Parent>,
 ) {
     for (entity, interaction) in &mut q_interaction.iter() {
         if *interaction != Interaction::Pressed {
             continue;
         }
 
+        // Interactive children of a text input (e.g. spinner buttons) keep the input focused.
+        let entity = std::iter::once(entity)
+            .chain(q_parent.iter_ancestors(entity))
+            .find(|entity| q_text_input.contains(*entity))
+            .unwrap_or(entity);
+
+        if q_disabled.contains(entity) {
+            continue;
+        }
+
         if let Ok((interacted_entity, mut active)) = q_text_input.get_mut(entity) {
             commands.entity(interacted_entity).insert(FormElementFocus);
             active.0 = true;
@@ -572,21 +1357,198 @@ fn focus_interaction(
     }
 }
 
+/// The primary window, converting between the logical and physical pixels of the UI.
+#[derive(SystemParam)]
+pub(crate) struct UiWindow<'w, 's> {
+    q_window: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    ui_scale: Option<Res<'w, UiScale>>,
+}
+
+impl UiWindow<'_, '_> {
+    /// Returns the factor converting logical into physical pixels of the UI.
+    pub(crate) fn scale_factor(&self) -> f32 {
+        let window = self.q_window.get_single().map_or(1.0, Window::scale_factor);
+        window * self.ui_scale.as_ref().map_or(1.0, |scale| scale.0)
+    }
+
+    /// Returns the position of the mouse in logical pixels, if it is inside of the window.
+    pub(crate) fn cursor_position(&self) -> Option<Vec2> {
+        self.q_window.get_single().ok()?.cursor_position()
+    }
+}
+
+/// Moves the cursor to the clicked character when pressing a text input. Dragging while pressed
+/// selects the text between the pressed character and the character below the mouse.
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub(crate) fn place_cursor(
+    window: UiWindow,
+    mut q_text_input: Query<
+        (
+            Entity,
+            Ref<Interaction>,
+            &TextInputTextStyle,
+            &mut TextInputCursorPos,
+            &mut TextInputCursorTimer,
+            Option<&mut TextInputSelection>,
+            Has<TextAreaSettings>,
+        ),
+        Without<FormElementDisabled>,
+    >,
+    q_text: Query<(&Text, &TextLayoutInfo, &Node, &GlobalTransform), With<TextInputInner>>,
+    q_children: Query<&Children>,
+) {
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+    let scale_factor = window.scale_factor();
+
+    for (entity, interaction, style, mut cursor_pos, mut cursor_timer, selection, text_area) in
+        &mut q_text_input
+    {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let Some((text, layout, node, transform)) = q_children
+            .iter_descendants(entity)
+            .find_map(|child| q_text.get(child).ok())
+        else {
+            continue;
+        };
+
+        // Glyphs are positioned relative to the top left corner of the text in physical pixels.
+        let top_left = transform.translation().truncate() - node.size() / 2.0;
+        let position = (cursor - top_left) * scale_factor;
+        let line_height = if text_area {
+            text_area::line_height(&style.0) * scale_factor
+        } else {
+            f32::INFINITY
+        };
+
+        let pos = cursor_pos_at(position, line_height, text, layout);
+        if let Some(mut selection) = selection {
+            let dragged = if interaction.is_changed() {
+                TextInputSelection::collapsed(pos)
+            } else {
+                TextInputSelection {
+                    end: pos,
+                    ..*selection
+                }
+            };
+            selection.set_if_neq(dragged);
+        }
+
+        if cursor_pos.0 != pos {
+            cursor_pos.0 = pos;
+            cursor_timer.should_reset = true;
+        }
+    }
+}
+
+/// Returns the line of the vertical glyph position, lines are `line_height` apart. Everything is on
+/// the first line if the height is infinite.
+pub(crate) fn line_of(y: f32, line_height: f32) -> f32 {
+    if line_height.is_finite() {
+        (y / line_height).floor()
+    } else {
+        0.0
+    }
+}
+
+/// Returns the glyphs of the value of a text input together with the index of their character.
+///
+/// The cursor glyph is skipped, characters after it are counted from the start of the value. The
+/// layout is only up to date before [`update_value`] changed the text.
+pub(crate) fn value_glyphs<'a>(
+    text: &'a Text,
+    layout: &'a TextLayoutInfo,
+) -> impl Iterator<Item = (usize, &'a PositionedGlyph)> + 'a {
+    let before_cursor = text.sections[0].value.chars().count();
+    layout
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.section_index != 1)
+        .map(move |glyph| {
+            let section = &text.sections[glyph.section_index].value;
+            let offset = if glyph.section_index == 0 {
+                0
+            } else {
+                before_cursor
+            };
+            // The layout lags behind when the text was changed in this frame.
+            let before = section.get(..glyph.byte_index).unwrap_or(section);
+            (offset + before.chars().count(), glyph)
+        })
+}
+
+/// Returns the cursor position closest to the position in the coordinates of the glyphs.
+///
+/// Only glyphs on the same line are considered, lines are `line_height` apart.
+fn cursor_pos_at(position: Vec2, line_height: f32, text: &Text, layout: &TextLayoutInfo) -> usize {
+    let line = line_of(position.y, line_height);
+
+    let mut glyphs = value_glyphs(text, layout)
+        .map(|(index, glyph)| {
+            (
+                index,
+                glyph.position,
+                line_of(glyph.position.y, line_height),
+            )
+        })
+        .skip_while(|(_, _, glyph_line)| *glyph_line < line)
+        .peekable();
+
+    let mut last = None;
+    while let Some((index, glyph_position, glyph_line)) = glyphs.next() {
+        // Clicking an empty line places the cursor in front of the line break ending it.
+        if glyph_line > line {
+            return last.map_or(index.saturating_sub(1), |last| last + 1);
+        }
+        if position.x < glyph_position.x {
+            return index;
+        }
+        last = Some(index);
+        if glyphs
+            .peek()
+            .is_some_and(|(_, _, next_line)| *next_line > line)
+        {
+            return index + 1;
+        }
+    }
+
+    text.sections[0].value.chars().count() + text.sections[2].value.chars().count()
+}
+
 #[allow(clippy::needless_pass_by_value)]
 fn focus_added(
     mut commands: Commands,
     q_focus_added: Query<Entity, Added<FormElementFocus>>,
     mut q_focus: Query<(Entity, &mut TextInputActive)>,
 ) {
-    for entity in &q_focus_added {
-        for (other_entity, mut active) in &mut q_focus {
-            if other_entity != entity {
-                commands.entity(other_entity).remove::<FormElementFocus>();
-                active.0 = false;
-                continue;
-            }
+    // Like `form_element_focus`, the element focused last keeps the focus, e.g. when forms with
+    // active elements are spawned at the same time.
+    let Some(entity) = q_focus_added.iter().last() else {
+        return;
+    };
 
-            active.0 = true;
+    for (other_entity, mut active) in &mut q_focus {
+        if other_entity != entity {
+            commands.entity(other_entity).remove::<FormElementFocus>();
+            active.0 = false;
+            continue;
+        }
+
+        active.0 = true;
+    }
+}
+
+fn focus_removed(
+    mut focus_removed: RemovedComponents<FormElementFocus>,
+    mut q_active: Query<&mut TextInputActive, Without<FormElementFocus>>,
+) {
+    for entity in focus_removed.read() {
+        if let Ok(mut active) = q_active.get_mut(entity) {
+            active.0 = false;
         }
     }
 }
@@ -595,34 +1557,32 @@ fn focus_added(
 #[allow(clippy::needless_pass_by_value)]
 fn blink_cursor(
     mut input_query: Query<
-        (Entity, &TextInputTextStyle, &mut TextInputCursorTimer),
+        (&mut TextInputCursorTimer, Option<&TextInputCaretStyle>),
         With<FormElementFocus>,
     >,
-    mut inner_text: InnerText,
     time: Res<Time>,
 ) {
-    for (entity, style, mut cursor_timer) in &mut input_query {
+    for (mut cursor_timer, caret_style) in &mut input_query {
         if cursor_timer.is_changed() && cursor_timer.should_reset {
             cursor_timer.timer.reset();
             cursor_timer.should_reset = false;
-            if let Some(mut text) = inner_text.get_mut(entity) {
-                text.sections[1].style.color = style.0.color;
-            }
-            continue;
-        }
-
-        if !cursor_timer.timer.tick(time.delta()).just_finished() {
+            cursor_timer.visible = true;
             continue;
         }
 
-        let Some(mut text) = inner_text.get_mut(entity) else {
+        let interval = caret_style.map_or(Some(0.5), |caret_style| caret_style.blink_interval);
+        let Some(interval) = interval.map(Duration::from_secs_f32) else {
+            if !cursor_timer.visible {
+                cursor_timer.visible = true;
+            }
             continue;
         };
+        if cursor_timer.timer.duration() != interval {
+            cursor_timer.timer.set_duration(interval);
+        }
 
-        if text.sections[1].style.color == Color::NONE {
-            text.sections[1].style.color = style.0.color;
-        } else {
-            text.sections[1].style.color = Color::NONE;
+        if cursor_timer.timer.tick(time.delta()).just_finished() {
+            cursor_timer.visible = !cursor_timer.visible;
         }
     }
 }
@@ -636,22 +1596,35 @@ fn set_placeholder(
             Option<&Children>,
             &TextInputValue,
             &TextInputTextStyle,
-            &TextInputPlaceholder,
+            Ref<TextInputPlaceholder>,
+            &TextInputSettings,
+            Has<FloatingLabel>,
         ),
-        Or<(Added<TextInputValue>, Changed<TextInputValue>)>,
+        Or<(
+            Added<TextInputValue>,
+            Changed<TextInputValue>,
+            Changed<TextInputPlaceholder>,
+        )>,
     >,
     q_inner: Query<(Entity, &TextInputPlaceholderInner)>,
 ) {
-    for (entity, children, text, style, placeholder) in &q_text_changed {
+    for (entity, children, text, style, placeholder, settings, floating) in &q_text_changed {
         let mut placeholder_inner = children
             .iter()
             .flat_map(|children| children.iter())
             .filter_map(|child| q_inner.get(*child).ok())
             .peekable();
 
-        if text.0.is_empty() {
+        // Floating labels stay visible above the value.
+        if text.0.is_empty() || floating {
             if placeholder_inner.peek().is_some() {
-                continue;
+                if !placeholder.is_changed() {
+                    continue;
+                }
+                // Respawned below with the new text and style.
+                placeholder_inner.for_each(|(entity, _)| {
+                    commands.entity(entity).despawn_recursive();
+                });
             }
 
             let placeholder_text = commands
@@ -659,7 +1632,11 @@ fn set_placeholder(
                     NodeBundle {
                         style: Style {
                             overflow: Overflow::clip(),
-                            justify_content: JustifyContent::FlexStart,
+                            justify_content: settings.alignment.justify_content(),
+                            width: match settings.alignment {
+                                TextInputAlignment::Left => Val::Auto,
+                                _ => Val::Percent(100.),
+                            },
                             max_width: Val::Percent(100.),
                             position_type: PositionType::Absolute,
                             ..default()
@@ -688,21 +1665,80 @@ fn set_placeholder(
     }
 }
 
+#[allow(clippy::type_complexity, clippy::needless_pass_by_value)]
+fn focus_ring(
+    themes: FormThemes,
+    mut input_query: Query<
+        (
+            Entity,
+            &TextInputSettings,
+            &TextInputActive,
+            &mut BorderColor,
+            Has<FormElementInvalid>,
+            Has<FormElementDisabled>,
+        ),
+        Without<FormThemed>,
+    >,
+) {
+    for (entity, settings, active, mut border_color, invalid, disabled) in &mut input_query {
+        if !settings.focus_ring {
+            continue;
+        }
+
+        let color = themes
+            .get(entity)
+            .border_color
+            .get(active.0, invalid, disabled);
+        if border_color.0 != color {
+            border_color.0 = color;
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
 fn update_style(
-    mut input_query: Query<(Entity, &TextInputTextStyle), Changed<TextInputTextStyle>>,
+    input_query: Query<
+        (Entity, &TextInputTextStyle, Has<FormElementDisabled>),
+        Or<(Changed<TextInputTextStyle>, Changed<FormElementDisabled>)>,
+    >,
+    q_style: Query<&TextInputTextStyle>,
+    mut disabled_removed: RemovedComponents<FormElementDisabled>,
     mut inner_text: InnerText,
 ) {
-    for (entity, style) in &mut input_query {
+    let enabled = disabled_removed
+        .read()
+        .filter_map(|entity| q_style.get(entity).ok().map(|style| (entity, style, false)));
+
+    for (entity, style, disabled) in input_query.iter().chain(enabled) {
         let Some(mut text) = inner_text.get_mut(entity) else {
             continue;
         };
 
-        text.sections[0].style = style.0.clone();
-        text.sections[1].style = TextStyle {
-            font: CURSOR_HANDLE,
-            ..style.0.clone()
-        };
-        text.sections[2].style = style.0.clone();
+        let style = text_style(&style.0, disabled);
+        text.sections[0].style = style.clone();
+        text.sections[1].style = cursor_style(&style);
+        text.sections[2].style = style;
+    }
+}
+
+/// Returns the style of the invisible cursor glyph, which marks the position of the caret.
+fn cursor_style(style: &TextStyle) -> TextStyle {
+    TextStyle {
+        font: CURSOR_HANDLE,
+        color: Color::NONE,
+        ..style.clone()
+    }
+}
+
+/// Returns the style of the input text. Disabled inputs are rendered with half opacity.
+fn text_style(style: &TextStyle, disabled: bool) -> TextStyle {
+    if disabled {
+        TextStyle {
+            color: style.color.with_a(style.color.a() * 0.5),
+            ..style.clone()
+        }
+    } else {
+        style.clone()
     }
 }
 
@@ -721,11 +1757,184 @@ fn set_section_values(value: &str, cursor_pos: usize, sections: &mut [TextSectio
     }
 }
 
-fn remove_char_at(input: &str, index: usize) -> String {
+fn is_modifier(key: &Key) -> bool {
+    matches!(key, Key::Shift | Key::Control | Key::Alt | Key::Super)
+}
+
+/// Returns where the key press moves the cursor to, or `None` if it does not move the cursor.
+///
+/// `Ctrl` moves the cursor by words, or to the start or end of the value using `Home` and `End`.
+/// Without `Ctrl`, `Home` and `End` move to the start or end of the line. Without `Shift`, the arrow
+/// keys move the cursor to the start or end of the selection.
+fn cursor_target(
+    value: &str,
+    pos: usize,
+    selected: Option<Range<usize>>,
+    event: &KeyboardInput,
+    ctrl: bool,
+    shift: bool,
+) -> Option<usize> {
+    let len = value.chars().count();
+    match (event.key_code, selected) {
+        (KeyCode::Home, _) if ctrl => Some(0),
+        (KeyCode::End, _) if ctrl => Some(len),
+        (KeyCode::Home, _) => Some(line_start(value, pos)),
+        (KeyCode::End, _) => Some(line_end(value, pos)),
+        (KeyCode::ArrowLeft, _) if ctrl => Some(word_start(value, pos)),
+        (KeyCode::ArrowRight, _) if ctrl => Some(word_end(value, pos)),
+        (KeyCode::ArrowLeft, Some(range)) if !shift => Some(range.start),
+        (KeyCode::ArrowRight, Some(range)) if !shift => Some(range.end),
+        (KeyCode::ArrowLeft, _) => Some(pos.saturating_sub(1)),
+        (KeyCode::ArrowRight, _) => Some((pos + 1).min(len)),
+        _ => None,
+    }
+}
+
+/// Returns the start of the line the position is in.
+fn line_start(value: &str, pos: usize) -> usize {
+    let before = value.chars().take(pos).collect::<Vec<_>>();
+    before.iter().rposition(|c| *c == '\n').map_or(0, |i| i + 1)
+}
+
+/// Returns the end of the line the position is in.
+fn line_end(value: &str, pos: usize) -> usize {
+    value
+        .chars()
+        .skip(pos)
+        .position(|c| c == '\n')
+        .map_or(value.chars().count(), |i| pos + i)
+}
+
+/// Moves the cursor. The selection is extended to the new position if `extend` is set, otherwise
+/// it is cleared.
+fn move_cursor(
+    cursor_pos: &mut Mut<TextInputCursorPos>,
+    selection: Option<&mut Mut<TextInputSelection>>,
+    target: usize,
+    extend: bool,
+) {
+    if let Some(selection) = selection {
+        let anchor = if selection.is_empty() {
+            cursor_pos.0
+        } else {
+            selection.start
+        };
+        selection.set_if_neq(if extend {
+            TextInputSelection::new(anchor, target)
+        } else {
+            TextInputSelection::collapsed(target)
+        });
+    }
+
+    cursor_pos.0 = target;
+}
+
+/// Returns the characters removed by `Backspace` or `Delete` without a selection. `Ctrl` removes up
+/// to the next word boundary.
+fn deleted_range(value: &str, pos: usize, key: KeyCode, ctrl: bool) -> Range<usize> {
+    let len = value.chars().count();
+    match key {
+        KeyCode::Backspace if ctrl => word_start(value, pos)..pos,
+        KeyCode::Backspace => pos.saturating_sub(1)..pos,
+        KeyCode::Delete if ctrl => pos..word_end(value, pos),
+        _ => pos..(pos + 1).min(len),
+    }
+}
+
+/// Returns the words of the value as ranges of character indices, found with the unicode word
+/// boundaries. Whitespace and punctuation between the words are skipped.
+fn words(value: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    let mut chars = 0;
+    let mut last_byte = 0;
+    value
+        .split_word_bound_indices()
+        .filter_map(move |(byte, segment)| {
+            chars += value[last_byte..byte].chars().count();
+            last_byte = byte;
+            let len = segment.chars().count();
+            segment
+                .chars()
+                .any(char::is_alphanumeric)
+                .then_some(chars..chars + len)
+        })
+}
+
+/// Returns the start of the word in front of the position.
+pub(crate) fn word_start(value: &str, pos: usize) -> usize {
+    words(value)
+        .take_while(|word| word.start < pos)
+        .last()
+        .map_or(0, |word| word.start)
+}
+
+/// Returns the end of the word after the position.
+pub(crate) fn word_end(value: &str, pos: usize) -> usize {
+    words(value)
+        .find(|word| word.end > pos)
+        .map_or_else(|| value.chars().count(), |word| word.end)
+}
+
+/// Replaces the selected text with the string, or inserts it at the cursor if nothing is selected.
+/// Returns false without changing the value if the filter rejects the string.
+fn replace(
+    text_input: &mut Mut<TextInputValue>,
+    cursor_pos: &mut Mut<TextInputCursorPos>,
+    selected: Option<Range<usize>>,
+    s: &str,
+    filter: Option<&TextInputFilter>,
+) -> bool {
+    let (value, pos) = match selected {
+        Some(range) => (remove_range(&text_input.0, range.clone()), range.start),
+        None => (text_input.0.clone(), cursor_pos.0),
+    };
+
+    let insert = |inserted: &str| -> String {
+        let before = value.chars().take(pos);
+        let after = value.chars().skip(pos);
+        before.chain(inserted.chars()).chain(after).collect()
+    };
+
+    // Each character is checked against the value including the characters inserted before it.
+    if let Some(filter) = filter {
+        for (i, (end, c)) in s.char_indices().enumerate() {
+            if !filter.accepts(&insert(&s[..end]), pos + i, c) {
+                return false;
+            }
+        }
+    }
+
+    text_input.0 = insert(s);
+    cursor_pos.0 = pos + s.chars().count();
+    true
+}
+
+/// Shortens the string to the characters fitting into the value without exceeding
+/// [`TextInputSettings::max_length`]. The selected text is replaced, so it doesn't count.
+/// Returns true as the second value if characters were dropped.
+fn fit_max_length<'a>(
+    value: &str,
+    selected: Option<&Range<usize>>,
+    s: &'a str,
+    settings: &TextInputSettings,
+) -> (&'a str, bool) {
+    let Some(max_length) = settings.max_length else {
+        return (s, false);
+    };
+
+    let remaining = value.chars().count() - selected.map_or(0, ExactSizeIterator::len);
+    let available = max_length.saturating_sub(remaining);
+    match s.char_indices().nth(available) {
+        Some((end, _)) => (&s[..end], true),
+        None => (s, false),
+    }
+}
+
+/// Removes the characters in the range.
+fn remove_range(input: &str, range: Range<usize>) -> String {
     input
         .chars()
         .enumerate()
-        .filter_map(|(i, c)| if i == index { None } else { Some(c) })
+        .filter_map(|(i, c)| if range.contains(&i) { None } else { Some(c) })
         .collect()
 }
 
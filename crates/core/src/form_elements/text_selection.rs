@@ -0,0 +1,364 @@
+#![allow(clippy::module_name_repetitions)]
+use std::ops::Range;
+
+use bevy::{ecs::system::Command, prelude::*, text::TextLayoutInfo};
+
+use crate::form_element::is_drawn;
+use crate::form_elements::text_area::{self, TextAreaSettings};
+use crate::form_elements::text_input::{self, TextInputInner, UiWindow};
+use crate::prelude::{
+    FormElementDisabled, FormElementFocus, FormKeyAction, FormKeyBindings, SetTextInputCursor,
+    TextInputCursorPos, TextInputTextStyle, TextInputValue,
+};
+
+/// A Bevy `Plugin` providing the systems required to select the text of text inputs.
+pub struct TextSelectionPlugin;
+
+impl Plugin for TextSelectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TextSelectionStyle>()
+            .init_resource::<FormKeyBindings>()
+            .add_systems(
+                Update,
+                (
+                    add_selection,
+                    clear_on_blur,
+                    select_all.before(text_input::update_value),
+                    highlight
+                        .run_if(is_drawn)
+                        .after(text_input::place_cursor)
+                        .after(text_input::keyboard)
+                        .before(text_input::update_value),
+                ),
+            )
+            .register_type::<TextInputSelection>()
+            .register_type::<TextSelectionStyle>();
+    }
+}
+
+/// Style of the highlight behind selected text.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct TextSelectionStyle {
+    /// The color of the highlight. Default is a half transparent blue.
+    pub highlight_color: Color,
+}
+
+impl Default for TextSelectionStyle {
+    fn default() -> Self {
+        Self {
+            highlight_color: Color::rgba(0.27, 0.52, 0.96, 0.4),
+        }
+    }
+}
+
+/// The selected text of a text input, counted in characters.
+///
+/// Added to all text inputs. `start` is where the selection began and `end` moves with the cursor,
+/// so `start` can be after `end`. The arrow keys, `Home` and `End` extend the selection while
+/// `Shift` is held, and the arrow keys move by words while `Ctrl` is held. Typing, `Backspace` and `Delete` replace the selected text.
+/// Use [`SetTextInputSelection`] to select text and move the cursor along.
+#[derive(Component, Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+pub struct TextInputSelection {
+    /// The position the selection started at.
+    pub start: usize,
+    /// The position the selection ends at, next to the cursor.
+    pub end: usize,
+}
+
+impl TextInputSelection {
+    /// A selection from `start` to `end`.
+    #[must_use]
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// An empty selection at the position.
+    #[must_use]
+    pub fn collapsed(pos: usize) -> Self {
+        Self::new(pos, pos)
+    }
+
+    /// The selected characters, in order.
+    #[must_use]
+    pub fn range(&self) -> Range<usize> {
+        self.start.min(self.end)..self.start.max(self.end)
+    }
+
+    /// Returns true if no text is selected.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the selected part of the value.
+    #[must_use]
+    pub fn text(&self, value: &str) -> String {
+        let range = self.range();
+        value
+            .chars()
+            .skip(range.start)
+            .take(range.end - range.start)
+            .collect()
+    }
+}
+
+/// A command that selects text of a text input and moves the cursor to the end of the selection.
+///
+/// Positions are counted in characters and clamped to the length of the value.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn select_on_focus(
+///     mut commands: Commands,
+///     q_focused: Query<Entity, (Added<FormElementFocus>, With<TextInputValue>)>,
+/// ) {
+///     for entity in &q_focused {
+///         commands.add(SetTextInputSelection::all(entity));
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SetTextInputSelection {
+    /// The text input to select the text of.
+    pub entity: Entity,
+    /// The new selection.
+    pub selection: TextInputSelection,
+}
+
+impl SetTextInputSelection {
+    /// Selects the text from `start` to `end`.
+    #[must_use]
+    pub fn new(entity: Entity, start: usize, end: usize) -> Self {
+        Self {
+            entity,
+            selection: TextInputSelection::new(start, end),
+        }
+    }
+
+    /// Selects the whole value.
+    #[must_use]
+    pub fn all(entity: Entity) -> Self {
+        Self::new(entity, 0, usize::MAX)
+    }
+}
+
+impl Command for SetTextInputSelection {
+    fn apply(self, world: &mut World) {
+        let Some(len) = world
+            .get::<TextInputValue>(self.entity)
+            .map(|value| value.0.chars().count())
+        else {
+            return;
+        };
+
+        let selection =
+            TextInputSelection::new(self.selection.start.min(len), self.selection.end.min(len));
+        world.entity_mut(self.entity).insert(selection);
+        SetTextInputCursor::new(self.entity, selection.end).apply(world);
+    }
+}
+
+/// Marker component of the nodes highlighting the selected text, one per line.
+#[derive(Component)]
+struct TextInputSelectionHighlight;
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_selection(
+    mut commands: Commands,
+    q_added: Query<Entity, (Added<TextInputValue>, Without<TextInputSelection>)>,
+) {
+    for entity in &q_added {
+        commands
+            .entity(entity)
+            .insert(TextInputSelection::default());
+    }
+}
+
+fn clear_on_blur(
+    mut focus_removed: RemovedComponents<FormElementFocus>,
+    mut q_selection: Query<&mut TextInputSelection>,
+) {
+    for entity in focus_removed.read() {
+        if let Ok(mut selection) = q_selection.get_mut(entity) {
+            let end = selection.end;
+            selection.set_if_neq(TextInputSelection::collapsed(end));
+        }
+    }
+}
+
+/// Selects the whole value of the focused text input on `Ctrl+A`.
+#[allow(clippy::needless_pass_by_value)]
+fn select_all(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<FormKeyBindings>,
+    mut q_focused: Query<
+        (
+            &TextInputValue,
+            &mut TextInputCursorPos,
+            &mut TextInputSelection,
+        ),
+        (With<FormElementFocus>, Without<FormElementDisabled>),
+    >,
+) {
+    if !key_bindings.just_pressed(FormKeyAction::SelectAll, &keys) {
+        return;
+    }
+
+    for (value, mut cursor_pos, mut selection) in &mut q_focused {
+        let len = value.0.chars().count();
+        selection.set_if_neq(TextInputSelection::new(0, len));
+        cursor_pos.0 = len;
+    }
+}
+
+/// Places a node behind each line of selected glyphs.
+#[allow(
+    clippy::needless_pass_by_value,
+    clippy::type_complexity,
+    clippy::too_many_arguments
+)]
+fn highlight(
+    mut commands: Commands,
+    window: UiWindow,
+    style: Res<TextSelectionStyle>,
+    q_input: Query<(
+        Entity,
+        &TextInputSelection,
+        &TextInputTextStyle,
+        Has<TextAreaSettings>,
+    )>,
+    q_children: Query<&Children>,
+    q_text: Query<(&Text, &TextLayoutInfo, &Node, &GlobalTransform, &Parent), With<TextInputInner>>,
+    q_container: Query<(&Node, &GlobalTransform)>,
+    mut q_highlight: Query<(&mut Style, &mut BackgroundColor), With<TextInputSelectionHighlight>>,
+) {
+    let scale_factor = window.scale_factor();
+
+    for (entity, selection, text_style, text_area) in &q_input {
+        let Some((text, layout, node, transform, container)) = q_children
+            .iter_descendants(entity)
+            .find_map(|child| q_text.get(child).ok())
+        else {
+            continue;
+        };
+        let Ok((container_node, container_transform)) = q_container.get(container.get()) else {
+            continue;
+        };
+
+        let line_height = if text_area {
+            text_area::line_height(&text_style.0)
+        } else {
+            f32::INFINITY
+        };
+        // The position of the text inside of the container the highlights are placed in.
+        let offset = (transform.translation() - container_transform.translation()).truncate()
+            - (node.size() - container_node.size()) / 2.0;
+
+        let rects = line_rects(selection, text, layout, line_height * scale_factor)
+            .into_iter()
+            .map(|(line, min_x, max_x)| {
+                let (top, height) = if text_area {
+                    (line * line_height, line_height)
+                } else {
+                    (0.0, node.size().y)
+                };
+                Rect::new(
+                    offset.x + min_x / scale_factor,
+                    offset.y + top,
+                    offset.x + max_x / scale_factor,
+                    offset.y + top + height,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut highlights = q_children
+            .get(container.get())
+            .map(|children| {
+                children
+                    .iter()
+                    .copied()
+                    .filter(|child| q_highlight.contains(*child))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        for (i, rect) in rects.iter().enumerate() {
+            let highlight_style = Style {
+                position_type: PositionType::Absolute,
+                left: Val::Px(rect.min.x),
+                top: Val::Px(rect.min.y),
+                width: Val::Px(rect.width()),
+                height: Val::Px(rect.height()),
+                ..default()
+            };
+
+            if let Some((mut current, mut background)) =
+                highlights.get(i).and_then(|e| q_highlight.get_mut(*e).ok())
+            {
+                if *current != highlight_style {
+                    *current = highlight_style;
+                }
+                if background.0 != style.highlight_color {
+                    background.0 = style.highlight_color;
+                }
+                continue;
+            }
+
+            let highlight = commands
+                .spawn((
+                    NodeBundle {
+                        style: highlight_style,
+                        background_color: style.highlight_color.into(),
+                        ..default()
+                    },
+                    TextInputSelectionHighlight,
+                ))
+                .id();
+            // Highlights are placed in front of the text, so they are drawn behind it.
+            commands
+                .entity(container.get())
+                .insert_children(0, &[highlight]);
+        }
+
+        for highlight in highlights.drain(rects.len().min(highlights.len())..) {
+            commands.entity(highlight).despawn_recursive();
+        }
+    }
+}
+
+/// Returns the line and horizontal extent of the selected glyphs of each line, in the coordinates
+/// of the glyphs.
+#[allow(clippy::float_cmp)] // Lines are whole numbers.
+fn line_rects(
+    selection: &TextInputSelection,
+    text: &Text,
+    layout: &TextLayoutInfo,
+    line_height: f32,
+) -> Vec<(f32, f32, f32)> {
+    let range = selection.range();
+    if range.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines: Vec<(f32, f32, f32)> = Vec::new();
+    for (_, glyph) in
+        text_input::value_glyphs(text, layout).filter(|(index, _)| range.contains(index))
+    {
+        let line = text_input::line_of(glyph.position.y, line_height);
+        let min_x = glyph.position.x - glyph.size.x / 2.0;
+        let max_x = glyph.position.x + glyph.size.x / 2.0;
+
+        match lines.last_mut() {
+            Some(last) if last.0 == line => {
+                last.1 = last.1.min(min_x);
+                last.2 = last.2.max(max_x);
+            }
+            _ => lines.push((line, min_x, max_x)),
+        }
+    }
+
+    lines
+}
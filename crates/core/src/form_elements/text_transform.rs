@@ -0,0 +1,114 @@
+#![allow(clippy::module_name_repetitions)]
+use bevy::prelude::*;
+
+use crate::form_elements::text_input;
+use crate::prelude::{FormElementFocus, TextInputValue};
+
+/// A Bevy `Plugin` providing the systems required to make a [`TextInputTransform`] work.
+pub struct TextTransformPlugin;
+
+impl Plugin for TextTransformPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                change_case
+                    .after(text_input::keyboard)
+                    .before(text_input::update_value),
+                trim_on_blur,
+            ),
+        )
+        .register_type::<TextInputTransform>()
+        .register_type::<TextCase>();
+    }
+}
+
+/// Normalizes the value of a text input, e.g. to trim and lowercase email addresses.
+///
+/// The transform is applied to the value of the [`TextInputSubmitEvent`](crate::prelude::TextInputSubmitEvent)
+/// and to the values read by generated forms. If `live` is set, the displayed value is transformed
+/// as well, the case while typing and trimming when the input loses focus.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default(),
+///         TextInputTransform {
+///             trim: true,
+///             case: Some(TextCase::Lowercase),
+///             live: true,
+///         },
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub struct TextInputTransform {
+    /// Removes leading and trailing whitespace.
+    pub trim: bool,
+    /// Converts all letters to the case.
+    pub case: Option<TextCase>,
+    /// If true, the displayed value is transformed too, not only the submitted one.
+    pub live: bool,
+}
+
+impl TextInputTransform {
+    /// Returns the transformed value.
+    #[must_use]
+    pub fn apply(&self, value: &str) -> String {
+        let value = if self.trim { value.trim() } else { value };
+        match self.case {
+            Some(TextCase::Lowercase) => value.to_lowercase(),
+            Some(TextCase::Uppercase) => value.to_uppercase(),
+            None => value.to_string(),
+        }
+    }
+}
+
+/// The case a [`TextInputTransform`] converts letters to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum TextCase {
+    /// All letters are lowercase.
+    Lowercase,
+    /// All letters are uppercase.
+    Uppercase,
+}
+
+fn change_case(
+    mut q_input: Query<(&TextInputTransform, &mut TextInputValue), Changed<TextInputValue>>,
+) {
+    for (transform, mut value) in &mut q_input {
+        if !transform.live {
+            continue;
+        }
+
+        // Trimming waits for the input to lose focus, so words can still be separated.
+        let transformed = TextInputTransform {
+            trim: false,
+            ..*transform
+        }
+        .apply(&value.0);
+        if transformed != value.0 {
+            value.0 = transformed;
+        }
+    }
+}
+
+fn trim_on_blur(
+    mut focus_removed: RemovedComponents<FormElementFocus>,
+    mut q_input: Query<(&TextInputTransform, &mut TextInputValue), Without<FormElementFocus>>,
+) {
+    for entity in focus_removed.read() {
+        let Ok((transform, mut value)) = q_input.get_mut(entity) else {
+            continue;
+        };
+
+        if transform.live && transform.trim && value.0.trim() != value.0 {
+            value.0 = value.0.trim().to_string();
+        }
+    }
+}
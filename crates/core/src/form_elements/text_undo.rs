@@ -0,0 +1,196 @@
+#![allow(clippy::module_name_repetitions)]
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::form_elements::text_input;
+use crate::prelude::{
+    FormElementDisabled, FormElementFocus, FormKeyAction, FormKeyBindings, TextInputCursorPos,
+    TextInputReadonly, TextInputSelection, TextInputSettings, TextInputValue,
+};
+
+/// A Bevy `Plugin` providing the systems required to undo and redo edits of text inputs.
+pub struct TextUndoPlugin;
+
+impl Plugin for TextUndoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FormKeyBindings>()
+            .add_systems(
+                Update,
+                (
+                    add_history,
+                    undo_redo.before(text_input::update_value),
+                    record.after(text_input::update_value),
+                ),
+            )
+            .register_type::<TextInputUndoHistory>();
+    }
+}
+
+/// The edits of a text input that can be undone with `Ctrl+Z` and redone with `Ctrl+Shift+Z` or
+/// `Ctrl+Y`.
+///
+/// Added to all text inputs. Every change of the value is recorded, including changes made by other
+/// systems. Characters typed in a row are undone together up to the next whitespace. The number of
+/// stored edits is limited by [`TextInputSettings::undo_depth`].
+#[derive(Component, Clone, Debug, Default, Reflect)]
+pub struct TextInputUndoHistory {
+    undo: VecDeque<(String, usize)>,
+    redo: Vec<(String, usize)>,
+    /// The value and cursor position the input had when last recorded.
+    current: (String, usize),
+    /// The position after the last typed character, while a word is being typed.
+    typing: Option<usize>,
+}
+
+impl TextInputUndoHistory {
+    /// Returns true if there is an edit to undo.
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns true if there is an undone edit to redo.
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets all edits.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.typing = None;
+    }
+
+    /// Stores the current state, so it can be restored later.
+    fn push(&mut self, depth: usize) {
+        self.undo.push_back(self.current.clone());
+        while self.undo.len() > depth {
+            self.undo.pop_front();
+        }
+        self.redo.clear();
+    }
+
+    /// Restores the state before the last edit, returning it.
+    fn undo(&mut self) -> Option<(String, usize)> {
+        let previous = self.undo.pop_back()?;
+        self.redo
+            .push(std::mem::replace(&mut self.current, previous.clone()));
+        self.typing = None;
+        Some(previous)
+    }
+
+    /// Restores the state before the last undo, returning it.
+    fn redo(&mut self) -> Option<(String, usize)> {
+        let next = self.redo.pop()?;
+        self.undo
+            .push_back(std::mem::replace(&mut self.current, next.clone()));
+        self.typing = None;
+        Some(next)
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_history(
+    mut commands: Commands,
+    q_added: Query<
+        (Entity, &TextInputValue, &TextInputCursorPos),
+        (Added<TextInputValue>, Without<TextInputUndoHistory>),
+    >,
+) {
+    for (entity, value, cursor_pos) in &q_added {
+        commands.entity(entity).insert(TextInputUndoHistory {
+            current: (value.0.clone(), cursor_pos.0),
+            ..default()
+        });
+    }
+}
+
+/// Undoes or redoes the last edit of the focused text input.
+#[allow(clippy::needless_pass_by_value)]
+fn undo_redo(
+    keys: Res<ButtonInput<KeyCode>>,
+    key_bindings: Res<FormKeyBindings>,
+    mut q_focused: Query<
+        (
+            &mut TextInputValue,
+            &mut TextInputCursorPos,
+            &mut TextInputUndoHistory,
+            Option<&mut TextInputSelection>,
+        ),
+        (
+            With<FormElementFocus>,
+            Without<FormElementDisabled>,
+            Without<TextInputReadonly>,
+        ),
+    >,
+) {
+    let undo = key_bindings.just_pressed(FormKeyAction::Undo, &keys);
+    if !undo && !key_bindings.just_pressed(FormKeyAction::Redo, &keys) {
+        return;
+    }
+
+    for (mut value, mut cursor_pos, mut history, selection) in &mut q_focused {
+        let restored = if undo { history.undo() } else { history.redo() };
+        let Some((restored_value, restored_cursor)) = restored else {
+            continue;
+        };
+
+        value.0 = restored_value;
+        cursor_pos.0 = restored_cursor;
+        if let Some(mut selection) = selection {
+            selection.set_if_neq(TextInputSelection::collapsed(restored_cursor));
+        }
+    }
+}
+
+/// Records changes of the value in the history.
+fn record(
+    mut q_input: Query<
+        (
+            &TextInputSettings,
+            &TextInputValue,
+            &TextInputCursorPos,
+            &mut TextInputUndoHistory,
+        ),
+        Or<(Changed<TextInputValue>, Changed<TextInputCursorPos>)>,
+    >,
+) {
+    for (settings, value, cursor_pos, mut history) in &mut q_input {
+        if history.current.0 == value.0 {
+            // Moving the cursor ends the word being typed.
+            if history.typing != Some(cursor_pos.0) {
+                history.typing = None;
+            }
+            history.current.1 = cursor_pos.0;
+            continue;
+        }
+
+        let typed = typed_char(&history.current.0, &value.0);
+        if history.typing.is_none() || typed.map(|(at, _)| at) != history.typing {
+            history.push(settings.undo_depth);
+        }
+
+        history.typing = typed
+            .filter(|(_, c)| !c.is_whitespace())
+            .map(|(at, _)| at + 1);
+        history.current = (value.0.clone(), cursor_pos.0);
+    }
+}
+
+/// Returns the position and character if the new value is the old one with a single character
+/// inserted.
+fn typed_char(old: &str, new: &str) -> Option<(usize, char)> {
+    let at = old
+        .chars()
+        .zip(new.chars())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let c = new.chars().nth(at)?;
+
+    old.chars()
+        .skip(at)
+        .eq(new.chars().skip(at + 1))
+        .then_some((at, c))
+}
@@ -0,0 +1,140 @@
+#![allow(clippy::module_name_repetitions)]
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// A Bevy `Plugin` providing the systems required to make a [`FormTooltip`] work.
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TooltipStyle>()
+            .add_systems(Update, (create, hover.after(create), update))
+            .register_type::<FormTooltip>()
+            .register_type::<TooltipStyle>();
+    }
+}
+
+/// A tooltip shown while the form element is hovered.
+///
+/// Add this to any form element. The tooltip is shown below the element once the pointer rested on
+/// it for the delay of the [`TooltipStyle`] and hidden when the pointer leaves the element.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         NodeBundle::default(),
+///         TextInputBundle::default(),
+///         FormTooltip("Your name as shown to other players".to_string()),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Default, Debug, Reflect)]
+pub struct FormTooltip(pub String);
+
+/// Style of tooltips.
+/// Default is white text with `font_size` 16.0 on a dark background, shown after half a second.
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct TooltipStyle {
+    /// How long the pointer needs to rest on the element before the tooltip is shown.
+    pub delay: Duration,
+    /// The text style of the tooltip.
+    pub text_style: TextStyle,
+    /// The background color of the tooltip.
+    pub background_color: Color,
+}
+
+impl Default for TooltipStyle {
+    fn default() -> Self {
+        Self {
+            delay: Duration::from_millis(500),
+            text_style: TextStyle {
+                font_size: 16.0,
+                color: Color::WHITE,
+                ..default()
+            },
+            background_color: Color::rgba(0.1, 0.1, 0.1, 0.9),
+        }
+    }
+}
+
+/// The time the element is hovered and the tooltip node while it is shown.
+#[derive(Component, Default)]
+struct TooltipState {
+    hovered: Duration,
+    node: Option<Entity>,
+}
+
+/// Elements need an `Interaction` to track whether they are hovered.
+#[allow(clippy::needless_pass_by_value)]
+fn create(mut commands: Commands, q_added: Query<(Entity, Has<Interaction>), Added<FormTooltip>>) {
+    for (entity, has_interaction) in &q_added {
+        let mut entity = commands.entity(entity);
+        entity.insert(TooltipState::default());
+        if !has_interaction {
+            entity.insert(Interaction::default());
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn hover(
+    mut commands: Commands,
+    style: Res<TooltipStyle>,
+    time: Res<Time>,
+    mut q_tooltip: Query<(Entity, &FormTooltip, &Interaction, &mut TooltipState)>,
+) {
+    for (entity, tooltip, interaction, mut state) in &mut q_tooltip {
+        if *interaction == Interaction::None {
+            state.hovered = Duration::ZERO;
+            if let Some(node) = state.node.take() {
+                if let Some(node) = commands.get_entity(node) {
+                    node.despawn_recursive();
+                }
+            }
+            continue;
+        }
+
+        state.hovered += time.delta();
+        if state.node.is_some() || state.hovered < style.delay {
+            continue;
+        }
+
+        let node = commands
+            .spawn((
+                TextBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Percent(100.0),
+                        left: Val::Px(0.0),
+                        padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                        ..default()
+                    },
+                    background_color: style.background_color.into(),
+                    z_index: ZIndex::Global(2),
+                    ..TextBundle::from_section(tooltip.0.clone(), style.text_style.clone())
+                },
+                Name::new("tooltip"),
+            ))
+            .id();
+        commands.entity(entity).add_child(node);
+        state.node = Some(node);
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn update(
+    q_tooltip: Query<(&FormTooltip, &TooltipState), Changed<FormTooltip>>,
+    mut q_text: Query<&mut Text>,
+) {
+    for (tooltip, state) in &q_tooltip {
+        let Some(mut text) = state.node.and_then(|node| q_text.get_mut(node).ok()) else {
+            continue;
+        };
+        text.sections[0].value.clone_from(&tooltip.0);
+    }
+}
@@ -32,6 +32,11 @@ pub mod form;
 pub mod form_element;
 /// Form elements
 pub mod form_elements;
+/// Helpers for testing forms without a window.
+#[cfg(feature = "test-util")]
+pub mod test_utils;
+/// The look of forms.
+pub mod theme;
 
 use bevy::app::{PluginGroup, PluginGroupBuilder};
 
@@ -40,15 +45,58 @@ use bevy::app::{PluginGroup, PluginGroupBuilder};
 pub use bevy_ui_forms_form_proc::form_struct;
 #[cfg(feature = "derive")]
 pub use bevy_ui_forms_form_proc::FormActions;
+#[cfg(feature = "derive")]
+pub use bevy_ui_forms_form_proc::FormSelectOptions;
+
+/// Re-export of `regex` for the validators matching regular expressions.
+#[cfg(feature = "regex")]
+pub use regex;
+
+/// Re-export of `chrono` for the types used by date inputs.
+#[cfg(feature = "date")]
+pub use chrono;
 
 /// Re-export common use items for easy access.
 pub mod prelude {
+    pub use crate::form::binding::*;
+    pub use crate::form::modal::*;
+    pub use crate::form::nested::*;
+    pub use crate::form::reflect::*;
+    pub use crate::form::repeated::*;
+    pub use crate::form::reset::*;
+    #[cfg(feature = "schema")]
+    pub use crate::form::schema::*;
+    pub use crate::form::section::*;
+    pub use crate::form::target::*;
+    pub use crate::form::validation::*;
     pub use crate::form::*;
     pub use crate::form_element::*;
+    pub use crate::form_elements::autocomplete::*;
     pub use crate::form_elements::button::*;
+    pub use crate::form_elements::checkbox::*;
+    pub use crate::form_elements::clear_button::*;
+    pub use crate::form_elements::color_picker::*;
+    #[cfg(feature = "date")]
+    pub use crate::form_elements::date_input::*;
+    #[cfg(feature = "date")]
+    pub use crate::form_elements::date_picker::*;
+    pub use crate::form_elements::floating_label::*;
+    pub use crate::form_elements::format_mask::*;
+    pub use crate::form_elements::number_input::*;
+    pub use crate::form_elements::select::*;
+    pub use crate::form_elements::slider::*;
+    pub use crate::form_elements::tag_input::*;
+    pub use crate::form_elements::text_area::*;
+    pub use crate::form_elements::text_caret::*;
     pub use crate::form_elements::text_input::*;
+    pub use crate::form_elements::text_selection::*;
+    pub use crate::form_elements::text_transform::*;
+    pub use crate::form_elements::text_undo::*;
+    pub use crate::form_elements::tooltip::*;
     pub use crate::form_struct;
+    pub use crate::theme::*;
     pub use crate::FormActions;
+    pub use crate::FormSelectOptions;
 }
 
 /// Plugin group for all `bevy_ui_forms` plugins.
@@ -56,10 +104,25 @@ pub struct BevyUiFormsPlugins;
 
 impl PluginGroup for BevyUiFormsPlugins {
     fn build(self) -> PluginGroupBuilder {
-        PluginGroupBuilder::start::<Self>()
+        let group = PluginGroupBuilder::start::<Self>()
             .add(form::FormPlugin)
             .add(form_element::FormElementPlugin)
             .add(form_elements::text_input::TextInputPlugin)
             .add(form_elements::button::ButtonPlugin)
+            .add(form_elements::number_input::NumberInputPlugin)
+            .add(form_elements::text_area::TextAreaPlugin)
+            .add(form_elements::tag_input::TagInputPlugin)
+            .add(form_elements::autocomplete::AutocompletePlugin)
+            .add(form_elements::checkbox::CheckboxPlugin)
+            .add(form_elements::color_picker::ColorPickerPlugin)
+            .add(form_elements::select::SelectPlugin)
+            .add(form_elements::slider::SliderPlugin)
+            .add(form_elements::tooltip::TooltipPlugin)
+            .add(theme::FormThemePlugin);
+
+        #[cfg(feature = "schema")]
+        let group = group.add(form::schema::FormSchemaPlugin);
+
+        group
     }
 }
@@ -0,0 +1,317 @@
+//! Helpers for driving forms in headless tests.
+//!
+//! Provides an [`App`] without a window and an extension trait to feed synthetic keyboard input
+//! and interactions into it, and to assert on the events of forms generated by the `form_struct`
+//! macro.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use bevy::prelude::*;
+//! use bevy_ui_forms::prelude::*;
+//! use bevy_ui_forms::test_utils::{headless_app, FormTestAppExt};
+//!
+//! let mut app = headless_app();
+//! let input = app
+//!     .world
+//!     .spawn((NodeBundle::default(), TextInputBundle::default().with_active(true)))
+//!     .id();
+//!
+//! app.step().type_text("hello");
+//!
+//! assert_eq!(app.world.get::<TextInputValue>(input).unwrap().0, "hello");
+//! ```
+#![allow(clippy::module_name_repetitions)]
+
+use bevy::{
+    asset::AssetPlugin,
+    ecs::event::Events,
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+};
+
+use crate::form::{FormEvent, GeneratedForm};
+use crate::form_element::HeadlessForms;
+use crate::prelude::{ButtonRole, FormButton, FormElementFocus};
+use crate::BevyUiFormsPlugins;
+
+/// Creates an [`App`] with all `bevy_ui_forms` plugins and the minimal set of Bevy plugins
+/// required to run them without a window. Forms are not drawn, see [`HeadlessForms`].
+#[must_use]
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        HierarchyPlugin,
+        InputPlugin,
+        AssetPlugin::default(),
+    ))
+    .init_asset::<Font>()
+    .init_resource::<HeadlessForms>()
+    .add_plugins(BevyUiFormsPlugins);
+
+    app
+}
+
+/// Extension trait for feeding synthetic input into an [`App`] and stepping it.
+pub trait FormTestAppExt {
+    /// Runs a single update of the app.
+    fn step(&mut self) -> &mut Self;
+
+    /// Sends a key press and steps the app.
+    fn press_key(&mut self, key_code: KeyCode, logical_key: Key) -> &mut Self;
+
+    /// Sends a key release and steps the app.
+    fn release_key(&mut self, key_code: KeyCode, logical_key: Key) -> &mut Self;
+
+    /// Presses and releases a key, stepping the app after each.
+    /// The logical key is derived from the key code.
+    fn tap_key(&mut self, key_code: KeyCode) -> &mut Self;
+
+    /// Types the text character by character and steps the app.
+    fn type_text(&mut self, text: &str) -> &mut Self;
+
+    /// Sets the [`Interaction`] of the entity to pressed and back, stepping the app after each.
+    fn click(&mut self, entity: Entity) -> &mut Self;
+
+    /// Focuses the form element and steps the app.
+    fn focus(&mut self, entity: Entity) -> &mut Self;
+
+    /// Clicks the first button with the role inside of the form, e.g. the generated submit button.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the form has no such button.
+    fn click_button(&mut self, form: Entity, role: impl Into<ButtonRole>) -> &mut Self;
+
+    /// Drains all pending events of the given type.
+    fn drain_events<E: Event>(&mut self) -> Vec<E>;
+
+    /// Drains the events of the generated form and returns the data of the first submit event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the form was not submitted.
+    fn assert_submitted<F>(&mut self) -> F
+    where
+        F: GeneratedForm + Clone,
+        F::Event: std::fmt::Debug;
+
+    /// Drains the events of the generated form and returns the form of the first cancel event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the form was not cancelled.
+    fn assert_cancelled<F>(&mut self) -> Entity
+    where
+        F: GeneratedForm,
+        F::Event: std::fmt::Debug;
+
+    /// Drains the events of the generated form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the form sent any event, e.g. because it was submitted while invalid.
+    fn assert_no_form_event<F>(&mut self)
+    where
+        F: GeneratedForm,
+        F::Event: std::fmt::Debug;
+}
+
+impl FormTestAppExt for App {
+    fn step(&mut self) -> &mut Self {
+        self.update();
+        self
+    }
+
+    fn press_key(&mut self, key_code: KeyCode, logical_key: Key) -> &mut Self {
+        send_key(self, key_code, logical_key, ButtonState::Pressed);
+        self.step()
+    }
+
+    fn release_key(&mut self, key_code: KeyCode, logical_key: Key) -> &mut Self {
+        send_key(self, key_code, logical_key, ButtonState::Released);
+        self.step()
+    }
+
+    fn tap_key(&mut self, key_code: KeyCode) -> &mut Self {
+        let logical_key = logical_key(key_code);
+        self.press_key(key_code, logical_key.clone())
+            .release_key(key_code, logical_key)
+    }
+
+    fn type_text(&mut self, text: &str) -> &mut Self {
+        for c in text.chars() {
+            let key_code = key_code(c);
+            let logical_key = if c == ' ' {
+                Key::Space
+            } else {
+                Key::Character(c.to_string().as_str().into())
+            };
+
+            send_key(self, key_code, logical_key.clone(), ButtonState::Pressed);
+            send_key(self, key_code, logical_key, ButtonState::Released);
+        }
+
+        self.step()
+    }
+
+    fn click(&mut self, entity: Entity) -> &mut Self {
+        self.world.entity_mut(entity).insert(Interaction::Pressed);
+        self.step();
+        self.world.entity_mut(entity).insert(Interaction::None);
+        self.step()
+    }
+
+    fn focus(&mut self, entity: Entity) -> &mut Self {
+        self.world.entity_mut(entity).insert(FormElementFocus);
+        self.step()
+    }
+
+    fn click_button(&mut self, form: Entity, role: impl Into<ButtonRole>) -> &mut Self {
+        let role = role.into();
+        let button = self
+            .world
+            .query_filtered::<(Entity, &ButtonRole), With<FormButton>>()
+            .iter(&self.world)
+            .filter(|(_, button_role)| **button_role == role)
+            .map(|(button, _)| button)
+            .find(|button| is_descendant(&self.world, *button, form));
+
+        let Some(button) = button else {
+            panic!("form {form:?} has no button with the role {role:?}");
+        };
+        self.click(button)
+    }
+
+    fn drain_events<E: Event>(&mut self) -> Vec<E> {
+        self.world.resource_mut::<Events<E>>().drain().collect()
+    }
+
+    fn assert_submitted<F>(&mut self) -> F
+    where
+        F: GeneratedForm + Clone,
+        F::Event: std::fmt::Debug,
+    {
+        let events = self.drain_events::<F::Event>();
+        let data = events.iter().find_map(|event| match F::form_event(event) {
+            FormEvent::Submit(data) => Some(data.clone()),
+            _ => None,
+        });
+
+        let Some(data) = data else {
+            panic!("the form was not submitted, events: {events:?}");
+        };
+        data
+    }
+
+    fn assert_cancelled<F>(&mut self) -> Entity
+    where
+        F: GeneratedForm,
+        F::Event: std::fmt::Debug,
+    {
+        let events = self.drain_events::<F::Event>();
+        let form = events.iter().find_map(|event| match F::form_event(event) {
+            FormEvent::Cancel(form) => Some(*form),
+            _ => None,
+        });
+
+        let Some(form) = form else {
+            panic!("the form was not cancelled, events: {events:?}");
+        };
+        form
+    }
+
+    fn assert_no_form_event<F>(&mut self)
+    where
+        F: GeneratedForm,
+        F::Event: std::fmt::Debug,
+    {
+        let events = self.drain_events::<F::Event>();
+        assert!(events.is_empty(), "the form sent events: {events:?}");
+    }
+}
+
+fn is_descendant(world: &World, entity: Entity, ancestor: Entity) -> bool {
+    let mut current = entity;
+    while let Some(parent) = world.get::<Parent>(current) {
+        if parent.get() == ancestor {
+            return true;
+        }
+        current = parent.get();
+    }
+    false
+}
+
+fn send_key(app: &mut App, key_code: KeyCode, logical_key: Key, state: ButtonState) {
+    app.world.send_event(KeyboardInput {
+        key_code,
+        logical_key,
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+fn logical_key(key_code: KeyCode) -> Key {
+    match key_code {
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::Escape => Key::Escape,
+        KeyCode::Space => Key::Space,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::ArrowLeft => Key::ArrowLeft,
+        KeyCode::ArrowRight => Key::ArrowRight,
+        KeyCode::ArrowUp => Key::ArrowUp,
+        KeyCode::ArrowDown => Key::ArrowDown,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        _ => Key::Unidentified(bevy::input::keyboard::NativeKey::Unidentified),
+    }
+}
+
+fn key_code(c: char) -> KeyCode {
+    match c.to_ascii_lowercase() {
+        ' ' => KeyCode::Space,
+        'a' => KeyCode::KeyA,
+        'b' => KeyCode::KeyB,
+        'c' => KeyCode::KeyC,
+        'd' => KeyCode::KeyD,
+        'e' => KeyCode::KeyE,
+        'f' => KeyCode::KeyF,
+        'g' => KeyCode::KeyG,
+        'h' => KeyCode::KeyH,
+        'i' => KeyCode::KeyI,
+        'j' => KeyCode::KeyJ,
+        'k' => KeyCode::KeyK,
+        'l' => KeyCode::KeyL,
+        'm' => KeyCode::KeyM,
+        'n' => KeyCode::KeyN,
+        'o' => KeyCode::KeyO,
+        'p' => KeyCode::KeyP,
+        'q' => KeyCode::KeyQ,
+        'r' => KeyCode::KeyR,
+        's' => KeyCode::KeyS,
+        't' => KeyCode::KeyT,
+        'u' => KeyCode::KeyU,
+        'v' => KeyCode::KeyV,
+        'w' => KeyCode::KeyW,
+        'x' => KeyCode::KeyX,
+        'y' => KeyCode::KeyY,
+        'z' => KeyCode::KeyZ,
+        '0' => KeyCode::Digit0,
+        '1' => KeyCode::Digit1,
+        '2' => KeyCode::Digit2,
+        '3' => KeyCode::Digit3,
+        '4' => KeyCode::Digit4,
+        '5' => KeyCode::Digit5,
+        '6' => KeyCode::Digit6,
+        '7' => KeyCode::Digit7,
+        '8' => KeyCode::Digit8,
+        '9' => KeyCode::Digit9,
+        _ => KeyCode::Unidentified(bevy::input::keyboard::NativeKeyCode::Unidentified),
+    }
+}
@@ -0,0 +1,661 @@
+#![allow(clippy::module_name_repetitions)]
+use std::f32::consts::TAU;
+
+use bevy::{ecs::system::SystemParam, prelude::*, window::PrimaryWindow};
+
+use crate::form_element::is_drawn;
+use crate::form_elements::text_input::TextInputInner;
+use crate::prelude::{
+    FormButton, FormElementDisabled, FormElementFocus, FormElementInvalid, FormElementValid,
+    FormInteraction, FormSubmitInvalid, TextInputTextStyle,
+};
+
+/// A Bevy `Plugin` applying the [`FormTheme`] to form elements and buttons.
+pub struct FormThemePlugin;
+
+impl Plugin for FormThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FormTheme>()
+            .add_systems(
+                Update,
+                (
+                    style_elements,
+                    style_buttons,
+                    style_texts,
+                    style_invalid,
+                    start_shake.run_if(is_drawn),
+                    shake.run_if(is_drawn).after(start_shake),
+                ),
+            )
+            .register_type::<FormTheme>()
+            .register_type::<FormThemeOverride>()
+            .register_type::<StateColors>()
+            .register_type::<ButtonColors>()
+            .register_type::<ErrorShake>()
+            .register_type::<ErrorShakeTarget>()
+            .register_type::<FormThemed>();
+    }
+}
+
+/// The look of forms, restyling all of them in one place.
+///
+/// Used by the built-in elements when they are created, by the forms generated by the
+/// `form_struct` macro and by [`FormButtonBundle`](crate::prelude::FormButtonBundle)s. Elements
+/// marked with [`FormThemed`] get the background, border and padding of the theme.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn setup(mut theme: ResMut<FormTheme>) {
+///     theme.input_background = Color::rgb(0.15, 0.15, 0.15);
+///     theme.text_style.color = Color::rgb(0.9, 0.9, 0.9);
+///     theme.border_color.focused = Color::rgb(0.75, 0.52, 0.99);
+/// }
+/// ```
+#[derive(Resource, Clone, Debug, Reflect)]
+pub struct FormTheme {
+    /// The text style of inputs and labels.
+    pub text_style: TextStyle,
+    /// The text style of the help texts shown below inputs.
+    pub help_text_style: TextStyle,
+    /// The text style of buttons.
+    pub button_text_style: TextStyle,
+    /// The background of inputs.
+    pub input_background: Color,
+    /// The background of invalid inputs.
+    pub invalid_background: Color,
+    /// Whether invalid elements that are not [`FormThemed`] get the invalid border color and
+    /// background too. Their own colors are restored once they are valid again.
+    pub style_invalid: bool,
+    /// The background of popups like the options of a select.
+    pub popup_background: Color,
+    /// The color of highlights like the thumb of a slider.
+    pub accent_color: Color,
+    /// The width of the border of inputs.
+    pub border_width: Val,
+    /// The border color of inputs depending on their state.
+    pub border_color: StateColors,
+    /// The background of buttons depending on their state.
+    pub button_background: ButtonColors,
+    /// The space between the border of inputs and their content.
+    pub padding: UiRect,
+    /// The space between the border of buttons and their text.
+    pub button_padding: UiRect,
+    /// The animation played when submitting an invalid form. Default is `None`.
+    pub error_shake: Option<ErrorShake>,
+}
+
+impl Default for FormTheme {
+    fn default() -> Self {
+        Self {
+            text_style: TextStyle {
+                font_size: 20.0,
+                color: Color::BLACK,
+                ..default()
+            },
+            help_text_style: TextStyle {
+                font_size: 16.0,
+                color: Color::rgb(0.45, 0.45, 0.45),
+                ..default()
+            },
+            button_text_style: TextStyle {
+                font_size: 20.0,
+                color: Color::BLACK,
+                ..default()
+            },
+            input_background: Color::WHITE,
+            invalid_background: Color::rgb(1.0, 0.92, 0.92),
+            style_invalid: true,
+            popup_background: Color::WHITE,
+            accent_color: Color::DARK_GRAY,
+            border_width: Val::Px(1.0),
+            border_color: StateColors::default(),
+            button_background: ButtonColors::default(),
+            padding: UiRect::axes(Val::Px(4.0), Val::Px(2.0)),
+            button_padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+            error_shake: None,
+        }
+    }
+}
+
+/// Overrides the [`FormTheme`] for the form it is added to and all of its descendants, e.g. to
+/// make a confirmation dialog look different from the other forms.
+///
+/// Texts of the form using one of the text styles of the global theme get the corresponding style
+/// of the override.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_ui_forms::prelude::*;
+/// fn spawn_danger_form(mut commands: Commands, theme: Res<FormTheme>) {
+///     let mut danger = theme.clone();
+///     danger.button_background.normal = Color::rgb(0.8, 0.1, 0.1);
+///     danger.border_color.focused = Color::rgb(0.8, 0.1, 0.1);
+///     commands.spawn((NodeBundle::default(), FormThemeOverride(danger)));
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub struct FormThemeOverride(pub FormTheme);
+
+/// Resolves the theme of an entity, which is the [`FormThemeOverride`] of its closest ancestor
+/// having one or the global [`FormTheme`].
+#[derive(SystemParam)]
+pub struct FormThemes<'w, 's> {
+    theme: Res<'w, FormTheme>,
+    q_override: Query<'w, 's, &'static FormThemeOverride>,
+    q_parent: Query<'w, 's, &'static Parent>,
+}
+
+impl FormThemes<'_, '_> {
+    /// Returns the theme of the entity.
+    #[must_use]
+    pub fn get(&self, entity: Entity) -> &FormTheme {
+        let mut current = Some(entity);
+        while let Some(entity) = current {
+            if let Ok(theme_override) = self.q_override.get(entity) {
+                return &theme_override.0;
+            }
+            current = self.q_parent.get(entity).ok().map(Parent::get);
+        }
+        &self.theme
+    }
+
+    /// Returns the global theme.
+    #[must_use]
+    pub fn global(&self) -> &FormTheme {
+        &self.theme
+    }
+}
+
+/// Colors of an input depending on its state.
+#[derive(Clone, Copy, Debug, Reflect)]
+pub struct StateColors {
+    /// The color of inputs in no other state.
+    pub normal: Color,
+    /// The color of the focused input.
+    pub focused: Color,
+    /// The color of invalid inputs.
+    pub invalid: Color,
+    /// The color of disabled inputs.
+    pub disabled: Color,
+}
+
+impl Default for StateColors {
+    fn default() -> Self {
+        Self {
+            normal: Color::GRAY,
+            focused: Color::rgb(1.0, 0.75, 0.0),
+            invalid: Color::rgb(0.8, 0.1, 0.1),
+            disabled: Color::rgb(0.8, 0.8, 0.8),
+        }
+    }
+}
+
+impl StateColors {
+    /// Returns the color for the state. Disabled inputs can not be focused, and the focus is shown
+    /// over the validity.
+    #[must_use]
+    pub fn get(&self, focused: bool, invalid: bool, disabled: bool) -> Color {
+        if disabled {
+            self.disabled
+        } else if focused {
+            self.focused
+        } else if invalid {
+            self.invalid
+        } else {
+            self.normal
+        }
+    }
+}
+
+/// Colors of a button depending on its state.
+#[derive(Clone, Copy, Debug, Reflect)]
+pub struct ButtonColors {
+    /// The color of buttons in no other state.
+    pub normal: Color,
+    /// The color of the hovered button.
+    pub hovered: Color,
+    /// The color of the pressed button.
+    pub pressed: Color,
+    /// The color of disabled buttons.
+    pub disabled: Color,
+}
+
+impl Default for ButtonColors {
+    fn default() -> Self {
+        Self {
+            normal: Color::rgb(0.9, 0.9, 0.9),
+            hovered: Color::rgb(0.8, 0.8, 0.8),
+            pressed: Color::rgb(0.7, 0.7, 0.7),
+            disabled: Color::rgba(0.9, 0.9, 0.9, 0.5),
+        }
+    }
+}
+
+impl ButtonColors {
+    /// Returns the color for the state.
+    #[must_use]
+    pub fn get(&self, interaction: &FormInteraction, disabled: bool) -> Color {
+        match interaction {
+            _ if disabled => self.disabled,
+            FormInteraction::None => self.normal,
+            FormInteraction::Hovered => self.hovered,
+            FormInteraction::Pressed => self.pressed,
+        }
+    }
+}
+
+/// A horizontal shake played when submitting an invalid form.
+///
+/// Default is three shakes of the invalid elements within 0.3 seconds, moving them up to 6 pixels.
+#[derive(Clone, Copy, Debug, Reflect)]
+pub struct ErrorShake {
+    /// What is shaken.
+    pub target: ErrorShakeTarget,
+    /// The duration of the animation in seconds.
+    pub duration: f32,
+    /// The largest horizontal offset in logical pixels.
+    pub distance: f32,
+    /// The number of back and forth movements.
+    pub shakes: u16,
+}
+
+impl Default for ErrorShake {
+    fn default() -> Self {
+        Self {
+            target: ErrorShakeTarget::Elements,
+            duration: 0.3,
+            distance: 6.0,
+            shakes: 3,
+        }
+    }
+}
+
+/// What is shaken by the [`ErrorShake`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum ErrorShakeTarget {
+    /// The invalid elements of the form.
+    #[default]
+    Elements,
+    /// The root of the form.
+    Form,
+}
+
+/// Marker component for elements getting the background, border and padding of the
+/// [`FormTheme`]. Inputs of generated forms have it.
+#[derive(Component, Default, Reflect)]
+pub struct FormThemed;
+
+/// The colors of an element that is not [`FormThemed`] before it was styled as invalid.
+#[derive(Component)]
+struct InvalidStyleBackup {
+    border: Option<Color>,
+    background: Option<Color>,
+}
+
+/// An element that is being shaken by the [`ErrorShake`]. Removed once the animation finished.
+#[derive(Component)]
+pub struct Shaking {
+    elapsed: f32,
+    inset: ShakeInset,
+}
+
+/// The inset positioning a shaken element horizontally, with its value before the shake and that
+/// value in logical pixels, which the shake is relative to.
+#[derive(Clone, Copy)]
+enum ShakeInset {
+    Left { original: Val, base: f32 },
+    Right { original: Val, base: f32 },
+}
+
+impl ShakeInset {
+    /// Returns `None` for absolutely positioned elements without insets, as their position can not
+    /// be offset.
+    fn of(style: &Style, parent_width: f32, viewport_size: Vec2) -> Option<Self> {
+        match (style.left, style.right) {
+            (Val::Auto, Val::Auto) => {
+                (style.position_type == PositionType::Relative).then_some(ShakeInset::Left {
+                    original: Val::Auto,
+                    base: 0.0,
+                })
+            }
+            (Val::Auto, right) => {
+                right
+                    .resolve(parent_width, viewport_size)
+                    .ok()
+                    .map(|base| ShakeInset::Right {
+                        original: right,
+                        base,
+                    })
+            }
+            (left, _) => {
+                left.resolve(parent_width, viewport_size)
+                    .ok()
+                    .map(|base| ShakeInset::Left {
+                        original: left,
+                        base,
+                    })
+            }
+        }
+    }
+
+    /// Moves the element to the right by the offset.
+    fn shift(self, style: &mut Style, offset: f32) {
+        match self {
+            ShakeInset::Left { base, .. } => style.left = Val::Px(base + offset),
+            ShakeInset::Right { base, .. } => style.right = Val::Px(base - offset),
+        }
+    }
+
+    fn restore(self, style: &mut Style) {
+        match self {
+            ShakeInset::Left { original, .. } => style.left = original,
+            ShakeInset::Right { original, .. } => style.right = original,
+        }
+    }
+}
+
+/// The text style of the theme a text was created with.
+#[derive(Component, Clone, Copy, PartialEq)]
+enum ThemeText {
+    Text,
+    HelpText,
+    ButtonText,
+}
+
+impl ThemeText {
+    fn of(style: &TextStyle, theme: &FormTheme) -> Option<Self> {
+        if same_style(style, &theme.text_style) {
+            Some(Self::Text)
+        } else if same_style(style, &theme.help_text_style) {
+            Some(Self::HelpText)
+        } else if same_style(style, &theme.button_text_style) {
+            Some(Self::ButtonText)
+        } else {
+            None
+        }
+    }
+
+    fn style(self, theme: &FormTheme) -> &TextStyle {
+        match self {
+            Self::Text => &theme.text_style,
+            Self::HelpText => &theme.help_text_style,
+            Self::ButtonText => &theme.button_text_style,
+        }
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::needless_pass_by_value)]
+fn style_elements(
+    themes: FormThemes,
+    mut q_element: Query<
+        (
+            Entity,
+            &mut Style,
+            &mut BackgroundColor,
+            &mut BorderColor,
+            Has<FormElementFocus>,
+            Has<FormElementInvalid>,
+            Has<FormElementDisabled>,
+        ),
+        With<FormThemed>,
+    >,
+) {
+    for (entity, mut style, mut background, mut border, focused, invalid, disabled) in
+        &mut q_element
+    {
+        let theme = themes.get(entity);
+        let border_width = UiRect::all(theme.border_width);
+        if style.border != border_width || style.padding != theme.padding {
+            style.border = border_width;
+            style.padding = theme.padding;
+        }
+        let background_color = if invalid && !disabled {
+            theme.invalid_background
+        } else {
+            theme.input_background
+        };
+        if background.0 != background_color {
+            background.0 = background_color;
+        }
+
+        let border_color = theme.border_color.get(focused, invalid, disabled);
+        if border.0 != border_color {
+            border.0 = border_color;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::needless_pass_by_value)]
+fn style_invalid(
+    mut commands: Commands,
+    themes: FormThemes,
+    mut q_invalid: Query<
+        (
+            Entity,
+            Option<&mut BorderColor>,
+            Option<&mut BackgroundColor>,
+        ),
+        (
+            Added<FormElementInvalid>,
+            Without<FormElementValid>,
+            Without<FormThemed>,
+            Without<InvalidStyleBackup>,
+        ),
+    >,
+    mut q_valid: Query<
+        (
+            Entity,
+            &InvalidStyleBackup,
+            Option<&mut BorderColor>,
+            Option<&mut BackgroundColor>,
+        ),
+        (Added<FormElementValid>, With<FormElementValid>),
+    >,
+) {
+    for (entity, border, background) in &mut q_invalid {
+        let theme = themes.get(entity);
+        if !theme.style_invalid {
+            continue;
+        }
+
+        let backup = InvalidStyleBackup {
+            border: border.as_ref().map(|border| border.0),
+            background: background.as_ref().map(|background| background.0),
+        };
+        if let Some(mut border) = border {
+            border.0 = theme.border_color.invalid;
+        }
+        if let Some(mut background) = background {
+            background.0 = theme.invalid_background;
+        }
+        commands.entity(entity).insert(backup);
+    }
+
+    for (entity, backup, border, background) in &mut q_valid {
+        if let (Some(mut border), Some(color)) = (border, backup.border) {
+            border.0 = color;
+        }
+        if let (Some(mut background), Some(color)) = (background, backup.background) {
+            background.0 = color;
+        }
+        commands.entity(entity).remove::<InvalidStyleBackup>();
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::needless_pass_by_value)]
+fn style_buttons(
+    themes: FormThemes,
+    mut q_button: Query<
+        (
+            Entity,
+            &Interaction,
+            &mut Style,
+            &mut BackgroundColor,
+            Has<FormElementDisabled>,
+        ),
+        With<FormButton>,
+    >,
+) {
+    for (entity, interaction, mut style, mut background, disabled) in &mut q_button {
+        let theme = themes.get(entity);
+        if style.padding != theme.button_padding {
+            style.padding = theme.button_padding;
+        }
+
+        let color = theme
+            .button_background
+            .get(&FormInteraction::from(interaction), disabled);
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::needless_pass_by_value)]
+fn style_texts(
+    mut commands: Commands,
+    themes: FormThemes,
+    q_override: Query<(), Changed<FormThemeOverride>>,
+    mut override_removed: RemovedComponents<FormThemeOverride>,
+    mut q_text: Query<(Entity, Option<&ThemeText>, &mut Text), Without<TextInputInner>>,
+    mut q_input: Query<(Entity, Option<&ThemeText>, &mut TextInputTextStyle)>,
+) {
+    // Texts are only restyled when they are added or their theme changes, leaving the font size of
+    // floating labels and dimmed colors of disabled elements alone otherwise.
+    let removed = override_removed.read().count() > 0;
+    let restyle = themes.theme.is_changed() || !q_override.is_empty() || removed;
+
+    for (entity, kind, mut text) in &mut q_text {
+        let kind = match kind {
+            Some(kind) if restyle => *kind,
+            None if text.is_added() => {
+                let Some(kind) = text
+                    .sections
+                    .first()
+                    .and_then(|section| ThemeText::of(&section.style, themes.global()))
+                else {
+                    continue;
+                };
+                commands.entity(entity).insert(kind);
+                kind
+            }
+            _ => continue,
+        };
+        let style = kind.style(themes.get(entity));
+        if text
+            .sections
+            .iter()
+            .any(|section| !same_style(&section.style, style))
+        {
+            for section in &mut text.sections {
+                section.style = style.clone();
+            }
+        }
+    }
+
+    for (entity, kind, mut input_style) in &mut q_input {
+        let kind = match kind {
+            Some(kind) if restyle => *kind,
+            None if input_style.is_added() => {
+                let Some(kind) = ThemeText::of(&input_style.0, themes.global()) else {
+                    continue;
+                };
+                commands.entity(entity).insert(kind);
+                kind
+            }
+            _ => continue,
+        };
+        let style = kind.style(themes.get(entity));
+        if !same_style(&input_style.0, style) {
+            input_style.0 = style.clone();
+        }
+    }
+}
+
+#[allow(clippy::float_cmp)] // Only styles copied from the theme are the same.
+fn same_style(style: &TextStyle, other: &TextStyle) -> bool {
+    style.font == other.font && style.font_size == other.font_size && style.color == other.color
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+fn start_shake(
+    mut commands: Commands,
+    themes: FormThemes,
+    mut ev_submit_invalid: EventReader<FormSubmitInvalid>,
+    q_children: Query<&Children>,
+    q_invalid: Query<(), With<FormElementInvalid>>,
+    q_style: Query<(&Style, Option<&Shaking>, Option<&Parent>)>,
+    q_node: Query<&Node>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+) {
+    let viewport_size = q_window.get_single().map_or(Vec2::ZERO, |window| {
+        Vec2::new(window.width(), window.height())
+    });
+
+    for ev in ev_submit_invalid.read() {
+        let Some(error_shake) = themes.get(ev.form).error_shake else {
+            continue;
+        };
+
+        let targets = match error_shake.target {
+            ErrorShakeTarget::Elements => q_children
+                .iter_descendants(ev.form)
+                .filter(|entity| q_invalid.contains(*entity))
+                .collect(),
+            ErrorShakeTarget::Form => vec![ev.form],
+        };
+        for target in targets {
+            let Ok((style, shaking, parent)) = q_style.get(target) else {
+                continue;
+            };
+            // A running shake starts over from the original offset.
+            let inset = shaking.map(|shaking| shaking.inset).or_else(|| {
+                let parent_width = parent
+                    .and_then(|parent| q_node.get(parent.get()).ok())
+                    .map_or(0.0, |node| node.size().x);
+                ShakeInset::of(style, parent_width, viewport_size)
+            });
+            let Some(inset) = inset else {
+                continue;
+            };
+            commands.entity(target).insert(Shaking {
+                elapsed: 0.0,
+                inset,
+            });
+        }
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn shake(
+    mut commands: Commands,
+    time: Res<Time>,
+    themes: FormThemes,
+    mut q_shaking: Query<(Entity, &mut Shaking, &mut Style)>,
+) {
+    for (entity, mut shaking, mut style) in &mut q_shaking {
+        shaking.elapsed += time.delta_seconds();
+        let progress = themes
+            .get(entity)
+            .error_shake
+            .filter(|error_shake| error_shake.duration > 0.0)
+            .map(|error_shake| (error_shake, shaking.elapsed / error_shake.duration))
+            .filter(|(_, progress)| *progress < 1.0);
+        let Some((error_shake, progress)) = progress else {
+            shaking.inset.restore(&mut style);
+            commands.entity(entity).remove::<Shaking>();
+            continue;
+        };
+
+        let offset = error_shake.distance
+            * (progress * f32::from(error_shake.shakes) * TAU).sin()
+            * (1.0 - progress);
+        shaking.inset.shift(&mut style, offset);
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "test-util")]
+//! Adjusting the color of a color picker.
+
+use bevy::prelude::*;
+use bevy_ui_forms::prelude::*;
+use bevy_ui_forms::test_utils::{headless_app, FormTestAppExt};
+
+fn spawn_picker(app: &mut App, bundle: ColorPickerBundle) -> Entity {
+    let picker = app.world.spawn((NodeBundle::default(), bundle)).id();
+    app.step().step();
+    picker
+}
+
+fn color(app: &App, picker: Entity) -> Color {
+    app.world.get::<ColorPickerValue>(picker).unwrap().0
+}
+
+fn hsv_slider(app: &mut App, channel: HsvChannel) -> Entity {
+    app.world
+        .query::<(Entity, &ColorPickerHsvSlider)>()
+        .iter(&app.world)
+        .find_map(|(entity, slider)| (slider.channel == channel).then_some(entity))
+        .unwrap()
+}
+
+fn assert_rgb(color: Color, [r, g, b]: [f32; 3]) {
+    let actual = [color.r(), color.g(), color.b()];
+    assert!(
+        actual
+            .iter()
+            .zip([r, g, b])
+            .all(|(actual, expected)| (actual - expected).abs() < 0.01),
+        "expected {:?}, got {actual:?}",
+        [r, g, b],
+    );
+}
+
+#[test]
+fn hue_slider_changes_color() {
+    let mut app = headless_app();
+    let picker = spawn_picker(&mut app, ColorPickerBundle::new(Color::RED));
+
+    let hue = hsv_slider(&mut app, HsvChannel::Hue);
+    assert_eq!(app.world.get::<SliderValue>(hue), Some(&SliderValue(0.0)));
+
+    app.world.entity_mut(hue).insert(SliderValue(120.0));
+    app.step();
+
+    assert_rgb(color(&app, picker), [0.0, 1.0, 0.0]);
+}
+
+#[test]
+fn channel_button_updates_hsv_sliders() {
+    let mut app = headless_app();
+    let picker = spawn_picker(&mut app, ColorPickerBundle::new(Color::BLACK));
+
+    let button = app
+        .world
+        .query::<(Entity, &ColorPickerChannelButton)>()
+        .iter(&app.world)
+        .find_map(|(entity, button)| {
+            (button.channel == ColorChannel::Red && button.increment).then_some(entity)
+        })
+        .unwrap();
+    app.click(button).step();
+
+    let value = hsv_slider(&mut app, HsvChannel::Value);
+    assert_eq!(
+        app.world.get::<SliderValue>(value),
+        Some(&SliderValue(0.07))
+    );
+    // The sliders only show the color, they do not round it.
+    assert!((color(&app, picker).r() - 17.0 / 255.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn texts_use_theme_text_style() {
+    let mut app = headless_app();
+    app.world.resource_mut::<FormTheme>().text_style.font_size = 31.0;
+    spawn_picker(&mut app, ColorPickerBundle::new(Color::RED));
+
+    let font_sizes = app
+        .world
+        .query::<&Text>()
+        .iter(&app.world)
+        .map(|text| text.sections[0].style.font_size)
+        .collect::<Vec<_>>();
+    assert!(!font_sizes.is_empty());
+    assert!(font_sizes
+        .iter()
+        .all(|font_size| (font_size - 31.0).abs() < f32::EPSILON));
+}
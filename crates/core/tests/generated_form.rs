@@ -0,0 +1,84 @@
+#![cfg(feature = "test-util")]
+//! Driving forms generated by the `form_struct` macro.
+
+use bevy::prelude::*;
+use bevy_ui_forms::prelude::*;
+use bevy_ui_forms::test_utils::{headless_app, FormTestAppExt};
+
+/// Data of a login form.
+#[form_struct(submit = "Login", cancel = "Cancel")]
+#[derive(Debug, Clone)]
+pub struct LoginData {
+    /// Name of the user.
+    #[form_field(active)]
+    #[text_box(placeholder = "Username")]
+    pub username: String,
+    /// Age of the user, if given.
+    #[form_field(optional)]
+    #[text_box(placeholder = "Age")]
+    pub age: Option<u32>,
+}
+
+fn app() -> (App, Entity) {
+    let mut app = headless_app();
+    app.add_plugins(LoginDataFormPlugin);
+    let form = app.world.spawn((NodeBundle::default(), LoginDataForm)).id();
+    app.step().step();
+
+    (app, form)
+}
+
+#[test]
+fn submit_sends_typed_data() {
+    let (mut app, form) = app();
+
+    app.type_text("bob").click_button(form, ButtonRole::Submit);
+
+    let data = app.assert_submitted::<LoginData>();
+    assert_eq!(data.username, "bob");
+    assert_eq!(data.age, None);
+}
+
+#[test]
+fn submit_of_invalid_form_is_ignored() {
+    let (mut app, form) = app();
+
+    app.click_button(form, ButtonRole::Submit);
+
+    app.assert_no_form_event::<LoginData>();
+}
+
+#[test]
+fn cancel_sends_cancel_event() {
+    let (mut app, form) = app();
+
+    app.type_text("bob").click_button(form, ButtonRole::Cancel);
+
+    assert_eq!(app.assert_cancelled::<LoginData>(), form);
+}
+
+#[test]
+fn enter_submits_the_typed_value() {
+    let (mut app, _) = app();
+
+    app.type_text("bob").tap_key(KeyCode::Enter).step();
+
+    assert_eq!(app.assert_submitted::<LoginData>().username, "bob");
+}
+
+#[test]
+fn submit_with_despawned_input_is_ignored() {
+    let (mut app, form) = app();
+    app.type_text("bob").step();
+
+    let age = app
+        .world
+        .query::<(Entity, &TextInputPlaceholder)>()
+        .iter(&app.world)
+        .find_map(|(entity, placeholder)| (placeholder.value == "Age").then_some(entity))
+        .unwrap();
+    app.world.entity_mut(age).despawn_recursive();
+    app.click_button(form, ButtonRole::Submit);
+
+    app.assert_no_form_event::<LoginData>();
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "test-util")]
+//! Stepping numeric text inputs.
+
+use bevy::prelude::*;
+use bevy_ui_forms::prelude::*;
+use bevy_ui_forms::test_utils::{headless_app, FormTestAppExt};
+
+fn spawn_number_input(app: &mut App, value: &str, settings: NumberInputSettings) -> Entity {
+    let input = app
+        .world
+        .spawn((
+            NodeBundle::default(),
+            TextInputBundle::default().with_value(value),
+            settings,
+        ))
+        .id();
+    app.step().focus(input);
+
+    input
+}
+
+fn value(app: &App, input: Entity) -> &str {
+    &app.world.get::<TextInputValue>(input).unwrap().0
+}
+
+#[test]
+fn arrow_keys_step_the_value() {
+    let mut app = headless_app();
+    let input = spawn_number_input(
+        &mut app,
+        "1",
+        NumberInputSettings {
+            step: 0.5,
+            ..default()
+        },
+    );
+
+    app.tap_key(KeyCode::ArrowUp).tap_key(KeyCode::ArrowUp);
+    assert_eq!(value(&app, input), "2.0");
+
+    app.tap_key(KeyCode::ArrowDown);
+    assert_eq!(value(&app, input), "1.5");
+}
+
+#[test]
+fn stepping_is_clamped_to_the_range() {
+    let mut app = headless_app();
+    let input = spawn_number_input(
+        &mut app,
+        "9",
+        NumberInputSettings {
+            min: Some(0.0),
+            max: Some(10.0),
+            step: 5.0,
+            ..default()
+        },
+    );
+
+    app.tap_key(KeyCode::ArrowUp);
+    assert_eq!(value(&app, input), "10");
+
+    app.tap_key(KeyCode::ArrowDown)
+        .tap_key(KeyCode::ArrowDown)
+        .tap_key(KeyCode::ArrowDown);
+    assert_eq!(value(&app, input), "0");
+}
+
+#[test]
+fn spinner_buttons_step_the_value() {
+    let mut app = headless_app();
+    let input = spawn_number_input(
+        &mut app,
+        "3",
+        NumberInputSettings {
+            spinner: true,
+            ..default()
+        },
+    );
+    let spinner = |app: &mut App, direction| {
+        app.world
+            .query::<(Entity, &NumberInputSpinner)>()
+            .iter(&app.world)
+            .find(|(_, spinner)| spinner.input == input && spinner.direction == direction)
+            .map(|(entity, _)| entity)
+            .unwrap()
+    };
+
+    let up = spinner(&mut app, StepDirection::Up);
+    app.click(up);
+    assert_eq!(value(&app, input), "4");
+
+    let down = spinner(&mut app, StepDirection::Down);
+    app.click(down).click(down);
+    assert_eq!(value(&app, input), "2");
+}
+
+#[test]
+fn prefix_suffix_and_spinner_surround_the_text() {
+    let mut app = headless_app();
+    app.world
+        .remove_resource::<bevy_ui_forms::form_element::HeadlessForms>();
+    let input = app
+        .world
+        .spawn((
+            NodeBundle::default(),
+            TextInputBundle::default().with_value("1"),
+            NumberInputSettings {
+                prefix: Some("$".to_string()),
+                suffix: Some("kg".to_string()),
+                spinner: true,
+                ..default()
+            },
+        ))
+        .id();
+    app.step();
+
+    let children = app.world.get::<Children>(input).unwrap().to_vec();
+    let text = |entity| {
+        app.world
+            .get::<Text>(entity)
+            .map(|text| text.sections[0].value.clone())
+    };
+    assert_eq!(children.len(), 4);
+    assert_eq!(text(children[0]).as_deref(), Some("$"));
+    assert_eq!(text(children[1]), None);
+    assert_eq!(text(children[2]).as_deref(), Some("kg"));
+    let spinner_buttons = app.world.get::<Children>(children[3]).unwrap();
+    assert!(spinner_buttons
+        .iter()
+        .all(|button| app.world.get::<NumberInputSpinner>(*button).is_some()));
+}
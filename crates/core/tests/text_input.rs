@@ -0,0 +1,225 @@
+#![cfg(feature = "test-util")]
+//! Editing text inputs with the keyboard.
+
+use bevy::input::keyboard::Key;
+use bevy::prelude::*;
+use bevy_ui_forms::prelude::*;
+use bevy_ui_forms::test_utils::{headless_app, FormTestAppExt};
+
+fn spawn_input(app: &mut App, bundle: impl Bundle) -> Entity {
+    let input = app.world.spawn((NodeBundle::default(), bundle)).id();
+    app.step().focus(input);
+
+    input
+}
+
+fn value(app: &App, input: Entity) -> &str {
+    &app.world.get::<TextInputValue>(input).unwrap().0
+}
+
+fn with_key(app: &mut App, key_code: KeyCode, logical_key: Key, tap: KeyCode) {
+    app.press_key(key_code, logical_key.clone())
+        .tap_key(tap)
+        .release_key(key_code, logical_key);
+}
+
+#[test]
+fn ctrl_z_undoes_and_ctrl_y_redoes() {
+    let mut app = headless_app();
+    let input = spawn_input(&mut app, TextInputBundle::default());
+
+    app.type_text("hello").type_text(" world");
+    with_key(&mut app, KeyCode::ControlLeft, Key::Control, KeyCode::KeyZ);
+    assert_eq!(value(&app, input), "hello");
+
+    with_key(&mut app, KeyCode::ControlLeft, Key::Control, KeyCode::KeyY);
+    assert_eq!(value(&app, input), "hello world");
+}
+
+#[test]
+fn typing_replaces_the_selection() {
+    let mut app = headless_app();
+    let input = spawn_input(&mut app, TextInputBundle::default());
+
+    app.type_text("hello");
+    app.press_key(KeyCode::ShiftLeft, Key::Shift)
+        .tap_key(KeyCode::ArrowLeft)
+        .tap_key(KeyCode::ArrowLeft)
+        .release_key(KeyCode::ShiftLeft, Key::Shift);
+    app.type_text("p");
+    assert_eq!(value(&app, input), "help");
+
+    with_key(&mut app, KeyCode::ControlLeft, Key::Control, KeyCode::KeyA);
+    app.tap_key(KeyCode::Backspace);
+    assert_eq!(value(&app, input), "");
+}
+
+#[test]
+fn typing_stops_at_max_length() {
+    let mut app = headless_app();
+    let input = spawn_input(
+        &mut app,
+        TextInputBundle::default().with_settings(TextInputSettings {
+            max_length: Some(3),
+            ..default()
+        }),
+    );
+
+    app.type_text("abcd");
+    assert_eq!(value(&app, input), "abc");
+
+    let events = app.drain_events::<TextInputMaxLengthEvent>();
+    assert!(events.iter().any(|event| event.entity == input));
+}
+
+#[test]
+fn filter_drops_rejected_characters() {
+    let mut app = headless_app();
+    let input = spawn_input(
+        &mut app,
+        (
+            TextInputBundle::default(),
+            TextInputFilter::Decimal {
+                separator: '.',
+                negative: true,
+            },
+        ),
+    );
+
+    app.type_text("-1a.2.3-");
+    assert_eq!(value(&app, input), "-1.23");
+}
+
+#[test]
+fn custom_filter_decides_per_character() {
+    let mut app = headless_app();
+    let input = spawn_input(
+        &mut app,
+        (
+            TextInputBundle::default(),
+            TextInputFilter::Custom(|c| c.is_ascii_uppercase()),
+        ),
+    );
+
+    app.type_text("aBcD");
+    assert_eq!(value(&app, input), "BD");
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex_filter_matches_each_character() {
+    let mut app = headless_app();
+    let input = spawn_input(
+        &mut app,
+        (
+            TextInputBundle::default(),
+            TextInputFilter::Regex(regex::Regex::new("[a-f0-9]").unwrap()),
+        ),
+    );
+
+    app.type_text("c0ffee xyz 42");
+    assert_eq!(value(&app, input), "c0ffee42");
+}
+
+#[cfg(feature = "clipboard")]
+fn paste(app: &mut App, filter: TextInputFilter, text: &str) -> String {
+    use bevy_ui_forms::clipboard::ClipboardEvent;
+
+    let input = spawn_input(app, (TextInputBundle::default(), filter));
+    app.world
+        .send_event(ClipboardEvent::Paste(text.to_string()));
+    app.step();
+
+    value(app, input).to_string()
+}
+
+#[cfg(feature = "clipboard")]
+#[test]
+fn paste_is_filtered_against_the_pasted_text() {
+    let decimal = TextInputFilter::Decimal {
+        separator: '.',
+        negative: true,
+    };
+    assert_eq!(paste(&mut headless_app(), decimal.clone(), "-1.5"), "-1.5");
+    assert_eq!(paste(&mut headless_app(), decimal, "1.2.3"), "");
+
+    let integer = TextInputFilter::Integer { negative: true };
+    assert_eq!(paste(&mut headless_app(), integer, "--5"), "");
+
+    assert_eq!(
+        paste(&mut headless_app(), TextInputFilter::Identifier, "a1"),
+        "a1"
+    );
+    assert_eq!(
+        paste(&mut headless_app(), TextInputFilter::Identifier, "1a"),
+        ""
+    );
+}
+
+#[test]
+fn format_mask_inserts_separators() {
+    let mut app = headless_app();
+    let mask = TextInputFormatMask::new("(###) ###-####");
+    let input = spawn_input(&mut app, (TextInputBundle::default(), mask.clone()));
+
+    app.type_text("555x1234567");
+    assert_eq!(value(&app, input), "(555) 123-4567");
+    assert_eq!(mask.strip(value(&app, input)), "5551234567");
+
+    app.type_text("8");
+    assert_eq!(value(&app, input), "(555) 123-4567");
+}
+
+#[test]
+fn enter_clears_input_inside_hand_built_form() {
+    let mut app = headless_app();
+    let input = spawn_input(&mut app, TextInputBundle::default());
+    app.world
+        .spawn((NodeBundle::default(), Form))
+        .add_child(input);
+
+    app.type_text("hello").tap_key(KeyCode::Enter);
+    assert_eq!(value(&app, input), "");
+
+    let events = app.drain_events::<TextInputSubmitEvent>();
+    assert!(events.iter().any(|event| event.value == "hello"));
+}
+
+#[test]
+fn ctrl_backspace_deletes_unicode_words() {
+    let mut app = headless_app();
+    let input = spawn_input(
+        &mut app,
+        TextInputBundle::default().with_value("日本語 can't"),
+    );
+
+    with_key(
+        &mut app,
+        KeyCode::ControlLeft,
+        Key::Control,
+        KeyCode::Backspace,
+    );
+    assert_eq!(value(&app, input), "日本語 ");
+
+    with_key(
+        &mut app,
+        KeyCode::ControlLeft,
+        Key::Control,
+        KeyCode::Backspace,
+    );
+    assert_eq!(value(&app, input), "日本");
+}
+
+#[test]
+fn filter_is_reflected() {
+    let app = headless_app();
+    let registry = app.world.resource::<AppTypeRegistry>().read();
+    assert!(registry
+        .get(std::any::TypeId::of::<TextInputFilter>())
+        .is_some());
+
+    let filter = TextInputFilter::Chars("ab".to_string());
+    let reflected = TextInputFilter::from_reflect(filter.clone_value().as_ref()).unwrap();
+    assert!(reflected.accepts("", 0, 'a'));
+    assert!(!reflected.accepts("", 0, 'c'));
+}
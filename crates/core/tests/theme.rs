@@ -0,0 +1,106 @@
+#![cfg(feature = "test-util")]
+//! Shaking the invalid elements of a form when submitting it.
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_ui_forms::form_element::HeadlessForms;
+use bevy_ui_forms::prelude::*;
+use bevy_ui_forms::test_utils::{headless_app, FormTestAppExt};
+
+fn app(style: Style) -> (App, Entity, Entity) {
+    let mut app = headless_app();
+    // The shake is only played for forms that are drawn.
+    app.world.remove_resource::<HeadlessForms>();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    app.world.resource_mut::<FormTheme>().error_shake = Some(ErrorShake::default());
+
+    let input = app
+        .world
+        .spawn((
+            NodeBundle { style, ..default() },
+            TextInputBundle::default(),
+        ))
+        .id();
+    let form = app
+        .world
+        .spawn((NodeBundle::default(), Form, FormValid))
+        .add_child(input)
+        .id();
+    let button = app
+        .world
+        .spawn(
+            FormButtonBundle::new("Submit")
+                .with_form(form)
+                .with_role(ButtonRole::Submit),
+        )
+        .id();
+    app.world.entity_mut(form).add_child(button);
+    app.step().step();
+
+    (app, form, input)
+}
+
+#[test]
+fn submitting_invalid_form_shakes_invalid_elements() {
+    let (mut app, form, input) = app(Style {
+        left: Val::Percent(10.0),
+        ..default()
+    });
+    assert!(app.world.get::<FormInvalid>(form).is_some());
+
+    app.click_button(form, ButtonRole::Submit);
+
+    let events = app.drain_events::<FormSubmitInvalid>();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].form, form);
+    assert!(app.world.get::<Shaking>(input).is_some());
+    assert!(matches!(
+        app.world.get::<Style>(input).unwrap().left,
+        Val::Px(_)
+    ));
+
+    app.step().step();
+
+    assert!(app.world.get::<Shaking>(input).is_none());
+    assert_eq!(
+        app.world.get::<Style>(input).unwrap().left,
+        Val::Percent(10.0)
+    );
+}
+
+#[test]
+fn submitting_valid_form_does_not_shake() {
+    let (mut app, form, input) = app(Style::default());
+    app.world.get_mut::<TextInputValue>(input).unwrap().0 = "name".to_string();
+    app.step();
+
+    app.click_button(form, ButtonRole::Submit);
+
+    assert!(app.drain_events::<FormSubmitInvalid>().is_empty());
+    assert!(app.world.get::<Shaking>(input).is_none());
+}
+
+#[test]
+fn right_anchored_elements_shake_around_their_offset() {
+    let (mut app, form, input) = app(Style {
+        position_type: PositionType::Absolute,
+        right: Val::Px(20.0),
+        ..default()
+    });
+
+    app.click_button(form, ButtonRole::Submit);
+
+    let style = app.world.get::<Style>(input).unwrap();
+    assert_eq!(style.left, Val::Auto);
+    let Val::Px(right) = style.right else {
+        panic!("expected a pixel offset, got {:?}", style.right);
+    };
+    assert!((right - 20.0).abs() <= ErrorShake::default().distance);
+
+    app.step().step();
+
+    assert_eq!(app.world.get::<Style>(input).unwrap().right, Val::Px(20.0));
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "test-util")]
+//! Validation of form elements.
+
+use bevy::prelude::*;
+use bevy_ui_forms::prelude::*;
+use bevy_ui_forms::test_utils::headless_app;
+
+#[test]
+fn required_input_with_only_spaces_is_invalid() {
+    let mut app = headless_app();
+    let input = app
+        .world
+        .spawn((
+            NodeBundle::default(),
+            TextInputBundle::default().with_value("name"),
+        ))
+        .id();
+    let form = app
+        .world
+        .spawn((NodeBundle::default(), Form, FormValid))
+        .add_child(input)
+        .id();
+    app.update();
+
+    app.world.get_mut::<TextInputValue>(input).unwrap().0 = "   ".to_string();
+    app.update();
+
+    assert!(app.world.get::<FormInvalid>(form).is_some());
+    assert!(app.world.get::<FormValid>(form).is_none());
+}
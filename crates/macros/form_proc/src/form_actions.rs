@@ -1,3 +1,6 @@
+// The derives of darling expand to `continue` expressions at the end of loops.
+#![allow(clippy::needless_continue)]
+
 use darling::{ast, FromDeriveInput, FromMeta, FromVariant};
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
@@ -16,12 +19,11 @@ struct FormActionsDeriveInput {
 struct FormActionsVariant {
     ident: syn::Ident,
     fields: ast::Fields<syn::Field>,
-    #[darling(default)]
-    action: Action,
+    action: Option<Action>,
     text: Option<String>,
 }
 
-#[derive(FromMeta, Default, Debug)]
+#[derive(FromMeta, Default, Clone, Debug)]
 #[darling(default)]
 enum Action {
     #[default]
@@ -45,7 +47,7 @@ pub(crate) fn form_actions_derive(input: TokenStream) -> TokenStream {
     let bundles = variants.iter().map(|variant| {
         let ident = &variant.ident;
         let text = variant.text.clone().unwrap_or_else(|| ident.to_string());
-        match variant.action {
+        match variant.action.clone().unwrap_or_default() {
             Action::Submit => quote! {
                 FormButtonBundle::new(#text).with_role(ButtonRole::Submit).with_form(form)
             },
@@ -55,7 +57,7 @@ pub(crate) fn form_actions_derive(input: TokenStream) -> TokenStream {
             Action::Cancel => quote! {
                 FormButtonBundle::new(#text).with_role(ButtonRole::Cancel).with_form(form)
             },
-            Action::Custom(ref name) => quote! {
+            Action::Custom(name) => quote! {
                 FormButtonBundle::new(#text).with_role(ButtonRole::Custom(stringify!(#name))).with_form(form)
             }
         }
@@ -0,0 +1,73 @@
+// The derives of darling expand to `continue` expressions at the end of loops.
+#![allow(clippy::needless_continue)]
+
+use darling::{ast, FromDeriveInput, FromVariant};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+#[derive(Debug, FromDeriveInput)]
+#[darling(attributes(select_option), supports(enum_unit))]
+struct FormSelectOptionsDeriveInput {
+    ident: syn::Ident,
+    data: ast::Data<FormSelectOptionsVariant, ()>,
+}
+
+#[derive(Debug, FromVariant)]
+#[darling(attributes(select_option))]
+struct FormSelectOptionsVariant {
+    ident: syn::Ident,
+    text: Option<String>,
+}
+
+pub(crate) fn form_select_options_derive(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    let input = match FormSelectOptionsDeriveInput::from_derive_input(&input) {
+        Ok(input) => input,
+        Err(e) => return e.write_errors().into(),
+    };
+
+    let ident = &input.ident;
+    let variants = input.data.take_enum().expect("expected enum");
+
+    let texts = variants.iter().map(|variant| {
+        variant
+            .text
+            .clone()
+            .unwrap_or_else(|| variant.ident.to_string())
+    });
+
+    let from_index = variants.iter().enumerate().map(|(i, variant)| {
+        let variant = &variant.ident;
+        quote! { #i => Some(#ident::#variant) }
+    });
+
+    let index = variants.iter().enumerate().map(|(i, variant)| {
+        let variant = &variant.ident;
+        quote! { #ident::#variant => #i }
+    });
+
+    quote! {
+        impl FormSelectOptions for #ident {
+            fn options() -> Vec<String> {
+                vec![
+                    #(#texts.to_string()),*
+                ]
+            }
+
+            fn from_index(index: usize) -> Option<Self> {
+                match index {
+                    #(#from_index,)*
+                    _ => None,
+                }
+            }
+
+            fn index(&self) -> usize {
+                match self {
+                    #(#index,)*
+                }
+            }
+        }
+    }
+    .into()
+}
@@ -16,7 +16,10 @@
 //! }
 //! ```
 
-use darling::{ast::NestedMeta, Error, FromField, FromMeta};
+// The derives of darling expand to `continue` expressions at the end of loops.
+#![allow(clippy::needless_continue)]
+
+use darling::{ast::NestedMeta, util::Override, Error, FromField, FromMeta};
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::{parse_macro_input, DeriveInput, Ident};
@@ -26,68 +29,1264 @@ struct FormOpts {
     actions: Option<syn::Path>,
     submit: Option<String>,
     cancel: Option<String>,
+    /// Text of a button resetting the fields to their initial values.
+    reset: Option<String>,
+    after_submit: Option<AfterSubmit>,
+    /// Path of a function validating multiple fields on submit. It receives the form data and
+    /// returns the names of the invalid fields with their error messages, e.g.
+    /// `fn check_passwords(data: &Signup) -> Result<(), Vec<(&'static str, String)>>`.
+    /// The fields are marked invalid using the code `"form"` until any field of the form changes.
+    validate: Option<syn::Path>,
+    validation: Option<Validation>,
+    label_position: Option<LabelPosition>,
+    /// Each section header can be clicked to collapse or expand the fields of the section.
+    collapsible_sections: Option<bool>,
+    /// Cancelling the form while it is dirty sends a `FormCancelRequested` event instead.
+    confirm_cancel: Option<bool>,
+    /// Pressing `Enter` moves the focus to the next field. The form is submitted from the last field.
+    enter_advances: Option<bool>,
+}
+
+/// Position of the labels of the fields
+/// - `above`: The label is placed above the field (default)
+/// - `left`: The label is placed left of the field, in a row with it
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// #[form_struct(submit = "Login", label_position = "left")]
+/// # #[derive(Debug, Clone)]
+/// # pub struct LoginData {
+/// #     #[text_box]
+/// #     pub username: String,
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = "snake_case")]
+enum LabelPosition {
+    #[default]
+    Above,
+    Left,
+}
+
+/// Focus behaviour after a successful submit
+/// - `keep`: The focus stays where it is (default)
+/// - `refocus_first`: The first field is focused. Useful for forms used to add many entries in a row.
+/// - `blur`: No field of the form is focused
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// #[form_struct(submit = "Add", after_submit = "refocus_first")]
+/// # #[derive(Debug, Clone)]
+/// # pub struct LoginData {
+/// #     #[text_box]
+/// #     pub username: String,
+/// # }
+/// ```
+#[derive(Debug, FromMeta)]
+#[darling(rename_all = "snake_case")]
+enum AfterSubmit {
+    Keep,
+    RefocusFirst,
+    Blur,
+}
+
+/// When changes of the validity are reported, for the form or a single field
+/// - `on_change`: As soon as the value changes (default)
+/// - `on_blur`: When the field loses focus
+/// - `on_submit`: When the form is submitted
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// #[form_struct(submit = "Login", validation = "on_blur")]
+/// # #[derive(Debug, Clone)]
+/// # pub struct LoginData {
+/// #     #[text_box]
+/// #     pub username: String,
+/// # }
+/// ```
+// Variants are named after the attribute values.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, FromMeta)]
+#[darling(rename_all = "snake_case")]
+enum Validation {
+    OnChange,
+    OnBlur,
+    OnSubmit,
+}
+
+impl Validation {
+    fn validation_mode(self) -> proc_macro2::TokenStream {
+        match self {
+            Validation::OnChange => quote! { ValidationMode::OnChange },
+            Validation::OnBlur => quote! { ValidationMode::OnBlur },
+            Validation::OnSubmit => quote! { ValidationMode::OnSubmit },
+        }
+    }
 }
 
 /// Optional attribute for form fields
 /// - `optional`: Indicates that the field is optional. Field needs to be an `Option<T>`.
-/// - `order`: The order of the field in the form (not implemented)
-/// - `label`: The label of the field (currently defaults to the placeholder)
+///   Non-string values are parsed using `FromStr`. An empty value results in `None`.
+/// - `required`: Indicates that the field is required, which is the default for fields that are not
+///   optional. The form can not be submitted while a required field is empty.
+///   Can not be combined with `optional`.
+/// - `order`: The position of the field in the form. Fields without an order keep their declaration
+///   position, fields with the same position are placed in declaration order. Orders must be unique.
+/// - `label`: The label of the field. Rendered as a text node above the input field.
+/// - `help`: A description of the field. Rendered as a text node below the input field, styled using
+///   the `FormTheme` resource.
+/// - `tooltip`: A tooltip shown while hovering the input field, styled using the `TooltipStyle` resource.
 /// - `active`: Whether the field is the active field. Should only be used once. Behaviour might be unexpected if used multiple times.
+/// - `group`: Consecutive fields of the same group are wrapped in a container titled with the group name.
+/// - `section`: Consecutive fields of the same section are wrapped in a container with a header
+///   showing the section name, styled using the `FormSectionStyle` resource. Sections can contain groups.
+///   With `collapsible_sections` on the form, clicking the header collapses or expands the section.
+/// - `disabled`: The field can not be focused or edited and is exempt from required validation.
+/// - `validation`: When changes of the validity are reported, overriding the form. See `Validation`.
+/// - `nested`: The field is a `form_struct` itself. Its inputs are embedded in a group below the
+///   label and its data is composed when the form is submitted. Can not be optional.
+/// - `skip`: No input is generated for the field. The submitted value is `Default::default()`, or the
+///   given expression when used as `skip = "expr"`.
 ///
 /// ```no_run
-/// #[form_field(optional, order = 1, label = "Username", active)]
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[derive(Debug, Clone, Default)]
+/// # pub struct Uuid;
+/// # impl Uuid {
+/// #     fn new_v4() -> Self {
+/// #         Uuid
+/// #     }
+/// # }
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[form_field(optional, order = 1, label = "Username", active, group = "Account")]
+/// #[text_box]
 /// pub foo: Option<String>,
+/// #[form_field(skip = "Uuid::new_v4()")]
+/// pub id: Uuid,
+/// # }
 /// ```
 #[derive(FromField)]
 #[darling(attributes(form_field))]
 struct FormFieldOpts {
     ident: Option<syn::Ident>,
+    ty: syn::Type,
 
     optional: Option<bool>,
-    _order: Option<usize>,
+    required: Option<bool>,
+    order: Option<usize>,
     label: Option<String>,
+    help: Option<String>,
+    tooltip: Option<String>,
     active: Option<bool>,
+    group: Option<String>,
+    section: Option<String>,
+    disabled: Option<bool>,
+    validation: Option<Validation>,
+    nested: Option<bool>,
+    skip: Option<Override<syn::Expr>>,
 }
 
 impl FormFieldOpts {
-    pub(crate) fn new(ident: syn::Ident) -> Self {
+    pub(crate) fn new(ident: syn::Ident, ty: syn::Type) -> Self {
         Self {
             ident: Some(ident),
+            ty,
             optional: None,
-            _order: None,
+            required: None,
+            order: None,
             label: None,
+            help: None,
+            tooltip: None,
             active: None,
+            group: None,
+            section: None,
+            disabled: None,
+            validation: None,
+            nested: None,
+            skip: None,
+        }
+    }
+}
+
+/// Attribute for `Vec<T>` fields consisting of a variable number of rows. All fields are optional.
+/// The rows are text boxes if the field has a `text_box` attribute, otherwise `T` needs to be a
+/// `form_struct` type. Validators of the field are applied to each text box.
+/// - `min`: The minimal number of rows (default 0)
+/// - `max`: The maximal number of rows (default `RepeatedField::DEFAULT_MAX`, i.e. 10). The rows
+///   reserve that many positions in the focus order, so more rows can not be added, and form data
+///   with more rows is cut off when filled in.
+/// - `add`: The text of the button adding a row (default "+ Add")
+/// - `remove`: The text of the buttons removing a row (default "Remove")
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # mod server {
+/// #     use bevy::prelude::*;
+/// #     use bevy_ui_forms::prelude::*;
+/// #     #[form_struct]
+/// #     #[derive(Debug, Clone)]
+/// #     pub struct Server {
+/// #         #[text_box]
+/// #         pub host: String,
+/// #     }
+/// # }
+/// # use server::Server;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[repeated(max = 5, add = "Add server")]
+/// pub servers: Vec<Server>,
+/// #[repeated]
+/// #[text_box(placeholder = "Tag")]
+/// pub tags: Vec<String>,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(repeated))]
+struct RepeatedOpts {
+    min: Option<usize>,
+    max: Option<usize>,
+    add: Option<String>,
+    remove: Option<String>,
+}
+
+/// Attribute for validating the text of text input based fields. All fields are optional.
+/// The validators are run in the order below whenever the value changes.
+/// - `min_len`: The minimum number of characters
+/// - `max_len`: The maximum number of characters
+/// - `regex`: Regular expression the value needs to match. Requires the `regex` feature. An invalid
+///   pattern is reported when compiling.
+/// - `email`: The value needs to be an email address
+/// - `url`: The value needs to be a URL with a scheme
+/// - `custom`: Path of a function `fn(&str) -> Result<(), String>` returning the error message if
+///   the value is invalid
+/// - `custom_async`: Path of an async function `fn(String) -> Result<(), String>`, run once the
+///   value passes the other validators
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # fn not_taken(_value: &str) -> Result<(), String> {
+/// #     Ok(())
+/// # }
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[text_box]
+/// #[validate(min_len = 3, max_len = 32, regex = "^[a-z]+$", custom = "not_taken")]
+/// pub username: String,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(validate))]
+struct ValidateOpts {
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    regex: Option<Regex>,
+    email: Option<bool>,
+    url: Option<bool>,
+    custom: Option<syn::Path>,
+    custom_async: Option<syn::Path>,
+}
+
+/// A regular expression, compiled when the macro is expanded to report invalid patterns.
+#[derive(Clone, Debug)]
+struct Regex(syn::LitStr);
+
+impl FromMeta for Regex {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        let syn::Lit::Str(lit) = value else {
+            return Err(Error::unexpected_lit_type(value));
+        };
+        regex::Regex::new(&lit.value()).map_err(|e| Error::custom(e).with_span(lit))?;
+        Ok(Regex(lit.clone()))
+    }
+}
+
+impl ValidateOpts {
+    fn validators(&self) -> proc_macro2::TokenStream {
+        let min_len = self
+            .min_len
+            .map(|min_len| quote! { FormValidator::MinLen(#min_len), });
+        let max_len = self
+            .max_len
+            .map(|max_len| quote! { FormValidator::MaxLen(#max_len), });
+        let regex = self.regex.as_ref().map(|Regex(regex)| {
+            quote! { FormValidator::regex(#regex).expect("regex checked by the form_struct macro"), }
+        });
+        let email = self
+            .email
+            .filter(|email| *email)
+            .map(|_| quote! { FormValidator::Email, });
+        let url = self
+            .url
+            .filter(|url| *url)
+            .map(|_| quote! { FormValidator::Url, });
+        let custom = self
+            .custom
+            .as_ref()
+            .map(|custom| quote! { FormValidator::Custom(#custom), });
+
+        let custom_async = self
+            .custom_async
+            .as_ref()
+            .map(|custom_async| quote! { FormAsyncValidator::new(#custom_async), });
+
+        quote! {
+            (FormValidators(vec![#min_len #max_len #regex #email #url #custom]), #custom_async)
         }
     }
 }
 
 /// Required attribute for text box fields. All fields are optional.
+///
+/// The field can be of any type implementing `FromStr` and `Display`. The text is parsed on submit,
+/// the input is marked invalid if parsing fails.
 /// - `placeholder`: The placeholder text for the text box
+/// - `placeholder_style`: The text style of the placeholder. If not provided the text style is used
+///   with quarter opacity.
+/// - `floating_label`: Move the placeholder above the value once the text box is focused or filled
 /// - `mask`: The mask character for the text box
-/// - `text_style`: The text style for the text box. If not provided uses the `FormInputTextStyle` resource.
+/// - `text_style`: The text style for the text box. If not provided uses the `FormTheme` resource.
 /// - `default_value`: The default value for the text box
+/// - `retain`: Whether the value is kept after the form is submitted. Defaults to `true`.
+/// - `max_length`: The maximum number of characters that can be entered
+/// - `filter`: The characters that can be entered, see [`Filter`]
+/// - `format_mask`: Formats the value while typing, e.g. `"###-####"`. `#` is a digit, `A` a letter
+///   and `*` a letter or digit, other characters are inserted automatically. The submitted value
+///   does not contain the inserted characters.
+/// - `transform`: Normalizes the submitted value, see [`Transform`]
+/// - `transform_live`: Whether the `transform` is also applied while typing. Defaults to `false`.
+/// - `align`: The alignment of the text, `left` (default), `center` or `right`
+/// - `clearable`: Render a button emptying the text box at its right edge
+/// - `debounce_ms`: Only send `TextInputChanged` once the value did not change for the milliseconds
 ///
 /// ```no_run
-/// #[text_box(placeholder = "Password", mask = '*', text_style = TextStyle { font_size: 22.0, color: Color::Black, ..default() })]
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[text_box(placeholder = "Password", mask = '*', text_style = TextStyle { font_size: 22.0, color: Color::BLACK, ..default() })]
 /// pub password: String,
+/// # }
 /// ```
 #[derive(FromField, Clone, Debug)]
 #[darling(attributes(text_box))]
 struct TextBoxOpts {
-    ident: Option<syn::Ident>,
     placeholder: Option<String>,
+    placeholder_style: Option<syn::Expr>,
+    floating_label: Option<bool>,
     mask: Option<char>,
     text_style: Option<syn::Expr>,
     default_value: Option<String>,
+    retain: Option<bool>,
+    max_length: Option<usize>,
+    filter: Option<Filter>,
+    format_mask: Option<String>,
+    transform: Option<Transform>,
+    transform_live: Option<bool>,
+    align: Option<Align>,
+    clearable: Option<bool>,
+    debounce_ms: Option<u64>,
+}
+
+/// Horizontal alignment of the text of a text box
+#[derive(Debug, Clone, Copy, FromMeta)]
+#[darling(rename_all = "snake_case")]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+impl Align {
+    fn text_input_alignment(self) -> proc_macro2::TokenStream {
+        match self {
+            Align::Left => quote! { TextInputAlignment::Left },
+            Align::Center => quote! { TextInputAlignment::Center },
+            Align::Right => quote! { TextInputAlignment::Right },
+        }
+    }
+}
+
+/// Characters that can be entered into a text box
+/// - `digits`: Only digits
+/// - `integer`: Digits and a leading minus sign
+/// - `decimal`: Digits, a leading minus sign and a `.` as decimal separator
+/// - `alphabetic`: Only letters
+/// - `alphanumeric`: Only letters and digits
+/// - `identifier`: Letters, digits and underscores, but no leading digit
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[text_box(filter = "identifier")]
+/// pub username: String,
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, FromMeta)]
+#[darling(rename_all = "snake_case")]
+enum Filter {
+    Digits,
+    Integer,
+    Decimal,
+    Alphabetic,
+    Alphanumeric,
+    Identifier,
+}
+
+impl Filter {
+    fn text_input_filter(self) -> proc_macro2::TokenStream {
+        match self {
+            Filter::Digits => quote! { TextInputFilter::Digits },
+            Filter::Integer => quote! { TextInputFilter::Integer { negative: true } },
+            Filter::Decimal => quote! {
+                TextInputFilter::Decimal {
+                    separator: '.',
+                    negative: true,
+                }
+            },
+            Filter::Alphabetic => quote! { TextInputFilter::Alphabetic },
+            Filter::Alphanumeric => quote! { TextInputFilter::Alphanumeric },
+            Filter::Identifier => quote! { TextInputFilter::Identifier },
+        }
+    }
+}
+
+/// Comma separated transforms of the value of a text box
+/// - `trim`: Removes leading and trailing whitespace
+/// - `lowercase`: Converts all letters to lowercase
+/// - `uppercase`: Converts all letters to uppercase
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[text_box(transform = "trim,lowercase")]
+/// pub email: String,
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+struct Transform {
+    trim: bool,
+    lowercase: bool,
+    uppercase: bool,
+}
+
+impl FromMeta for Transform {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        let mut transform = Transform::default();
+        for name in value.split(',').map(str::trim) {
+            match name {
+                "trim" => transform.trim = true,
+                "lowercase" => transform.lowercase = true,
+                "uppercase" => transform.uppercase = true,
+                _ => return Err(Error::unknown_value(name)),
+            }
+        }
+
+        if transform.lowercase && transform.uppercase {
+            return Err(Error::custom(
+                "`lowercase` and `uppercase` can not be combined",
+            ));
+        }
+
+        Ok(transform)
+    }
+}
+
+impl Transform {
+    fn text_input_transform(self, live: bool) -> proc_macro2::TokenStream {
+        let trim = self.trim;
+        let case = if self.lowercase {
+            quote! { Some(TextCase::Lowercase) }
+        } else if self.uppercase {
+            quote! { Some(TextCase::Uppercase) }
+        } else {
+            quote! { None }
+        };
+
+        quote! {
+            TextInputTransform {
+                trim: #trim,
+                case: #case,
+                live: #live,
+            }
+        }
+    }
+}
+
+/// Attribute for text fields with suggestions. The field needs to be a `String`.
+/// - `source`: Expression evaluating to the suggestions, any iterator of strings
+/// - `placeholder`, `text_style`, `default_value` and `retain`: Same as for `text_box`
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[autocomplete(source = ["Apple", "Banana", "Cherry"])]
+/// pub fruit: String,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(autocomplete))]
+struct AutocompleteOpts {
+    source: syn::Expr,
+    placeholder: Option<String>,
+    text_style: Option<syn::Expr>,
+    default_value: Option<String>,
+    retain: Option<bool>,
+}
+
+impl AutocompleteOpts {
+    fn text_box_opts(&self) -> TextBoxOpts {
+        TextBoxOpts {
+            placeholder: self.placeholder.clone(),
+            placeholder_style: None,
+            floating_label: None,
+            mask: None,
+            text_style: self.text_style.clone(),
+            default_value: self.default_value.clone(),
+            retain: self.retain,
+            max_length: None,
+            filter: None,
+            format_mask: None,
+            transform: None,
+            transform_live: None,
+            clearable: None,
+            debounce_ms: None,
+            align: None,
+        }
+    }
+}
+
+/// Attribute for multi-line text fields. The field needs to be a `String`.
+/// - `rows`: The number of visible lines. Defaults to `3`.
+/// - `max_lines`: The maximum number of lines that can be entered
+/// - `placeholder`, `text_style`, `default_value`, `retain` and `max_length`: Same as for `text_box`
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[text_area(rows = 5)]
+/// pub description: String,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(text_area))]
+struct TextAreaOpts {
+    rows: Option<usize>,
+    max_lines: Option<usize>,
+    placeholder: Option<String>,
+    text_style: Option<syn::Expr>,
+    default_value: Option<String>,
+    retain: Option<bool>,
+    max_length: Option<usize>,
+}
+
+impl TextAreaOpts {
+    fn text_box_opts(&self) -> TextBoxOpts {
+        TextBoxOpts {
+            placeholder: self.placeholder.clone(),
+            placeholder_style: None,
+            floating_label: None,
+            mask: None,
+            text_style: self.text_style.clone(),
+            default_value: self.default_value.clone(),
+            retain: self.retain,
+            max_length: self.max_length,
+            filter: None,
+            format_mask: None,
+            transform: None,
+            transform_live: None,
+            clearable: None,
+            debounce_ms: None,
+            align: None,
+        }
+    }
+
+    fn text_area_settings(&self) -> proc_macro2::TokenStream {
+        let rows = self.rows.unwrap_or(3);
+        let max_lines = self
+            .max_lines
+            .map_or(quote! { None }, |max_lines| quote! { Some(#max_lines) });
+
+        quote! {
+            TextAreaSettings {
+                rows: #rows,
+                max_lines: #max_lines,
+            }
+        }
+    }
+}
+
+/// Attribute for currency fields. Integer fields resolve to minor units (e.g. cents), float fields to the value.
+/// - `symbol`: The currency symbol
+/// - `decimals`: The number of decimal places. Defaults to `2`.
+/// - `suffix`: Render the symbol after the value instead of in front of it
+/// - `negative`: Allow negative values
+/// - `decimal_separator`: The character separating the decimal places. Defaults to `.`.
+/// - `grouping`: The character used to group thousands. Defaults to `,`.
+/// - `placeholder`, `text_style`, `default_value` and `align`: Same as for `text_box`
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[currency_box(symbol = "€", suffix, decimal_separator = ',', grouping = '.')]
+/// pub price_cents: u64,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(currency_box))]
+struct CurrencyBoxOpts {
+    symbol: String,
+    decimals: Option<usize>,
+    suffix: Option<bool>,
+    negative: Option<bool>,
+    decimal_separator: Option<char>,
+    grouping: Option<char>,
+    placeholder: Option<String>,
+    text_style: Option<syn::Expr>,
+    default_value: Option<String>,
+    align: Option<Align>,
+}
+
+impl CurrencyBoxOpts {
+    fn decimals(&self) -> usize {
+        self.decimals.unwrap_or(2)
+    }
+
+    fn text_box_opts(&self) -> TextBoxOpts {
+        TextBoxOpts {
+            placeholder: self.placeholder.clone(),
+            placeholder_style: None,
+            floating_label: None,
+            mask: None,
+            text_style: self.text_style.clone(),
+            default_value: self.default_value.clone(),
+            retain: None,
+            max_length: None,
+            filter: None,
+            format_mask: None,
+            transform: None,
+            transform_live: None,
+            clearable: None,
+            debounce_ms: None,
+            align: self.align,
+        }
+    }
+
+    fn number_input_settings(&self) -> proc_macro2::TokenStream {
+        let symbol = &self.symbol;
+        let (prefix, suffix) = if self.suffix.unwrap_or(false) {
+            (quote! { None }, quote! { Some(#symbol.to_string()) })
+        } else {
+            (quote! { Some(#symbol.to_string()) }, quote! { None })
+        };
+        let min = if self.negative.unwrap_or(false) {
+            quote! { None }
+        } else {
+            quote! { Some(0.0) }
+        };
+        let decimals = self.decimals();
+        let decimal_separator = self.decimal_separator.unwrap_or('.');
+        let grouping = self.grouping.unwrap_or(',');
+
+        quote! {
+            NumberInputSettings {
+                min: #min,
+                grouping: Some(#grouping),
+                decimal_separator: #decimal_separator,
+                decimals: Some(#decimals),
+                prefix: #prefix,
+                suffix: #suffix,
+                ..default()
+            }
+        }
+    }
+}
+
+/// Attribute for percentage fields. The value is shown with a trailing `%` and clamped to the range.
+/// - `min`: The minimum value. Defaults to `0`.
+/// - `max`: The maximum value. Defaults to `100`.
+/// - `decimals`: The number of decimal places
+/// - `fraction`: Resolve to a fraction (`0.0` - `1.0`) instead of the percentage (`0` - `100`)
+/// - `placeholder`, `text_style`, `default_value` and `align`: Same as for `text_box`
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[percent_box(max = 50, fraction)]
+/// pub discount: f32,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(percent_box))]
+struct PercentBoxOpts {
+    min: Option<Number>,
+    max: Option<Number>,
+    decimals: Option<usize>,
+    fraction: Option<bool>,
+    placeholder: Option<String>,
+    text_style: Option<syn::Expr>,
+    default_value: Option<String>,
+    align: Option<Align>,
+}
+
+impl PercentBoxOpts {
+    fn text_box_opts(&self) -> TextBoxOpts {
+        TextBoxOpts {
+            placeholder: self.placeholder.clone(),
+            placeholder_style: None,
+            floating_label: None,
+            mask: None,
+            text_style: self.text_style.clone(),
+            default_value: self.default_value.clone(),
+            retain: None,
+            max_length: None,
+            filter: None,
+            format_mask: None,
+            transform: None,
+            transform_live: None,
+            clearable: None,
+            debounce_ms: None,
+            align: self.align,
+        }
+    }
+
+    fn number_input_settings(&self) -> proc_macro2::TokenStream {
+        let min = self.min.map_or(0.0, |min| min.0);
+        let max = self.max.map_or(100.0, |max| max.0);
+        let decimals = self
+            .decimals
+            .map_or(quote! { None }, |decimals| quote! { Some(#decimals) });
+
+        quote! {
+            NumberInputSettings {
+                min: Some(#min),
+                max: Some(#max),
+                decimals: #decimals,
+                ..NumberInputSettings::percent()
+            }
+        }
+    }
+}
+
+/// Attribute for numeric fields. The field needs to be an integer, `f32` or `f64`.
+/// Only numbers can be typed, values that can not be parsed as the type of the field mark the field invalid.
+/// - `min`: The minimum value. Values are clamped to this when the input loses focus.
+/// - `max`: The maximum value. Values are clamped to this when the input loses focus.
+/// - `step`: The amount the value changes by when stepping with the arrow keys. Defaults to `1`.
+/// - `spinner`: Render increment and decrement buttons beside the input
+/// - `placeholder`, `text_style`, `default_value` and `align`: Same as for `text_box`
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[number_input(min = 0, max = 120)]
+/// pub age: u32,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(number_input))]
+struct NumberInputOpts {
+    min: Option<Number>,
+    max: Option<Number>,
+    step: Option<Number>,
+    spinner: Option<bool>,
+    placeholder: Option<String>,
+    text_style: Option<syn::Expr>,
+    default_value: Option<String>,
+    align: Option<Align>,
+}
+
+impl NumberInputOpts {
+    fn text_box_opts(&self) -> TextBoxOpts {
+        TextBoxOpts {
+            placeholder: self.placeholder.clone(),
+            placeholder_style: None,
+            floating_label: None,
+            mask: None,
+            text_style: self.text_style.clone(),
+            default_value: self.default_value.clone(),
+            retain: None,
+            max_length: None,
+            filter: None,
+            format_mask: None,
+            transform: None,
+            transform_live: None,
+            clearable: None,
+            debounce_ms: None,
+            align: self.align,
+        }
+    }
+
+    fn number_input_settings(&self) -> proc_macro2::TokenStream {
+        let min = self.min.map_or(quote! { None }, |min| {
+            let min = min.0;
+            quote! { Some(#min) }
+        });
+        let max = self.max.map_or(quote! { None }, |max| {
+            let max = max.0;
+            quote! { Some(#max) }
+        });
+        let step = self.step.map_or(1.0, |step| step.0);
+        let spinner = self.spinner.unwrap_or(false);
+
+        quote! {
+            NumberInputSettings {
+                min: #min,
+                max: #max,
+                step: #step,
+                spinner: #spinner,
+                ..default()
+            }
+        }
+    }
+
+    /// Integer fields only accept digits, and a minus sign if the type and range allow negative values.
+    fn filter(&self, ty: &syn::Type) -> proc_macro2::TokenStream {
+        if is_integer(ty) {
+            let negative = self.min.map_or_else(|| !is_unsigned(ty), |min| min.0 < 0.0);
+            quote! { TextInputFilter::Integer { negative: #negative } }
+        } else {
+            let settings = self.number_input_settings();
+            quote! { #settings.filter() }
+        }
+    }
+}
+
+/// Attribute for date fields. The field needs to be a `chrono::NaiveDate`. Requires the `date` feature.
+/// - `format`: The `chrono` format of the date. Defaults to `%Y-%m-%d`.
+/// - `min`: The earliest valid date, in the given format
+/// - `max`: The latest valid date, in the given format
+/// - `placeholder`, `text_style` and `default_value`: Same as for `text_box`
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # use bevy_ui_forms::chrono::NaiveDate;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[date_box(format = "%d.%m.%Y", min = "01.01.1900")]
+/// pub birthday: NaiveDate,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(date_box))]
+struct DateBoxOpts {
+    format: Option<String>,
+    min: Option<String>,
+    max: Option<String>,
+    placeholder: Option<String>,
+    text_style: Option<syn::Expr>,
+    default_value: Option<String>,
+}
+
+impl DateBoxOpts {
+    fn text_box_opts(&self) -> TextBoxOpts {
+        TextBoxOpts {
+            placeholder: self.placeholder.clone(),
+            placeholder_style: None,
+            floating_label: None,
+            mask: None,
+            text_style: self.text_style.clone(),
+            default_value: self.default_value.clone(),
+            retain: None,
+            max_length: None,
+            filter: None,
+            format_mask: None,
+            transform: None,
+            transform_live: None,
+            clearable: None,
+            debounce_ms: None,
+            align: None,
+        }
+    }
+
+    fn date_input_settings(&self) -> proc_macro2::TokenStream {
+        let format = self.format.as_deref().unwrap_or("%Y-%m-%d");
+        let min = self
+            .min
+            .as_ref()
+            .map(|min| quote! { .with_min(#min) })
+            .unwrap_or_default();
+        let max = self
+            .max
+            .as_ref()
+            .map(|max| quote! { .with_max(#max) })
+            .unwrap_or_default();
+
+        quote! {
+            DateInputSettings::new(#format) #min #max
+        }
+    }
+}
+
+/// Attribute for date fields with a popup calendar. The field needs to be a `chrono::NaiveDate`.
+/// Requires the `date` feature. Takes the same options as `date_box`.
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # use bevy_ui_forms::chrono::NaiveDate;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[date(format = "%Y-%m-%d")]
+/// pub due: NaiveDate,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(date))]
+struct DatePickerOpts {
+    format: Option<String>,
+    min: Option<String>,
+    max: Option<String>,
+    placeholder: Option<String>,
+    text_style: Option<syn::Expr>,
+    default_value: Option<String>,
+}
+
+impl From<DatePickerOpts> for DateBoxOpts {
+    fn from(opts: DatePickerOpts) -> Self {
+        DateBoxOpts {
+            format: opts.format,
+            min: opts.min,
+            max: opts.max,
+            placeholder: opts.placeholder,
+            text_style: opts.text_style,
+            default_value: opts.default_value,
+        }
+    }
+}
+
+/// Attribute for select fields. The field needs to implement `FormSelectOptions`, e.g. an enum using
+/// `#[derive(FormSelectOptions)]`.
+/// - `placeholder`: The text displayed while no option is chosen
+/// - `text_style`: Same as for `text_box`
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[derive(FormSelectOptions, Debug, Clone, Copy)]
+/// # pub enum Country {
+/// #     Germany,
+/// #     France,
+/// # }
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[select(placeholder = "Country")]
+/// pub country: Country,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(select))]
+struct SelectOpts {
+    placeholder: Option<String>,
+    text_style: Option<syn::Expr>,
+}
+
+/// Attribute for tag fields. The field needs to be a `Vec<String>`. Every entered text becomes a
+/// tag, required fields need at least one tag.
+/// - `placeholder` and `text_style`: Same as for `text_box`
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[tags(placeholder = "Add tag")]
+/// pub tags: Vec<String>,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(tags))]
+struct TagsOpts {
+    placeholder: Option<String>,
+    text_style: Option<syn::Expr>,
+}
+
+impl TagsOpts {
+    fn text_box_opts(&self) -> TextBoxOpts {
+        TextBoxOpts {
+            placeholder: self.placeholder.clone(),
+            placeholder_style: None,
+            floating_label: None,
+            mask: None,
+            text_style: self.text_style.clone(),
+            default_value: None,
+            retain: None,
+            max_length: None,
+            filter: None,
+            format_mask: None,
+            transform: None,
+            transform_live: None,
+            clearable: None,
+            debounce_ms: None,
+            align: None,
+        }
+    }
+}
+
+/// Attribute for slider fields. The field needs to be a `f32`, `f64` or an integer.
+/// - `min`: The minimum value. Defaults to `0`.
+/// - `max`: The maximum value. Defaults to `1`.
+/// - `step`: The amount the value changes by with each step. Defaults to `0.1`.
+/// - `default_value`: The initial value. Defaults to `min`.
+/// - `text_style`: Same as for `text_box`
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[slider(min = 0.0, max = 1.0, step = 0.05)]
+/// pub volume: f32,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(slider))]
+struct SliderOpts {
+    min: Option<Number>,
+    max: Option<Number>,
+    step: Option<Number>,
+    default_value: Option<Number>,
+    text_style: Option<syn::Expr>,
 }
 
+impl SliderOpts {
+    fn slider_settings(&self) -> proc_macro2::TokenStream {
+        let min = self.min.map_or(0.0, |min| min.0);
+        let max = self.max.map_or(1.0, |max| max.0);
+        let step = self.step.map_or(0.1, |step| step.0);
+
+        quote! {
+            SliderSettings {
+                min: #min,
+                max: #max,
+                step: #step,
+            }
+        }
+    }
+}
+
+/// Attribute for color fields. The field needs to be a `Color`.
+/// - `default`: The initial color. Defaults to `Color::WHITE`.
+/// - `text_style`: Same as for `text_box`
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_ui_forms::prelude::*;
+/// # #[form_struct]
+/// # #[derive(Debug, Clone)]
+/// # pub struct Data {
+/// #[color_picker(default = Color::ORANGE)]
+/// pub accent: Color,
+/// # }
+/// ```
+#[derive(FromField, Clone, Debug)]
+#[darling(attributes(color_picker))]
+struct ColorPickerOpts {
+    default: Option<syn::Expr>,
+    text_style: Option<syn::Expr>,
+}
+
+/// A number literal that may be written as an integer or a float.
+#[derive(Clone, Copy, Debug)]
+struct Number(f64);
+
+impl FromMeta for Number {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        match value {
+            syn::Lit::Int(lit) => Ok(Number(lit.base10_parse()?)),
+            syn::Lit::Float(lit) => Ok(Number(lit.base10_parse()?)),
+            _ => Err(Error::unexpected_lit_type(value)),
+        }
+    }
+}
+
+#[allow(clippy::struct_field_names)]
 struct FormField {
     form_field_opts: FormFieldOpts,
     field_specific_opts: FormFieldType,
+    validate_opts: Option<ValidateOpts>,
+}
+
+impl FormField {
+    fn is_nested(&self) -> bool {
+        matches!(self.field_specific_opts, FormFieldType::Nested)
+    }
+
+    /// Whether the field is a single form element, as opposed to nested or repeated fields.
+    fn is_element(&self) -> bool {
+        !matches!(
+            self.field_specific_opts,
+            FormFieldType::Nested | FormFieldType::Repeated(..)
+        )
+    }
+
+    /// The number of positions the field takes up in the focus order.
+    fn element_count(&self) -> proc_macro2::TokenStream {
+        let ty = &self.form_field_opts.ty;
+        match &self.field_specific_opts {
+            FormFieldType::Nested => quote! { <#ty as NestedForm>::ELEMENT_COUNT },
+            FormFieldType::Repeated(repeated_opts, text_box_opts) => {
+                let max = repeated_opts
+                    .max
+                    .map_or(quote! { RepeatedField::DEFAULT_MAX }, |max| quote! { #max });
+                let row_elements = if text_box_opts.is_some() {
+                    quote! { 1 }
+                } else {
+                    let row_ty = vec_inner_type(ty).unwrap_or(ty);
+                    quote! { <#row_ty as NestedForm>::ELEMENT_COUNT }
+                };
+                quote! { #max * #row_elements }
+            }
+            _ => quote! { 1 },
+        }
+    }
+
+    /// The identifier of the node placed in the layout of the form.
+    fn node_ident(&self) -> Ident {
+        let field_name = self.form_field_opts.ident.as_ref().unwrap();
+        if self.is_nested() {
+            format_ident!("{}_nested", field_name)
+        } else {
+            format_ident!("{}_input", field_name)
+        }
+    }
 }
 
+// Variants are named after their attributes.
+#[allow(clippy::enum_variant_names)]
 enum FormFieldType {
     TextBox(TextBoxOpts),
+    TextArea(TextAreaOpts),
+    Autocomplete(AutocompleteOpts),
+    CurrencyBox(CurrencyBoxOpts),
+    PercentBox(PercentBoxOpts),
+    NumberInput(NumberInputOpts),
+    DateBox(DateBoxOpts),
+    DatePicker(DateBoxOpts),
+    Select(SelectOpts),
+    Tags(TagsOpts),
+    Slider(SliderOpts),
+    ColorPicker(ColorPickerOpts),
+    Nested,
+    /// Rows of text boxes, or of a nested form without text box options.
+    Repeated(RepeatedOpts, Option<TextBoxOpts>),
+}
+
+/// The attributes selecting the input of a field.
+const INPUT_ATTRIBUTES: [&str; 12] = [
+    "text_box",
+    "text_area",
+    "autocomplete",
+    "currency_box",
+    "percent_box",
+    "number_input",
+    "date_box",
+    "date",
+    "select",
+    "tags",
+    "slider",
+    "color_picker",
+];
+
+/// Parses the options of the attribute if the field has it.
+fn parse_attr<T: FromField>(field: &syn::Field, attr: &str) -> darling::Result<Option<T>> {
+    if field.attrs.iter().any(|a| a.path().is_ident(attr)) {
+        T::from_field(field).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Parses the input of the field, selected by the first of its [`INPUT_ATTRIBUTES`].
+/// Returns `None` if the field has no input.
+fn parse_form_field(
+    field: &syn::Field,
+    form_field_opts: FormFieldOpts,
+) -> darling::Result<Option<FormField>> {
+    let specific_opts = if let Some(text_box) = parse_attr(field, "text_box")? {
+        Some(FormFieldType::TextBox(text_box))
+    } else if let Some(text_area) = parse_attr(field, "text_area")? {
+        Some(FormFieldType::TextArea(text_area))
+    } else if let Some(autocomplete) = parse_attr(field, "autocomplete")? {
+        Some(FormFieldType::Autocomplete(autocomplete))
+    } else if let Some(currency_box) = parse_attr(field, "currency_box")? {
+        Some(FormFieldType::CurrencyBox(currency_box))
+    } else if let Some(percent_box) = parse_attr(field, "percent_box")? {
+        Some(FormFieldType::PercentBox(percent_box))
+    } else if let Some(number_input) = parse_attr(field, "number_input")? {
+        Some(FormFieldType::NumberInput(number_input))
+    } else if let Some(date_box) = parse_attr(field, "date_box")? {
+        Some(FormFieldType::DateBox(date_box))
+    } else if let Some(date_picker) = parse_attr::<DatePickerOpts>(field, "date")? {
+        Some(FormFieldType::DatePicker(date_picker.into()))
+    } else if let Some(select) = parse_attr(field, "select")? {
+        Some(FormFieldType::Select(select))
+    } else if let Some(tags) = parse_attr(field, "tags")? {
+        Some(FormFieldType::Tags(tags))
+    } else if let Some(slider) = parse_attr(field, "slider")? {
+        Some(FormFieldType::Slider(slider))
+    } else if let Some(color_picker) = parse_attr(field, "color_picker")? {
+        Some(FormFieldType::ColorPicker(color_picker))
+    } else if form_field_opts.nested == Some(true) {
+        Some(FormFieldType::Nested)
+    } else {
+        None
+    };
+
+    let specific_opts = match parse_attr(field, "repeated")? {
+        Some(repeated) => match specific_opts {
+            Some(FormFieldType::TextBox(text_box)) => {
+                Some(FormFieldType::Repeated(repeated, Some(text_box)))
+            }
+            _ => Some(FormFieldType::Repeated(repeated, None)),
+        },
+        None => specific_opts,
+    };
+
+    let validate_opts = parse_attr(field, "validate")?;
+
+    Ok(specific_opts.map(|s| FormField {
+        form_field_opts,
+        field_specific_opts: s,
+        validate_opts,
+    }))
 }
 
 struct FormIdentifiers {
@@ -95,7 +1294,7 @@ struct FormIdentifiers {
     marker_form_element: Ident,
     plugin: Ident,
     event: Ident,
-    entity_resource: Ident,
+    fields_component: Ident,
 }
 
 /// Proc macro for generating a form plugin
@@ -105,6 +1304,7 @@ struct FormIdentifiers {
 /// - If the annotated element is not a struct
 /// - If any field is not public
 /// - If any field does not have an associated input field
+#[allow(clippy::too_many_lines)]
 pub(crate) fn form_struct(args: TokenStream, input: &TokenStream) -> TokenStream {
     let parse_input = input.clone();
     let args = match NestedMeta::parse_meta_list(args.into()) {
@@ -129,40 +1329,75 @@ pub(crate) fn form_struct(args: TokenStream, input: &TokenStream) -> TokenStream
         );
     }
 
-    let form_field_opts = fields
-        .iter()
-        .map(|f| {
-            FormFieldOpts::from_field(f).unwrap_or(FormFieldOpts::new(f.ident.clone().unwrap()))
-        })
-        .collect::<Vec<_>>();
+    if let Some(field) = fields.iter().find(|f| {
+        f.attrs.iter().any(|a| a.path().is_ident("repeated"))
+            && (vec_inner_type(&f.ty).is_none()
+                || f.attrs.iter().any(|a| {
+                    INPUT_ATTRIBUTES
+                        .iter()
+                        .any(|input| *input != "text_box" && a.path().is_ident(input))
+                }))
+    }) {
+        return TokenStream::from(
+            Error::custom("Repeated fields need to be a `Vec` of text boxes or form structs")
+                .with_span(&field.ident)
+                .write_errors(),
+        );
+    }
 
-    let text_box_field_opts = fields
-        .iter()
-        .filter(|f| f.attrs.iter().any(|a| a.path().is_ident("text_box")))
-        .filter_map(|f| TextBoxOpts::from_field(f).ok())
-        .collect::<Vec<_>>();
+    let mut errors = Error::accumulator();
+    let mut form_fields = Vec::new();
+    let mut skipped_fields = Vec::new();
+    for field in fields {
+        let Some(form_field_opts) = errors.handle(FormFieldOpts::from_field(field)) else {
+            continue;
+        };
+        if form_field_opts.skip.is_some() {
+            skipped_fields.push(form_field_opts);
+            continue;
+        }
 
-    let form_fields = form_field_opts
-        .into_iter()
-        .filter_map(|f| {
-            let specific_opts = text_box_field_opts
-                .iter()
-                .find(|t| t.ident == f.ident)
-                .map(|text_box| FormFieldType::TextBox(text_box.clone()));
-
-            specific_opts.map(|s| FormField {
-                form_field_opts: f,
-                field_specific_opts: s,
-            })
-        })
-        .collect::<Vec<_>>();
+        match errors.handle(parse_form_field(field, form_field_opts)) {
+            Some(Some(form_field)) => form_fields.push(form_field),
+            Some(None) => errors.push(
+                Error::custom("All fields must have an associated input field")
+                    .with_span(&field.ident),
+            ),
+            None => {}
+        }
+    }
+    if let Err(e) = errors.finish() {
+        return TokenStream::from(e.write_errors());
+    }
+
+    if let Some(field) = form_fields.iter().find(|f| {
+        f.form_field_opts.optional.unwrap_or(false) && f.form_field_opts.required.unwrap_or(false)
+    }) {
+        let ident = field.form_field_opts.ident.as_ref().unwrap();
+        return TokenStream::from(
+            Error::custom("A field can not be both optional and required")
+                .with_span(ident)
+                .write_errors(),
+        );
+    }
 
-    if form_fields.len() != fields.len() {
+    if let Some(field) = form_fields
+        .iter()
+        .find(|f| f.is_nested() && f.form_field_opts.optional.unwrap_or(false))
+    {
+        let ident = field.form_field_opts.ident.as_ref().unwrap();
         return TokenStream::from(
-            Error::missing_field("All fields must have an associated input field").write_errors(),
+            Error::custom("A nested field can not be optional")
+                .with_span(ident)
+                .write_errors(),
         );
     }
 
+    let form_fields = match order_fields(form_fields) {
+        Ok(form_fields) => form_fields,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+
     let DeriveInput { ident, attrs, .. } = inputs;
 
     let form_identifiers = FormIdentifiers {
@@ -170,24 +1405,35 @@ pub(crate) fn form_struct(args: TokenStream, input: &TokenStream) -> TokenStream
         marker_form_element: format_ident!("{}FormElement", ident),
         plugin: format_ident!("{}FormPlugin", ident),
         event: format_ident!("{}FormEvent", ident),
-        entity_resource: format_ident!("{}FormFields", ident),
+        fields_component: format_ident!("{}FormFields", ident),
     };
 
     let plugin = generate_plugin(&ident, &args, &form_fields, &form_identifiers);
-    let setup = generate_setup(
+    let setup = generate_setup(&ident, &args, &form_identifiers.marker_component);
+    let nested_form = generate_nested_form(
         &ident,
-        &args,
         &form_fields,
-        &form_identifiers.marker_component,
+        &skipped_fields,
+        &args,
+        &form_identifiers.fields_component,
     );
     let submit = generate_submit_system(&ident, &form_fields, &args, &form_identifiers);
+    let set_form_data = generate_set_form_data(&ident, &form_fields, &form_identifiers);
 
     let field_definitions = fields
         .iter()
         .map(|f| {
             let ident = f.ident.as_ref().unwrap();
             let ty = &f.ty;
+            // Keep doc comments and the attributes of other macros.
+            let attrs = f.attrs.iter().filter(|a| {
+                !["form_field", "validate", "repeated"]
+                    .iter()
+                    .chain(INPUT_ATTRIBUTES.iter())
+                    .any(|attr| a.path().is_ident(attr))
+            });
             quote! {
+                #( #attrs )*
                 pub #ident: #ty,
             }
         })
@@ -200,7 +1446,9 @@ pub(crate) fn form_struct(args: TokenStream, input: &TokenStream) -> TokenStream
         }
         #plugin
         #setup
+        #nested_form
         #submit
+        #set_form_data
     }
     .into()
 }
@@ -216,6 +1464,17 @@ fn generate_plugin(
         .iter()
         .map(|o| format_ident!("{}_input", o.form_field_opts.ident.as_ref().unwrap()))
         .collect::<Vec<_>>();
+    let input_field_types = fields
+        .iter()
+        .map(|o| {
+            if o.is_nested() {
+                let ty = &o.form_field_opts.ty;
+                quote! { <#ty as NestedForm>::Fields }
+            } else {
+                quote! { Entity }
+            }
+        })
+        .collect::<Vec<_>>();
     let action_event = opts.actions.as_ref().map_or(quote! {}, |actions| {
         quote! {
             .add_event::<#actions>()
@@ -226,9 +1485,28 @@ fn generate_plugin(
         marker_form_element,
         plugin,
         event,
-        entity_resource,
+        fields_component,
     } = form_identifiers;
 
+    let (form_validation_system, clear_form_errors) = if opts.validate.is_some() {
+        (
+            quote! { .add_systems(Update, clear_form_errors) },
+            generate_clear_form_errors(fields, fields_component),
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
+
+    let marker_component_doc =
+        format!("Marker component spawning a [`{name}`] form on the entity.");
+    let marker_form_element_doc =
+        format!("Marker component for the elements of a [`{name}`] form.");
+    let fields_component_doc =
+        format!("The inputs of the fields of a [`{name}`] form, stored on the form entity.");
+    let event_doc = format!(
+        "Event sent when a [`{name}`] form is submitted, cancelled or an action is triggered."
+    );
+
     quote! {
         pub(crate) struct #plugin;
         impl Plugin for #plugin {
@@ -236,43 +1514,350 @@ fn generate_plugin(
                 app
                     .add_event::<#event>()
                     #action_event
-                    .add_systems(Update, (setup, submit, btn_submit));
+                    .register_type::<#marker_component>()
+                    .register_type::<#marker_form_element>()
+                    .add_systems(Update, (setup, submit, btn_submit))
+                    #form_validation_system;
             }
         }
 
+        #[doc = #marker_component_doc]
         #[derive(Component, Reflect)]
-        pub(crate) struct #marker_component;
+        #[reflect(Component)]
+        pub struct #marker_component;
 
+        #[doc = #marker_form_element_doc]
         #[derive(Component, Reflect)]
+        #[reflect(Component)]
         pub struct #marker_form_element;
 
-        #[derive(Resource, Debug)]
-        pub(crate) struct #entity_resource {
+        #[doc = #fields_component_doc]
+        #[derive(Component, Debug)]
+        pub struct #fields_component {
             #(
-                pub(crate) #input_fields: Entity,
+                pub(crate) #input_fields: #input_field_types,
             )*
         }
 
+        #[doc = #event_doc]
         #[derive(Event, Debug)]
-        pub(crate) struct #event {
+        pub struct #event {
+            pub(crate) form: Entity,
             pub(crate) event: FormEvent<#name>,
         }
+
+        impl GeneratedForm for #name {
+            type Marker = #marker_component;
+            type Fields = #fields_component;
+            type Event = #event;
+
+            fn insert_marker(commands: &mut Commands, entity: Entity) {
+                commands.entity(entity).insert(#marker_component);
+            }
+
+            fn set_form_data(fields: &Self::Fields, commands: &mut Commands, data: &Self) {
+                fields.set_form_data(commands, data);
+            }
+
+            fn form_event(event: &Self::Event) -> &FormEvent<Self> {
+                &event.event
+            }
+
+            fn form(event: &Self::Event) -> Entity {
+                event.form
+            }
+        }
+
+        #clear_form_errors
+    }
+}
+
+/// Generates the system removing the errors of the form validation once any field of the form
+/// changes. The changed fields are validated again by their own systems.
+fn generate_clear_form_errors(
+    fields: &[FormField],
+    fields_component: &Ident,
+) -> proc_macro2::TokenStream {
+    let input_fields = fields
+        .iter()
+        .filter(|o| o.is_element())
+        .map(|o| format_ident!("{}_input", o.form_field_opts.ident.as_ref().unwrap()))
+        .collect::<Vec<_>>();
+
+    quote! {
+        #[allow(clippy::needless_pass_by_value)]
+        fn clear_form_errors(
+            mut commands: Commands,
+            q_changed: Query<
+                (),
+                Or<(
+                    Changed<TextInputValue>,
+                    Changed<ColorPickerValue>,
+                    Changed<SelectValue>,
+                    Changed<SliderValue>,
+                    Changed<TagInputValue>,
+                )>,
+            >,
+            q_invalid: Query<&FormElementInvalid>,
+            q_form_fields: Query<&#fields_component>,
+        ) {
+            for form_fields in &q_form_fields {
+                let fields = [#(form_fields.#input_fields),*];
+                if !fields.iter().any(|field| q_changed.contains(*field)) {
+                    continue;
+                }
+
+                for field in fields {
+                    if q_changed.contains(field) {
+                        continue;
+                    }
+
+                    if q_invalid.get(field).is_ok_and(|invalid| invalid.0.code() == Some("form")) {
+                        commands
+                            .entity(field)
+                            .remove::<FormElementInvalid>()
+                            .insert(FormElementValid);
+                    }
+                }
+            }
+        }
     }
 }
 
 fn generate_setup(
     name: &Ident,
     form_opts: &FormOpts,
-    form_field_opts: &[FormField],
     marker_component_name: &Ident,
 ) -> proc_macro2::TokenStream {
+    let form_validation_mode = form_opts
+        .validation
+        .map(|validation| {
+            let validation_mode = validation.validation_mode();
+            quote! { .insert(#validation_mode) }
+        })
+        .unwrap_or_default();
+
+    let actions_setup = generate_actions_setup(form_opts);
+
+    let confirm_cancel = if form_opts.confirm_cancel.unwrap_or_default() {
+        quote! { .insert(FormConfirmCancel) }
+    } else {
+        quote! {}
+    };
+
+    let enter_advances = if form_opts.enter_advances.unwrap_or_default() {
+        quote! { .insert(EnterKeyBehavior::Advance) }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        fn setup(
+            mut commands: Commands,
+            q_added: Query<(Entity, Option<&FormInitialValue<#name>>), Added<#marker_component_name>>,
+            styles: FormStyles,
+        ) {
+            for (entity, initial_value) in q_added.iter() {
+                let (form_fields, form_children) =
+                    <#name as NestedForm>::spawn_fields(&mut commands, &styles, 0);
+                if let Some(initial_value) = initial_value {
+                    form_fields.set_form_data(&mut commands, &initial_value.0);
+                }
+
+                #actions_setup
+
+                commands.entity(entity)
+                    .insert((Form, FormValid, form_fields))
+                    .insert(Name::new("form"))
+                    #form_validation_mode
+                    #confirm_cancel
+                    #enter_advances
+                    .push_children(&form_children)
+                    .add_child(actions);
+            }
+        }
+    }
+}
+
+/// Generates the `NestedForm` implementation, spawning the inputs and reading their data.
+fn generate_nested_form(
+    name: &Ident,
+    fields: &[FormField],
+    skipped_fields: &[FormFieldOpts],
+    opts: &FormOpts,
+    fields_component: &Ident,
+) -> proc_macro2::TokenStream {
+    let spawn_fields = generate_spawn_fields(fields, opts, fields_component);
+    let read_fields = generate_read_fields(name, fields, skipped_fields, opts, fields_component);
+
+    let element_counts = fields
+        .iter()
+        .map(FormField::element_count)
+        .collect::<Vec<_>>();
+
+    quote! {
+        impl NestedForm for #name {
+            type Fields = #fields_component;
+
+            const ELEMENT_COUNT: usize = 0 #( + #element_counts )*;
+
+            #spawn_fields
+
+            #read_fields
+        }
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn generate_spawn_fields(
+    form_field_opts: &[FormField],
+    form_opts: &FormOpts,
+    fields_component: &Ident,
+) -> proc_macro2::TokenStream {
+    // Nested and repeated fields take up as many positions in the focus order as they can have
+    // elements.
+    let mut orders = Vec::new();
+    let mut order = quote! { order };
+    for o in form_field_opts {
+        orders.push(order.clone());
+        let element_count = o.element_count();
+        order = quote! { #order + #element_count };
+    }
+
     let form_field_setups = form_field_opts
         .iter()
-        .enumerate()
-        .map(|(i, o)| match &o.field_specific_opts {
+        .zip(&orders)
+        .map(|(o, i)| match &o.field_specific_opts {
             FormFieldType::TextBox(text_box_opts) => {
-                generate_input_field_setup(&o.form_field_opts, text_box_opts, i)
+                generate_input_field_setup(&o.form_field_opts, text_box_opts, i, &quote! {})
+            }
+            FormFieldType::TextArea(text_area_opts) => {
+                let settings = text_area_opts.text_area_settings();
+                generate_input_field_setup(
+                    &o.form_field_opts,
+                    &text_area_opts.text_box_opts(),
+                    i,
+                    &quote! { #settings, },
+                )
+            }
+            FormFieldType::Autocomplete(autocomplete_opts) => {
+                let source = &autocomplete_opts.source;
+                generate_input_field_setup(
+                    &o.form_field_opts,
+                    &autocomplete_opts.text_box_opts(),
+                    i,
+                    &quote! { SuggestionsProvider::new(#source), },
+                )
+            }
+            FormFieldType::CurrencyBox(currency_box_opts) => {
+                let settings = currency_box_opts.number_input_settings();
+                generate_input_field_setup(
+                    &o.form_field_opts,
+                    &currency_box_opts.text_box_opts(),
+                    i,
+                    &quote! { #settings, },
+                )
+            }
+            FormFieldType::PercentBox(percent_box_opts) => {
+                let settings = percent_box_opts.number_input_settings();
+                generate_input_field_setup(
+                    &o.form_field_opts,
+                    &percent_box_opts.text_box_opts(),
+                    i,
+                    &quote! { #settings, #settings.filter(), },
+                )
+            }
+            FormFieldType::NumberInput(number_input_opts) => {
+                let settings = number_input_opts.number_input_settings();
+                let filter = number_input_opts.filter(field_value_type(&o.form_field_opts));
+                generate_input_field_setup(
+                    &o.form_field_opts,
+                    &number_input_opts.text_box_opts(),
+                    i,
+                    &quote! { #settings, #filter, },
+                )
             }
+            FormFieldType::DateBox(date_box_opts) => {
+                let settings = date_box_opts.date_input_settings();
+                generate_input_field_setup(
+                    &o.form_field_opts,
+                    &date_box_opts.text_box_opts(),
+                    i,
+                    &quote! { #settings, },
+                )
+            }
+            FormFieldType::DatePicker(date_box_opts) => {
+                let settings = date_box_opts.date_input_settings();
+                generate_input_field_setup(
+                    &o.form_field_opts,
+                    &date_box_opts.text_box_opts(),
+                    i,
+                    &quote! { #settings, DatePicker, },
+                )
+            }
+            FormFieldType::Select(select_opts) => {
+                generate_select_setup(&o.form_field_opts, select_opts, i)
+            }
+            FormFieldType::Tags(tags_opts) => generate_input_field_setup(
+                &o.form_field_opts,
+                &tags_opts.text_box_opts(),
+                i,
+                &quote! { TagInputValue::default(), },
+            ),
+            FormFieldType::Slider(slider_opts) => {
+                generate_slider_setup(&o.form_field_opts, slider_opts, i)
+            }
+            FormFieldType::ColorPicker(color_picker_opts) => {
+                generate_color_picker_setup(&o.form_field_opts, color_picker_opts, i)
+            }
+            FormFieldType::Nested => generate_nested_setup(&o.form_field_opts, i),
+            FormFieldType::Repeated(repeated_opts, text_box_opts) => {
+                generate_repeated_setup(o, repeated_opts, text_box_opts.as_ref(), i)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let validator_setups = form_field_opts
+        .iter()
+        .filter(|o| o.is_element())
+        .filter_map(|o| {
+            let input_field_name =
+                format_ident!("{}_input", o.form_field_opts.ident.as_ref().unwrap());
+            o.validate_opts.as_ref().map(|validate_opts| {
+                let validators = validate_opts.validators();
+                quote! {
+                    commands.entity(#input_field_name).insert(#validators);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let validation_mode_setups = form_field_opts
+        .iter()
+        .filter(|o| o.is_element())
+        .filter_map(|o| {
+            let input_field_name =
+                format_ident!("{}_input", o.form_field_opts.ident.as_ref().unwrap());
+            o.form_field_opts.validation.map(|validation| {
+                let validation_mode = validation.validation_mode();
+                quote! {
+                    commands.entity(#input_field_name).insert(#validation_mode);
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let tooltip_setups = form_field_opts
+        .iter()
+        .filter(|o| o.is_element())
+        .filter_map(|o| {
+            let input_field_name =
+                format_ident!("{}_input", o.form_field_opts.ident.as_ref().unwrap());
+            o.form_field_opts.tooltip.as_ref().map(|tooltip| {
+                quote! {
+                    commands.entity(#input_field_name).insert(FormTooltip(#tooltip.to_string()));
+                }
+            })
         })
         .collect::<Vec<_>>();
 
@@ -281,47 +1866,270 @@ fn generate_setup(
         .map(|o| format_ident!("{}_input", o.form_field_opts.ident.as_ref().unwrap()))
         .collect::<Vec<_>>();
 
-    let actions_setup = generate_actions_setup(form_opts);
-
-    let entity_resource_name = format_ident!("{}FormFields", name);
+    let (group_setups, form_children) = generate_groups_setup(
+        form_field_opts,
+        form_opts.label_position.unwrap_or_default(),
+        form_opts.collapsible_sections.unwrap_or_default(),
+    );
 
     quote! {
-        fn setup(
-            mut commands: Commands,
-            q_added: Query<Entity, Added<#marker_component_name>>,
-            res_form_input_text_style: Res<FormInputTextStyle>,
-        ) {
-            for entity in q_added.iter() {
-                #(#form_field_setups)*
+        #[allow(unused_variables)]
+        fn spawn_fields(
+            commands: &mut Commands,
+            styles: &FormStyles,
+            order: usize,
+        ) -> (#fields_component, Vec<Entity>) {
+            let theme_text_style = &styles.theme.text_style;
+            let theme_help_text_style = &styles.theme.help_text_style;
+            let res_form_section_style = &styles.section;
 
-                commands.insert_resource(#entity_resource_name {
+            #(#form_field_setups)*
+            #(#validator_setups)*
+            #(#validation_mode_setups)*
+            #(#tooltip_setups)*
+            #(#group_setups)*
+
+            (
+                #fields_component {
                     #(#input_field_names),*
-                });
+                },
+                vec![#(#form_children),*],
+            )
+        }
+    }
+}
 
-                #actions_setup
+/// Sorts the fields by their `order`, falling back to the declaration position.
+/// Fails if multiple fields have the same order.
+fn order_fields(fields: Vec<FormField>) -> Result<Vec<FormField>, Error> {
+    let mut errors = Error::accumulator();
+    for (i, field) in fields.iter().enumerate() {
+        let Some(order) = field.form_field_opts.order else {
+            continue;
+        };
+
+        if fields[..i]
+            .iter()
+            .any(|other| other.form_field_opts.order == Some(order))
+        {
+            let ident = field.form_field_opts.ident.as_ref().unwrap();
+            errors.push(Error::custom(format!("Duplicate field order {order}")).with_span(ident));
+        }
+    }
+    errors.finish()?;
+
+    let mut fields = fields.into_iter().enumerate().collect::<Vec<_>>();
+    fields.sort_by_key(|(i, field)| field.form_field_opts.order.unwrap_or(*i));
+
+    Ok(fields.into_iter().map(|(_, field)| field).collect())
+}
+
+/// Wraps consecutive fields of the same group in a titled container.
+/// Returns the setup of the containers and the entities to add as children of the form.
+fn generate_groups_setup(
+    fields: &[FormField],
+    label_position: LabelPosition,
+    collapsible_sections: bool,
+) -> (Vec<proc_macro2::TokenStream>, Vec<Ident>) {
+    let mut group_setups = Vec::new();
+    let mut field_children = Vec::new();
+
+    for field in fields {
+        let field_ident = field.form_field_opts.ident.as_ref().unwrap();
+        let node_ident = field.node_ident();
+        let mut children: Vec<Ident> = match (&field.form_field_opts.label, label_position) {
+            (None, _) => vec![node_ident],
+            (Some(_), LabelPosition::Above) => {
+                vec![format_ident!("{}_label", field_ident), node_ident]
+            }
+            (Some(_), LabelPosition::Left) => {
+                let row_ident = format_ident!("{}_row", field_ident);
+                group_setups.push(generate_label_row_setup(
+                    field_ident,
+                    &node_ident,
+                    &row_ident,
+                ));
+                vec![row_ident]
+            }
+        };
+
+        if let Some(help) = &field.form_field_opts.help {
+            let help_ident = format_ident!("{}_help", field_ident);
+            group_setups.push(quote! {
+                let #help_ident = commands.spawn((
+                    TextBundle::from_section(#help, theme_help_text_style.clone()),
+                    Name::new("help"),
+                )).id();
+            });
+            children.push(help_ident);
+        }
+
+        let section = field.form_field_opts.section.as_ref();
+        let group = field.form_field_opts.group.as_ref();
+        field_children.push(((section, group), children));
+    }
+
+    let mut section_children = Vec::new();
+    for ((section, group), children) in consecutive_runs(field_children) {
+        let children = children.into_iter().flatten().collect::<Vec<_>>();
+        let Some(group) = group else {
+            section_children.push((section, children));
+            continue;
+        };
+
+        let group_ident = format_ident!("group_{}", group_setups.len());
+        group_setups.push(generate_group_setup(group, &group_ident, &children));
+        section_children.push((section, vec![group_ident]));
+    }
+
+    let mut form_children = Vec::new();
+    for (section, children) in consecutive_runs(section_children) {
+        let children = children.into_iter().flatten().collect::<Vec<_>>();
+        let Some(section) = section else {
+            form_children.extend(children);
+            continue;
+        };
+
+        let section_ident = format_ident!("section_{}", group_setups.len());
+        group_setups.push(generate_section_setup(
+            section,
+            &section_ident,
+            &children,
+            collapsible_sections,
+        ));
+        form_children.push(section_ident);
+    }
+
+    (group_setups, form_children)
+}
+
+/// Splits the items into runs of consecutive items with the same key.
+fn consecutive_runs<K: PartialEq, T>(items: Vec<(K, T)>) -> Vec<(K, Vec<T>)> {
+    let mut runs: Vec<(K, Vec<T>)> = Vec::new();
+    for (key, item) in items {
+        match runs.last_mut() {
+            Some((last, run)) if *last == key => run.push(item),
+            _ => runs.push((key, vec![item])),
+        }
+    }
+    runs
+}
+
+/// Places the label left of the input, in a row spanning the width of the form.
+fn generate_label_row_setup(
+    field_ident: &Ident,
+    node_ident: &Ident,
+    row_ident: &Ident,
+) -> proc_macro2::TokenStream {
+    let label_ident = format_ident!("{}_label", field_ident);
+
+    quote! {
+        commands.entity(#label_ident).insert(Style {
+            flex_shrink: 0.0,
+            margin: UiRect::right(Val::Px(8.0)),
+            ..default()
+        });
+        let #row_ident = commands.spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("label-row"),
+        )).id();
+        commands.entity(#row_ident).push_children(&[#label_ident, #node_ident]);
+    }
+}
+
+fn generate_group_setup(
+    title: &String,
+    group_ident: &Ident,
+    children: &[Ident],
+) -> proc_macro2::TokenStream {
+    quote! {
+        let #group_ident = commands.spawn((
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("group"),
+        )).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(#title, theme_text_style.clone()));
+        }).id();
+        commands.entity(#group_ident)
+            #( .add_child(#children) )*;
+    }
+}
+
+fn generate_section_setup(
+    title: &String,
+    section_ident: &Ident,
+    children: &[Ident],
+    collapsible: bool,
+) -> proc_macro2::TokenStream {
+    let (collapsible, header_interaction) = if collapsible {
+        (
+            quote! { CollapsibleSection, },
+            quote! { Interaction::default(), },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
 
-                commands.entity(entity)
-                    .insert((Form, FormValid))
-                    .insert(Name::new("form"))
-                    #( .add_child(#input_field_names) )*
-                    .add_child(actions);
-            }
-        }
+    quote! {
+        let #section_ident = commands.spawn((
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    margin: res_form_section_style.margin,
+                    ..default()
+                },
+                ..default()
+            },
+            FormSection,
+            #collapsible
+            Name::new("section"),
+        )).with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(#title, res_form_section_style.header_text_style.clone())
+                    .with_style(Style {
+                        margin: res_form_section_style.header_margin,
+                        ..default()
+                    }),
+                FormSectionHeader,
+                #header_interaction
+                Name::new("section-header"),
+            ));
+        }).id();
+        commands.entity(#section_ident)
+            #( .add_child(#children) )*;
     }
 }
 
 fn generate_input_field_setup(
     field_opts: &FormFieldOpts,
     text_box_opts: &TextBoxOpts,
-    order: usize,
+    order: &proc_macro2::TokenStream,
+    extra_components: &proc_macro2::TokenStream,
 ) -> proc_macro2::TokenStream {
     let field_name = format_ident!("{}_input", field_opts.ident.as_ref().unwrap());
 
+    let placeholder_style = text_box_opts
+        .placeholder_style
+        .as_ref()
+        .map_or(quote! { None }, |style| quote! { Some(#style) });
     let placeholder = text_box_opts
         .placeholder
         .as_ref()
-        .or(field_opts.label.as_ref())
-        .map(|placeholder| quote! { .with_placeholder(#placeholder, None) })
+        .map(|placeholder| quote! { .with_placeholder(#placeholder, #placeholder_style) })
         .unwrap_or_default();
 
     let default_value = text_box_opts
@@ -338,6 +2146,40 @@ fn generate_input_field_setup(
 
     let settings = generate_input_field_settings(text_box_opts);
 
+    let filter = text_box_opts
+        .filter
+        .map(|filter| {
+            let filter = filter.text_input_filter();
+            quote! { #filter, }
+        })
+        .unwrap_or_default();
+
+    let format_mask = text_box_opts
+        .format_mask
+        .as_ref()
+        .map(|format_mask| quote! { TextInputFormatMask::new(#format_mask), })
+        .unwrap_or_default();
+
+    let floating_label = text_box_opts
+        .floating_label
+        .filter(|floating_label| *floating_label)
+        .map(|_| quote! { FloatingLabel::default(), })
+        .unwrap_or_default();
+
+    let debounce = text_box_opts
+        .debounce_ms
+        .map(|debounce_ms| quote! { DebouncedChange::from_millis(#debounce_ms), })
+        .unwrap_or_default();
+
+    let transform = text_box_opts
+        .transform
+        .map(|transform| {
+            let transform =
+                transform.text_input_transform(text_box_opts.transform_live.unwrap_or(false));
+            quote! { #transform, }
+        })
+        .unwrap_or_default();
+
     let optional = field_opts
         .optional
         .as_ref()
@@ -345,13 +2187,22 @@ fn generate_input_field_setup(
         .map(|_| quote! { FormElementOptional, })
         .unwrap_or_default();
 
+    let disabled = field_opts
+        .disabled
+        .filter(|disabled| *disabled)
+        .map(|_| quote! { FormElementDisabled, })
+        .unwrap_or_default();
+
     let text_style = text_box_opts
         .text_style
         .as_ref()
         .map(|text_style| quote! { #text_style })
-        .unwrap_or(quote! { res_form_input_text_style.0.clone() });
+        .unwrap_or(quote! { theme_text_style.clone() });
+
+    let label = generate_label_setup(field_opts, &text_style);
 
     quote! {
+        #label
         let #field_name = commands.spawn((
             NodeBundle {
                 style: Style {
@@ -366,12 +2217,309 @@ fn generate_input_field_setup(
                 #settings
                 #default_value
                 #active,
+            #filter
+            #format_mask
+            #transform
+            #floating_label
+            #debounce
+            #optional
+            #disabled
+            #extra_components
+            FormElementOrder(#order),
+            FormThemed,
+        )).id();
+    }
+}
+
+fn generate_color_picker_setup(
+    field_opts: &FormFieldOpts,
+    color_picker_opts: &ColorPickerOpts,
+    order: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_name = format_ident!("{}_input", field_opts.ident.as_ref().unwrap());
+
+    let default = color_picker_opts
+        .default
+        .as_ref()
+        .map_or(quote! { Color::WHITE }, |default| quote! { #default });
+
+    let text_style = color_picker_opts
+        .text_style
+        .as_ref()
+        .map(|text_style| quote! { #text_style })
+        .unwrap_or(quote! { theme_text_style.clone() });
+
+    let disabled = field_opts
+        .disabled
+        .filter(|disabled| *disabled)
+        .map(|_| quote! { FormElementDisabled, })
+        .unwrap_or_default();
+
+    let label = generate_label_setup(field_opts, &text_style);
+
+    quote! {
+        #label
+        let #field_name = commands.spawn((
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                ..default()
+            },
+            ColorPickerBundle::new(#default).with_text_style(#text_style),
+            #disabled
+            FormElementOrder(#order),
+        )).id();
+    }
+}
+
+fn generate_select_setup(
+    field_opts: &FormFieldOpts,
+    select_opts: &SelectOpts,
+    order: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_name = format_ident!("{}_input", field_opts.ident.as_ref().unwrap());
+    let ty = field_value_type(field_opts);
+
+    let placeholder = select_opts
+        .placeholder
+        .as_ref()
+        .map(|placeholder| quote! { .with_placeholder(#placeholder) })
+        .unwrap_or_default();
+
+    let text_style = select_opts
+        .text_style
+        .as_ref()
+        .map(|text_style| quote! { #text_style })
+        .unwrap_or(quote! { theme_text_style.clone() });
+
+    let optional = field_opts
+        .optional
+        .filter(|optional| *optional)
+        .map(|_| quote! { FormElementOptional, })
+        .unwrap_or_default();
+
+    let disabled = field_opts
+        .disabled
+        .filter(|disabled| *disabled)
+        .map(|_| quote! { FormElementDisabled, })
+        .unwrap_or_default();
+
+    let label = generate_label_setup(field_opts, &text_style);
+
+    quote! {
+        #label
+        let #field_name = commands.spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::SpaceBetween,
+                    ..default()
+                },
+                ..default()
+            },
+            SelectBundle::from_options::<#ty>()
+                .with_text_style(#text_style)
+                #placeholder,
             #optional
+            #disabled
+            FormElementOrder(#order),
+            FormThemed,
+        )).id();
+    }
+}
+
+fn generate_slider_setup(
+    field_opts: &FormFieldOpts,
+    slider_opts: &SliderOpts,
+    order: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_name = format_ident!("{}_input", field_opts.ident.as_ref().unwrap());
+    let settings = slider_opts.slider_settings();
+
+    let default_value = slider_opts
+        .default_value
+        .map(|default_value| {
+            let default_value = default_value.0;
+            quote! { .with_value(#default_value) }
+        })
+        .unwrap_or_default();
+
+    let text_style = slider_opts
+        .text_style
+        .as_ref()
+        .map(|text_style| quote! { #text_style })
+        .unwrap_or(quote! { theme_text_style.clone() });
+
+    let disabled = field_opts
+        .disabled
+        .filter(|disabled| *disabled)
+        .map(|_| quote! { FormElementDisabled, })
+        .unwrap_or_default();
+
+    let label = generate_label_setup(field_opts, &text_style);
+
+    quote! {
+        #label
+        let #field_name = commands.spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                },
+                ..default()
+            },
+            SliderBundle::new(#settings)
+                .with_text_style(#text_style)
+                #default_value,
+            #disabled
             FormElementOrder(#order),
         )).id();
     }
 }
 
+/// Spawns the label of the field if it has one.
+fn generate_nested_setup(
+    field_opts: &FormFieldOpts,
+    order: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_name = field_opts.ident.as_ref().unwrap();
+    let input_field_name = format_ident!("{}_input", field_name);
+    let nested_name = format_ident!("{}_nested", field_name);
+    let ty = &field_opts.ty;
+
+    let label = generate_label_setup(field_opts, &quote! { theme_text_style.clone() });
+
+    quote! {
+        #label
+        let (#input_field_name, children) =
+            <#ty as NestedForm>::spawn_fields(commands, styles, #order);
+        let #nested_name = commands.spawn((
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("nested"),
+        )).id();
+        commands.entity(#nested_name).push_children(&children);
+    }
+}
+
+fn generate_repeated_setup(
+    field: &FormField,
+    repeated_opts: &RepeatedOpts,
+    text_box_opts: Option<&TextBoxOpts>,
+    order: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_opts = &field.form_field_opts;
+    let field_name = format_ident!("{}_input", field_opts.ident.as_ref().unwrap());
+    let row_ty = vec_inner_type(&field_opts.ty).unwrap_or(&field_opts.ty);
+
+    let (spawn_row, row_elements) = if let Some(text_box_opts) = text_box_opts {
+        let row_opts = FormFieldOpts::new(format_ident!("row"), row_ty.clone());
+        let row_setup =
+            generate_input_field_setup(&row_opts, text_box_opts, &quote! { order }, &quote! {});
+        let validators = field.validate_opts.as_ref().map(|validate_opts| {
+            let validators = validate_opts.validators();
+            quote! { commands.entity(row_input).insert(#validators); }
+        });
+        (
+            quote! {
+                let theme_text_style = &styles.theme.text_style;
+                #row_setup
+                #validators
+                (row_input, Box::new(row_input))
+            },
+            quote! { 1 },
+        )
+    } else {
+        (
+            quote! {
+                let (fields, children) =
+                    <#row_ty as NestedForm>::spawn_fields(commands, styles, order);
+                let node = commands.spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Column,
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("nested"),
+                )).id();
+                commands.entity(node).push_children(&children);
+                (node, Box::new(fields))
+            },
+            quote! { <#row_ty as NestedForm>::ELEMENT_COUNT },
+        )
+    };
+
+    let min = repeated_opts.min.map(|min| quote! { .with_min(#min) });
+    let max = repeated_opts.max.map(|max| quote! { .with_max(#max) });
+    let add = repeated_opts
+        .add
+        .as_ref()
+        .map(|add| quote! { .with_add_text(#add) });
+    let remove = repeated_opts
+        .remove
+        .as_ref()
+        .map(|remove| quote! { .with_remove_text(#remove) });
+
+    let label = generate_label_setup(field_opts, &quote! { theme_text_style.clone() });
+
+    quote! {
+        #label
+        let #field_name = commands.spawn((
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+                ..default()
+            },
+            RepeatedField::new(
+                |commands: &mut Commands, styles: &FormStyles, order: usize| -> (Entity, Box<dyn std::any::Any + Send + Sync>) {
+                    #spawn_row
+                },
+                #order,
+                #row_elements,
+            )
+            #min
+            #max
+            #add
+            #remove,
+            Name::new("repeated"),
+        )).id();
+    }
+}
+
+fn generate_label_setup(
+    field_opts: &FormFieldOpts,
+    text_style: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    field_opts
+        .label
+        .as_ref()
+        .map(|label| {
+            let label_name = format_ident!("{}_label", field_opts.ident.as_ref().unwrap());
+            quote! {
+                let #label_name = commands.spawn((
+                    TextBundle::from_section(#label, #text_style),
+                    Name::new("label"),
+                )).id();
+            }
+        })
+        .unwrap_or_default()
+}
+
 fn generate_input_field_settings(opts: &TextBoxOpts) -> proc_macro2::TokenStream {
     let mask = opts
         .mask
@@ -380,16 +2528,41 @@ fn generate_input_field_settings(opts: &TextBoxOpts) -> proc_macro2::TokenStream
         .map(|mask| quote! { Some(#mask) })
         .unwrap_or(quote! { None });
 
+    let max_length = opts
+        .max_length
+        .map(|max_length| quote! { Some(#max_length) })
+        .unwrap_or(quote! { None });
+    let alignment = opts.align.unwrap_or(Align::Left).text_input_alignment();
+    let clearable = opts.clearable.unwrap_or(false);
+
+    // The form clears the inputs not retaining their value itself, once it was submitted.
     quote! {
         .with_settings(TextInputSettings {
             mask_character: #mask,
             retain_on_submit: true,
+            max_length: #max_length,
+            alignment: #alignment,
+            clearable: #clearable,
+            ..Default::default()
         })
     }
 }
 
 fn generate_actions_setup(opts: &FormOpts) -> proc_macro2::TokenStream {
     let mut actions = Vec::new();
+    if let Some(reset_text) = &opts.reset {
+        actions.push(quote! {
+            let reset = commands.spawn((
+                FormButtonBundle::new(#reset_text)
+                    .with_form(entity)
+                    .with_role(ButtonRole::Reset)
+            )).id();
+
+            commands.entity(actions)
+                .add_child(reset);
+        });
+    }
+
     if let Some(cancel_text) = &opts.cancel {
         actions.push(quote! {
             let cancel = commands.spawn((
@@ -436,95 +2609,478 @@ fn generate_actions_setup(opts: &FormOpts) -> proc_macro2::TokenStream {
     }
 }
 
-fn generate_submit_system(
+#[allow(clippy::too_many_lines)]
+fn generate_submit_system(
+    name: &Ident,
+    fields: &[FormField],
+    opts: &FormOpts,
+    form_identifiers: &FormIdentifiers,
+) -> proc_macro2::TokenStream {
+    let button_submit = generate_button_submit(opts, form_identifiers);
+
+    let FormIdentifiers {
+        marker_component,
+        fields_component,
+        event,
+        ..
+    } = form_identifiers;
+
+    let mut submitted_effects = fields
+        .iter()
+        .filter_map(|o| match &o.field_specific_opts {
+            FormFieldType::TextBox(TextBoxOpts {
+                retain: Some(false),
+                ..
+            })
+            | FormFieldType::TextArea(TextAreaOpts {
+                retain: Some(false),
+                ..
+            })
+            | FormFieldType::Autocomplete(AutocompleteOpts {
+                retain: Some(false),
+                ..
+            }) => {
+                let input_field_name =
+                    format_ident!("{}_input", o.form_field_opts.ident.as_ref().unwrap());
+                Some(quote! {
+                    commands.entity(form_fields.#input_field_name).insert(TextInputValue(String::new()));
+                })
+            }
+            FormFieldType::TextBox(_)
+            | FormFieldType::TextArea(_)
+            | FormFieldType::Autocomplete(_)
+            | FormFieldType::CurrencyBox(_)
+            | FormFieldType::PercentBox(_)
+            | FormFieldType::NumberInput(_)
+            | FormFieldType::DateBox(_)
+            | FormFieldType::DatePicker(_)
+            | FormFieldType::Select(_)
+            | FormFieldType::Tags(_)
+            | FormFieldType::Slider(_)
+            | FormFieldType::ColorPicker(_)
+            | FormFieldType::Nested
+            | FormFieldType::Repeated(..) => None,
+        })
+        .collect::<Vec<_>>();
+
+    submitted_effects.extend(generate_after_submit_focus(fields, opts));
+
+    let submitted = if submitted_effects.is_empty() {
+        quote! {
+            fn submitted(_commands: &mut Commands, _form_fields: &#fields_component) {}
+        }
+    } else {
+        quote! {
+            fn submitted(commands: &mut Commands, form_fields: &#fields_component) {
+                #(#submitted_effects)*
+            }
+        }
+    };
+
+    quote! {
+        fn submit(
+            mut commands: Commands,
+            mut ev_form: EventReader<GenericFormEvent>,
+            mut ev_specific_form_event: EventWriter<#event>,
+            q_form: Query<(&#fields_component, Has<FormValid>), With<#marker_component>>,
+            values: FormValues,
+        ) {
+            for ev in ev_form.read() {
+                match ev.form {
+                    FormEvent::Submit(form) => {
+                        let Ok((form_fields, valid)) = q_form.get(form) else {
+                            continue;
+                        };
+                        if let Some(form_data) = get_form_data(&mut commands, valid, &values, form_fields) {
+                            ev_specific_form_event.send(#event { form, event: FormEvent::Submit(form_data) });
+                            submitted(&mut commands, form_fields);
+                        }
+                    }
+                    FormEvent::Cancel(form) => {
+                        if !q_form.contains(form) {
+                            continue;
+                        }
+                        ev_specific_form_event.send(#event { form, event: FormEvent::Cancel(form) });
+                    }
+                    FormEvent::Reset(form) => {
+                        if !q_form.contains(form) {
+                            continue;
+                        }
+                        ev_specific_form_event.send(#event { form, event: FormEvent::Reset(form) });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        #button_submit
+
+        #submitted
+
+        fn get_form_data(
+            commands: &mut Commands,
+            valid: bool,
+            values: &FormValues,
+            form_fields: &#fields_component,
+        ) -> Option<#name> {
+            if valid {
+                <#name as NestedForm>::read_fields(commands, values, form_fields)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn generate_read_fields(
     name: &Ident,
     fields: &[FormField],
+    skipped_fields: &[FormFieldOpts],
     opts: &FormOpts,
-    form_identifiers: &FormIdentifiers,
+    fields_component: &Ident,
 ) -> proc_macro2::TokenStream {
     let input_field_names = fields
         .iter()
         .map(|o| o.form_field_opts.ident.as_ref().unwrap())
         .collect::<Vec<_>>();
 
+    let skipped_field_values = skipped_fields
+        .iter()
+        .map(|o| {
+            let field_name = o.ident.as_ref().unwrap();
+            if let Some(Override::Explicit(expr)) = &o.skip {
+                quote! { #field_name: #expr }
+            } else {
+                quote! { #field_name: Default::default() }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let form_validation = generate_form_validation(fields, opts);
+
     let input_field_query_resolvers = fields
         .iter()
-        .map(|o| match o.field_specific_opts {
-            FormFieldType::TextBox(_) => {
+        .map(|o| match &o.field_specific_opts {
+            FormFieldType::CurrencyBox(currency_box_opts) => generate_number_resolver(
+                &o.form_field_opts,
+                &currency_box_opts.number_input_settings(),
+                10f64.powi(i32::try_from(currency_box_opts.decimals()).unwrap_or(i32::MAX)),
+                1.0,
+            ),
+            FormFieldType::PercentBox(percent_box_opts) => {
+                let scale = if percent_box_opts.fraction.unwrap_or(false) {
+                    0.01
+                } else {
+                    1.0
+                };
+                generate_number_resolver(
+                    &o.form_field_opts,
+                    &percent_box_opts.number_input_settings(),
+                    scale,
+                    scale,
+                )
+            }
+            FormFieldType::NumberInput(number_input_opts) => generate_typed_number_resolver(
+                &o.form_field_opts,
+                &number_input_opts.number_input_settings(),
+            ),
+            FormFieldType::DateBox(date_box_opts) | FormFieldType::DatePicker(date_box_opts) => {
+                generate_date_resolver(&o.form_field_opts, &date_box_opts.date_input_settings())
+            }
+            FormFieldType::Select(_) => {
+                let field_name = o.form_field_opts.ident.as_ref().unwrap();
+                let input_field_name = format_ident!("{}_input", field_name);
+                let ty = field_value_type(&o.form_field_opts);
+                let value = quote! {
+                    {
+                        let Ok(select) = q_select.get(form_fields.#input_field_name) else {
+                            return None;
+                        };
+                        select.0.and_then(<#ty as FormSelectOptions>::from_index)
+                    }
+                };
+                if let Some(true) = o.form_field_opts.optional {
+                    quote! {
+                        let #field_name = #value;
+                    }
+                } else {
+                    let mark_invalid = generate_mark_invalid(&input_field_name);
+                    quote! {
+                        let #field_name = #value;
+                        let Some(#field_name) = #field_name else {
+                            #mark_invalid
+                        };
+                    }
+                }
+            }
+            FormFieldType::Tags(_) => {
+                let field_name = o.form_field_opts.ident.as_ref().unwrap();
+                let input_field_name = format_ident!("{}_input", field_name);
+                let value = quote! {
+                    {
+                        let Ok(tags) = q_tag_input.get(form_fields.#input_field_name) else {
+                            return None;
+                        };
+                        tags.0.clone()
+                    }
+                };
+                if let Some(true) = o.form_field_opts.optional {
+                    quote! {
+                        let #field_name = Some(#value).filter(|tags| !tags.is_empty());
+                    }
+                } else {
+                    quote! {
+                        let #field_name = #value;
+                    }
+                }
+            }
+            FormFieldType::Slider(_) => {
+                let field_name = o.form_field_opts.ident.as_ref().unwrap();
+                let input_field_name = format_ident!("{}_input", field_name);
+                let ty = field_value_type(&o.form_field_opts);
+                let value = if is_integer(ty) {
+                    quote! { slider.0.round() as #ty }
+                } else {
+                    quote! { slider.0 as #ty }
+                };
+                let value = if let Some(true) = o.form_field_opts.optional {
+                    quote! { Some(#value) }
+                } else {
+                    value
+                };
+                quote! {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::unnecessary_cast)]
+                    let #field_name = {
+                        let Ok(slider) = q_slider.get(form_fields.#input_field_name) else {
+                            return None;
+                        };
+                        #value
+                    };
+                }
+            }
+            FormFieldType::ColorPicker(_) => {
+                let field_name = o.form_field_opts.ident.as_ref().unwrap();
+                let input_field_name = format_ident!("{}_input", field_name);
+                let value = quote! {
+                    {
+                        let Ok(color) = q_color_picker.get(form_fields.#input_field_name) else {
+                            return None;
+                        };
+                        color.0
+                    }
+                };
+                if let Some(true) = o.form_field_opts.optional {
+                    quote! {
+                        let #field_name = Some(#value);
+                    }
+                } else {
+                    quote! {
+                        let #field_name = #value;
+                    }
+                }
+            }
+            FormFieldType::Nested => {
+                let field_name = o.form_field_opts.ident.as_ref().unwrap();
+                let input_field_name = format_ident!("{}_input", field_name);
+                let ty = &o.form_field_opts.ty;
+                quote! {
+                    let #field_name = <#ty as NestedForm>::read_fields(
+                        commands,
+                        values,
+                        &form_fields.#input_field_name,
+                    )?;
+                }
+            }
+            FormFieldType::Repeated(_, text_box_opts) => {
+                let field_name = o.form_field_opts.ident.as_ref().unwrap();
+                let input_field_name = format_ident!("{}_input", field_name);
+                let row_ty = vec_inner_type(&o.form_field_opts.ty).unwrap_or(&o.form_field_opts.ty);
+                let read_row = match text_box_opts {
+                    Some(_) if is_string(row_ty) => quote! {
+                        for row in values.repeated_rows::<Entity>(form_fields.#input_field_name) {
+                            #field_name.push(q_text_input.get(*row).ok()?.0.clone());
+                        }
+                    },
+                    Some(_) => quote! {
+                        for row in values.repeated_rows::<Entity>(form_fields.#input_field_name) {
+                            let value = &q_text_input.get(*row).ok()?.0;
+                            if let Ok(parsed) = value.trim().parse::<#row_ty>() {
+                                #field_name.push(parsed);
+                            } else {
+                                commands
+                                    .entity(*row)
+                                    .insert(FormElementInvalid(FormValidationError::Invalid(*row)))
+                                    .remove::<FormElementValid>();
+                                return None;
+                            }
+                        }
+                    },
+                    None => quote! {
+                        for row in values.repeated_rows::<<#row_ty as NestedForm>::Fields>(
+                            form_fields.#input_field_name,
+                        ) {
+                            #field_name.push(<#row_ty as NestedForm>::read_fields(commands, values, row)?);
+                        }
+                    },
+                };
+                quote! {
+                    let mut #field_name = Vec::new();
+                    #read_row
+                }
+            }
+            FormFieldType::TextBox(_)
+            | FormFieldType::TextArea(_)
+            | FormFieldType::Autocomplete(_) => {
                 let field_name = o.form_field_opts.ident.as_ref().unwrap();
                 let input_field_name = format_ident!("{}_input", field_name);
                 if let Some(true) = o.form_field_opts.optional {
+                    let mark_invalid = generate_mark_invalid(&input_field_name);
+                    match option_inner_type(&o.form_field_opts.ty) {
+                        Some(inner) if !is_string(inner) => quote! {
+                            let #field_name = {
+                                let Some(value) = values.text(form_fields.#input_field_name) else {
+                                    return None;
+                                };
+                                if value.trim().is_empty() {
+                                    None
+                                } else if let Ok(parsed) = value.trim().parse::<#inner>() {
+                                    Some(parsed)
+                                } else {
+                                    #mark_invalid
+                                }
+                            };
+                        },
+                        _ => quote! {
+                            let Some(#field_name) = values.text(form_fields.#input_field_name) else {
+                                return None;
+                            };
+                            let #field_name = Some(#field_name);
+                        },
+                    }
+                } else if !is_string(&o.form_field_opts.ty) {
+                    let ty = &o.form_field_opts.ty;
+                    let mark_invalid = generate_mark_invalid(&input_field_name);
                     quote! {
-                         let #field_name = if let Ok(value) = q_text_input.get(res_form_fields.#input_field_name) {
-                            Some(value.0.clone())
-                        } else {
-                            None
+                        let #field_name = {
+                            let Some(value) = values.text(form_fields.#input_field_name) else {
+                                return None;
+                            };
+                            if let Ok(parsed) = value.trim().parse::<#ty>() {
+                                parsed
+                            } else {
+                                #mark_invalid
+                            }
                         };
                     }
                 } else {
                     quote! {
-                        let #field_name = q_text_input.get(res_form_fields.#input_field_name).unwrap().0.clone();
+                        let Some(#field_name) = values.text(form_fields.#input_field_name) else {
+                            return None;
+                        };
                     }
                 }
             }
         })
         .collect::<Vec<_>>();
 
-    let button_submit = generate_button_submit(opts, form_identifiers);
+    quote! {
+        #[allow(unused_variables, clippy::question_mark)]
+        fn read_fields(
+            commands: &mut Commands,
+            values: &FormValues,
+            form_fields: &#fields_component,
+        ) -> Option<#name> {
+            let q_text_input = &values.text_input;
+            let q_color_picker = &values.color_picker;
+            let q_select = &values.select;
+            let q_slider = &values.slider;
+            let q_tag_input = &values.tag_input;
 
-    let FormIdentifiers {
-        marker_component,
-        entity_resource,
-        event,
-        ..
-    } = form_identifiers;
+            #(#input_field_query_resolvers)*
+            let data = #name {
+                #(
+                    #input_field_names,
+                )*
+                #(
+                    #skipped_field_values,
+                )*
+            };
+            #form_validation
+            Some(data)
+        }
+    }
+}
+
+/// Generates the call of the form validation function, marking the fields it reports as invalid.
+fn generate_form_validation(fields: &[FormField], opts: &FormOpts) -> proc_macro2::TokenStream {
+    let Some(validate) = &opts.validate else {
+        return quote! {};
+    };
+
+    let (field_names, input_field_names): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter(|o| o.is_element())
+        .map(|o| {
+            let field_name = o.form_field_opts.ident.as_ref().unwrap();
+            (
+                field_name.to_string(),
+                format_ident!("{}_input", field_name),
+            )
+        })
+        .unzip();
 
     quote! {
-        fn submit(
-            mut commands: Commands,
-            mut ev_form: EventReader<GenericFormEvent>,
-            mut ev_specific_form_event: EventWriter<#event>,
-            mut q_form: Query<&#marker_component, With<FormValid>>,
-            q_form_entity: Query<Entity, With<#marker_component>>,
-            mut q_text_input: Query<&TextInputValue>,
-            res_form_fields: Option<Res<#entity_resource>>,
-        ) {
-            for ev in ev_form.read() {
-                match ev.form {
-                    FormEvent::Submit(form) => {
-                        let form = if let Ok(form) = q_form_entity.get_single() {
-                            form
-                        } else {
-                            continue;
-                        };
-                        ev_specific_form_event.send(#event { event: FormEvent::Submit(get_form_data(&q_form, &q_text_input, &res_form_fields).unwrap()) });
+        if let Err(errors) = #validate(&data) {
+            for (field, message) in errors {
+                let entity = match field {
+                    #( #field_names => form_fields.#input_field_names, )*
+                    _ => {
+                        warn!("Form validation reported unknown field {}", field);
+                        continue;
                     }
-                    FormEvent::Cancel(e) => { ev_specific_form_event.send(#event { event: FormEvent::Cancel(e) }); }
-                    _ => {}
-                }
+                };
+                commands
+                    .entity(entity)
+                    .insert(FormElementInvalid(FormValidationError::Code(entity, "form", message)))
+                    .remove::<FormElementValid>();
             }
+            return None;
         }
+    }
+}
 
-        #button_submit
+fn generate_after_submit_focus(
+    fields: &[FormField],
+    opts: &FormOpts,
+) -> Vec<proc_macro2::TokenStream> {
+    let input_field_names = fields
+        .iter()
+        .filter(|o| o.is_element())
+        .map(|o| format_ident!("{}_input", o.form_field_opts.ident.as_ref().unwrap()))
+        .collect::<Vec<_>>();
 
-        fn get_form_data(
-            q_form: &Query<&#marker_component, With<FormValid>>,
-            q_text_input: &Query<&TextInputValue>,
-            res_form_fields: &Option<Res<#entity_resource>>,
-        ) -> Option<#name> {
-            if let Ok(form) = q_form.get_single() {
-                let res_form_fields = res_form_fields.as_ref().unwrap();
-                #(#input_field_query_resolvers)*
-                Some(#name {
-                    #(
-                        #input_field_names,
-                    )*
-                })
-            } else {
-                error!("Failed to get form entity");
-                None
-            }
-        }
+    match opts.after_submit.as_ref().unwrap_or(&AfterSubmit::Keep) {
+        AfterSubmit::Keep => Vec::new(),
+        AfterSubmit::RefocusFirst => input_field_names
+            .first()
+            .map(|first| {
+                quote! {
+                    commands.entity(form_fields.#first).insert(FormElementFocus);
+                }
+            })
+            .into_iter()
+            .collect(),
+        AfterSubmit::Blur => input_field_names
+            .iter()
+            .map(|input_field_name| {
+                quote! {
+                    commands
+                        .entity(form_fields.#input_field_name)
+                        .remove::<FormElementFocus>();
+                }
+            })
+            .collect(),
     }
 }
 
@@ -534,7 +3090,7 @@ fn generate_button_submit(
 ) -> proc_macro2::TokenStream {
     let FormIdentifiers {
         marker_component,
-        entity_resource,
+        fields_component,
         event,
         ..
     } = form_identifiers;
@@ -547,7 +3103,7 @@ fn generate_button_submit(
             },
             quote! {
                 if let Ok(id) = q_id_button.get(ev.entity) {
-                    let form_data = get_form_data(&q_form, &q_text_input, &res_form_fields);
+                    let form_data = get_form_data(&mut commands, valid, &values, form_fields);
                     warn!("{:?}", form_data);
                     let action = #action::from_id_and_data(id.0, form_data).unwrap();
                     ev_action.send(action);
@@ -560,41 +3116,46 @@ fn generate_button_submit(
     };
 
     quote! {
+        #[allow(clippy::too_many_arguments)]
         fn btn_submit(
             mut commands: Commands,
             mut ev_form: EventWriter<#event>,
             #action_event
             mut ev_btn: EventReader<ButtonPressEvent>,
             q_generic_button: Query<&ButtonRole, Without<FormActionId>>,
-            q_form: Query<&#marker_component, With<FormValid>>,
-            q_form_entity: Query<Entity, With<#marker_component>>,
-            q_text_input: Query<&TextInputValue>,
-            res_form_fields: Option<Res<#entity_resource>>,
+            q_form: Query<(&#fields_component, Has<FormValid>), With<#marker_component>>,
+            values: FormValues,
+            pending: PendingElements,
+            mut cancel_guard: FormCancelGuard,
         ) {
             for ev in ev_btn.read() {
-                let form = if let Ok(form) = q_form_entity.get_single() {
-                    form
-                } else {
+                let Some(form) = ev.button.form else {
                     continue;
                 };
-                if ev.button.form.is_none() || ev.button.form.unwrap() != form {
+                let Ok((form_fields, valid)) = q_form.get(form) else {
                     continue;
-                }
+                };
                 #action
                 if let Ok(role) = q_generic_button.get(ev.entity) {
-                    let form_data = get_form_data(&q_form, &q_text_input, &res_form_fields);
-                    let form = ev.button.form.unwrap();
+                    let form_data = get_form_data(&mut commands, valid, &values, form_fields);
                     match role {
                         ButtonRole::Submit => {
+                            if pending.is_pending(form) {
+                                continue;
+                            }
                             if let Some(form_data) = form_data {
-                                ev_form.send(#event { event: FormEvent::Submit(form_data) });
+                                ev_form.send(#event { form, event: FormEvent::Submit(form_data) });
+                                submitted(&mut commands, form_fields);
                             }
                         }
                         ButtonRole::Cancel => {
-                            ev_form.send(#event { event: FormEvent::Cancel(form) });
+                            if cancel_guard.intercept(form) {
+                                continue;
+                            }
+                            ev_form.send(#event { form, event: FormEvent::Cancel(form) });
                         }
                         ButtonRole::Custom(name) => {
-                            ev_form.send(#event { event: FormEvent::Custom(form, name.to_string(), form_data) });
+                            ev_form.send(#event { form, event: FormEvent::Custom(form, name.to_string(), form_data) });
                         }
                         _ => {}
                     }
@@ -603,3 +3164,383 @@ fn generate_button_submit(
         }
     }
 }
+
+#[allow(clippy::too_many_lines)]
+fn generate_set_form_data(
+    name: &Ident,
+    fields: &[FormField],
+    form_identifiers: &FormIdentifiers,
+) -> proc_macro2::TokenStream {
+    let values = fields
+        .iter()
+        .map(|o| {
+            let field_name = o.form_field_opts.ident.as_ref().unwrap();
+            let input_field_name = format_ident!("{}_input", field_name);
+            let optional = o.form_field_opts.optional.unwrap_or(false);
+            let ty = field_value_type(&o.form_field_opts);
+
+            let to_string = match &o.field_specific_opts {
+                FormFieldType::Nested => {
+                    return quote! {
+                        self.#input_field_name.set_form_data(commands, &data.#field_name);
+                    };
+                }
+                FormFieldType::Repeated(_, Some(_)) => {
+                    return quote! {
+                        {
+                            let rows = data.#field_name.iter().map(ToString::to_string).collect::<Vec<_>>();
+                            set_repeated_rows(commands, self.#input_field_name, rows.len(), move |commands, index, row| {
+                                if let Some(row) = row.downcast_ref::<Entity>() {
+                                    commands.entity(*row).insert(TextInputValue(rows[index].clone()));
+                                }
+                            });
+                        }
+                    };
+                }
+                FormFieldType::Repeated(_, None) => {
+                    let row_ty = vec_inner_type(&o.form_field_opts.ty).unwrap_or(&o.form_field_opts.ty);
+                    return quote! {
+                        {
+                            let rows = data.#field_name.clone();
+                            set_repeated_rows(commands, self.#input_field_name, rows.len(), move |commands, index, row| {
+                                if let Some(row) = row.downcast_ref::<<#row_ty as NestedForm>::Fields>() {
+                                    row.set_form_data(commands, &rows[index]);
+                                }
+                            });
+                        }
+                    };
+                }
+                FormFieldType::Slider(_) => {
+                    return if optional {
+                        quote! {
+                            if let Some(value) = data.#field_name {
+                                commands.entity(self.#input_field_name).insert(SliderValue(value as f64));
+                            }
+                        }
+                    } else {
+                        quote! {
+                            commands.entity(self.#input_field_name).insert(SliderValue(data.#field_name as f64));
+                        }
+                    };
+                }
+                FormFieldType::Tags(_) => {
+                    let value = if optional {
+                        quote! { data.#field_name.clone().unwrap_or_default() }
+                    } else {
+                        quote! { data.#field_name.clone() }
+                    };
+                    return quote! {
+                        commands.entity(self.#input_field_name).insert(TagInputValue(#value));
+                    };
+                }
+                FormFieldType::Select(_) => {
+                    let value = if optional {
+                        quote! { data.#field_name.as_ref().map(FormSelectOptions::index) }
+                    } else {
+                        quote! { Some(data.#field_name.index()) }
+                    };
+                    return quote! {
+                        commands.entity(self.#input_field_name).insert(SelectValue(#value));
+                    };
+                }
+                FormFieldType::ColorPicker(_) => {
+                    return if optional {
+                        quote! {
+                            if let Some(value) = data.#field_name {
+                                commands.entity(self.#input_field_name).insert(ColorPickerValue(value));
+                            }
+                        }
+                    } else {
+                        quote! {
+                            commands.entity(self.#input_field_name).insert(ColorPickerValue(data.#field_name));
+                        }
+                    };
+                }
+                FormFieldType::TextBox(_)
+                | FormFieldType::TextArea(_)
+                | FormFieldType::Autocomplete(_)
+                | FormFieldType::NumberInput(_) => {
+                    quote! { value.to_string() }
+                }
+                FormFieldType::DateBox(date_box_opts)
+                | FormFieldType::DatePicker(date_box_opts) => {
+                    let settings = date_box_opts.date_input_settings();
+                    quote! { #settings.display(*value) }
+                }
+                FormFieldType::CurrencyBox(currency_box_opts) => generate_number_to_string(
+                    ty,
+                    &currency_box_opts.number_input_settings(),
+                    10f64.powi(i32::try_from(currency_box_opts.decimals()).unwrap_or(i32::MAX)),
+                    1.0,
+                ),
+                FormFieldType::PercentBox(percent_box_opts) => {
+                    let scale = if percent_box_opts.fraction.unwrap_or(false) {
+                        0.01
+                    } else {
+                        1.0
+                    };
+                    generate_number_to_string(
+                        ty,
+                        &percent_box_opts.number_input_settings(),
+                        scale,
+                        scale,
+                    )
+                }
+            };
+
+            let value = if optional {
+                quote! {
+                    data.#field_name.as_ref().map(|value| #to_string).unwrap_or_default()
+                }
+            } else {
+                quote! {
+                    {
+                        let value = &data.#field_name;
+                        #to_string
+                    }
+                }
+            };
+
+            quote! {
+                commands.entity(self.#input_field_name).insert(TextInputValue(#value));
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let fields_component = &form_identifiers.fields_component;
+
+    quote! {
+        impl #fields_component {
+            /// Fills the form with the given data. The fields are validated again afterwards.
+            #[allow(dead_code, clippy::cast_precision_loss, clippy::unnecessary_cast)]
+            pub(crate) fn set_form_data(&self, commands: &mut Commands, data: &#name) {
+                #(#values)*
+            }
+        }
+    }
+}
+
+/// Formats the value of a field backed by a `NumberInputSettings`. Inverse of
+/// [`generate_number_resolver`].
+fn generate_number_to_string(
+    ty: &syn::Type,
+    settings: &proc_macro2::TokenStream,
+    integer_scale: f64,
+    float_scale: f64,
+) -> proc_macro2::TokenStream {
+    let scale = if is_integer(ty) {
+        integer_scale
+    } else {
+        float_scale
+    };
+    let scale = proc_macro2::Literal::f64_suffixed(scale);
+
+    quote! {
+        {
+            let settings = #settings;
+            settings.display(&settings.format(*value as f64 / #scale))
+        }
+    }
+}
+
+/// Resolves a field backed by a `NumberInputSettings`. The parsed value is multiplied by
+/// `integer_scale` and rounded for integer fields and multiplied by `float_scale` otherwise.
+fn generate_number_resolver(
+    field_opts: &FormFieldOpts,
+    settings: &proc_macro2::TokenStream,
+    integer_scale: f64,
+    float_scale: f64,
+) -> proc_macro2::TokenStream {
+    let field_name = field_opts.ident.as_ref().unwrap();
+    let input_field_name = format_ident!("{}_input", field_name);
+    let mark_invalid = generate_mark_invalid(&input_field_name);
+
+    let optional = field_opts.optional.unwrap_or(false);
+    let ty = field_value_type(field_opts);
+
+    let scaled = |scale: f64| {
+        #[allow(clippy::float_cmp)]
+        if scale == 1.0 {
+            quote! { parsed }
+        } else {
+            let scale = proc_macro2::Literal::f64_suffixed(scale);
+            quote! { (parsed * #scale) }
+        }
+    };
+
+    let value = if is_integer(ty) {
+        let scaled = scaled(integer_scale);
+        quote! { #scaled.round() as #ty }
+    } else if matches!(ty, syn::Type::Path(type_path) if type_path.path.is_ident("f64")) {
+        scaled(float_scale)
+    } else {
+        let scaled = scaled(float_scale);
+        quote! { #scaled as #ty }
+    };
+
+    let (empty, wrap) = if optional {
+        (quote! { None }, quote! { Some(#value) })
+    } else {
+        (quote! { #mark_invalid }, value)
+    };
+
+    quote! {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let #field_name = {
+            let settings = #settings;
+            let Ok(input) = q_text_input.get(form_fields.#input_field_name) else {
+                return None;
+            };
+            let value = &input.0;
+            if value.trim().is_empty() {
+                #empty
+            } else if let Some(parsed) = settings.parse(value) {
+                let parsed = settings.clamp(parsed);
+                #wrap
+            } else {
+                #mark_invalid
+            }
+        };
+    }
+}
+
+/// Resolves a field backed by a `NumberInputSettings` by parsing the value as the type of the field.
+/// Values that can not be parsed as the type or are out of range mark the field invalid.
+fn generate_typed_number_resolver(
+    field_opts: &FormFieldOpts,
+    settings: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_name = field_opts.ident.as_ref().unwrap();
+    let input_field_name = format_ident!("{}_input", field_name);
+    let mark_invalid = generate_mark_invalid(&input_field_name);
+    let ty = field_value_type(field_opts);
+
+    let (empty, wrap) = if field_opts.optional.unwrap_or(false) {
+        (quote! { None }, quote! { Some(parsed) })
+    } else {
+        (quote! { #mark_invalid }, quote! { parsed })
+    };
+
+    quote! {
+        let #field_name = {
+            let settings = #settings;
+            let Ok(input) = q_text_input.get(form_fields.#input_field_name) else {
+                return None;
+            };
+            let value = settings.normalize(&input.0);
+            if value.trim().is_empty() {
+                #empty
+            } else {
+                match value.trim().parse::<#ty>() {
+                    #[allow(clippy::cast_lossless, clippy::cast_precision_loss, clippy::unnecessary_cast)]
+                    Ok(parsed) if !settings.is_out_of_range(parsed as f64) => #wrap,
+                    _ => {
+                        #mark_invalid
+                    }
+                }
+            }
+        };
+    }
+}
+
+/// Resolves a field backed by a `DateInputSettings`.
+fn generate_date_resolver(
+    field_opts: &FormFieldOpts,
+    settings: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_name = field_opts.ident.as_ref().unwrap();
+    let input_field_name = format_ident!("{}_input", field_name);
+    let mark_invalid = generate_mark_invalid(&input_field_name);
+
+    let (empty, wrap) = if field_opts.optional.unwrap_or(false) {
+        (quote! { None }, quote! { Some(date) })
+    } else {
+        (quote! { #mark_invalid }, quote! { date })
+    };
+
+    quote! {
+        let #field_name = {
+            let Ok(input) = q_text_input.get(form_fields.#input_field_name) else {
+                return None;
+            };
+            let value = &input.0;
+            if value.trim().is_empty() {
+                #empty
+            } else if let Some(date) = #settings.resolve(value) {
+                #wrap
+            } else {
+                #mark_invalid
+            }
+        };
+    }
+}
+
+/// Marks the input as invalid and aborts collecting the form data.
+fn generate_mark_invalid(input_field_name: &Ident) -> proc_macro2::TokenStream {
+    quote! {
+        let entity = form_fields.#input_field_name;
+        commands
+            .entity(entity)
+            .insert(FormElementInvalid(FormValidationError::Invalid(entity)))
+            .remove::<FormElementValid>();
+        return None;
+    }
+}
+
+/// Returns the type of the field, without the `Option` of optional fields.
+fn field_value_type(field_opts: &FormFieldOpts) -> &syn::Type {
+    if field_opts.optional.unwrap_or(false) {
+        option_inner_type(&field_opts.ty).unwrap_or(&field_opts.ty)
+    } else {
+        &field_opts.ty
+    }
+}
+
+/// Returns true if the type is a primitive integer.
+fn is_integer(ty: &syn::Type) -> bool {
+    const INTEGERS: [&str; 12] = [
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+    ];
+
+    matches!(ty, syn::Type::Path(type_path) if INTEGERS.iter().any(|integer| type_path.path.is_ident(integer)))
+}
+
+/// Returns true if the type is a primitive unsigned integer.
+fn is_unsigned(ty: &syn::Type) -> bool {
+    const UNSIGNED: [&str; 6] = ["u8", "u16", "u32", "u64", "u128", "usize"];
+
+    matches!(ty, syn::Type::Path(type_path) if UNSIGNED.iter().any(|unsigned| type_path.path.is_ident(unsigned)))
+}
+
+/// Returns the inner type if the type is an `Option<T>`.
+fn option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_inner_type(ty, "Option")
+}
+
+/// Returns the inner type if the type is a `Vec<T>`.
+fn vec_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_inner_type(ty, "Vec")
+}
+
+/// Returns the type argument if the type is `wrapper<T>`.
+fn generic_inner_type<'a>(ty: &'a syn::Type, wrapper: &str) -> Option<&'a syn::Type> {
+    let syn::Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+/// Returns true if the type is a `String`.
+fn is_string(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(type_path) if type_path.path.is_ident("String"))
+}
@@ -3,6 +3,7 @@
 use proc_macro::TokenStream;
 
 mod form_actions;
+mod form_select_options;
 mod form_struct;
 
 /// Proc macro for generating a form plugin
@@ -23,3 +24,10 @@ pub fn form_struct(args: TokenStream, input: TokenStream) -> TokenStream {
 pub fn form_actions_derive(input: TokenStream) -> TokenStream {
     form_actions::form_actions_derive(input)
 }
+
+/// Proc macro for deriving select options
+/// This is intended to be used on a fieldless enum in conjunction with the `select` attribute of the `form_struct` macro
+#[proc_macro_derive(FormSelectOptions, attributes(select_option))]
+pub fn form_select_options_derive(input: TokenStream) -> TokenStream {
+    form_select_options::form_select_options_derive(input)
+}